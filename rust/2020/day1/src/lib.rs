@@ -0,0 +1,384 @@
+#[cfg(test)]
+mod day1_tests {
+    use super::*;
+
+    static TEST_INPUT: &str = "\
+1721
+979
+366
+299
+675
+1456";
+
+    static TEST_PARSED: [i32; 6] = [1721, 979, 366, 299, 675, 1456];
+
+    #[test]
+    fn convert_lines_to_number_array() {
+        assert_eq!(Vec::from(TEST_PARSED), numbers::<i32>(TEST_INPUT).unwrap());
+    }
+
+    #[test]
+    fn find_the_two_numbers_that_sum_to_2020() {
+        assert_eq!(Some(vec![0, 3]), find_k_sum(&TEST_PARSED, 2, 2020));
+    }
+
+    #[test]
+    fn day_1a_basic() {
+        assert_eq!(514579, day1a(&numbers(TEST_INPUT).unwrap()).unwrap());
+    }
+
+    #[test]
+    fn find_the_three_numbers_that_sum_to_2020() {
+        assert_eq!(Some(vec![1, 2, 4]), find_k_sum(&TEST_PARSED, 3, 2020));
+    }
+
+    #[test]
+    fn day_1b_basic() {
+        assert_eq!(241861950, day1b(&numbers(TEST_INPUT).unwrap()).unwrap());
+    }
+
+    #[test]
+    fn reject_malformed_lines() {
+        assert_eq!(
+            "line 3, column 1: 'x2' invalid digit found in string",
+            Day1::parse("1721\n\nx2\n299").unwrap_err().to_string()
+        );
+        assert!(Day1::parse("2020\n\n0").is_ok());
+        assert!(Day1::parse("2020\n-\n").is_err());
+    }
+
+    #[test]
+    fn pair_an_entry_with_an_equal_one() {
+        assert_eq!(1020100, day1a(&[1010, 7, 1010]).unwrap());
+        assert!(day1a(&[1010, 7]).is_err());
+    }
+
+    #[test]
+    fn report_every_candidate_when_the_answer_is_ambiguous() {
+        assert_eq!(
+            "ambiguous: 2 different pairs of entries sum to 2020: 1721 + 299, 1000 + 1020",
+            day1a(&[1721, 1000, 979, 299, 1020])
+                .unwrap_err()
+                .to_string()
+        );
+        assert_eq!(1020100, day1a(&[1010, 1010, 1010]).unwrap());
+        assert!(day1b(&[1000, 1010, 10, 1000, 20, 1000]).is_err());
+    }
+
+    #[test]
+    fn report_a_product_too_large_for_an_answer() {
+        assert_eq!(
+            "no solution: 100000 * -97980 is too large for an answer",
+            day1a(&[100000, 5, -97980]).unwrap_err().to_string()
+        );
+        assert!(day1b(&[i32::MAX, i32::MIN, 2021, 3]).is_err());
+        assert_eq!(-2020, day1b(&[-1, 2020, 1, 5]).unwrap_or(0));
+    }
+
+    #[test]
+    fn never_reuse_an_entry() {
+        assert_eq!(None, find_k_sum(&[1010, 5], 2, 2020));
+        assert_eq!(Some(vec![0, 2]), find_k_sum(&[1010, 5, 1010], 2, 2020));
+        assert_eq!(None, find_k_sum(&[1, 2, 3], 4, 6));
+    }
+
+    #[test]
+    fn find_every_set_of_entries_with_the_target_sum() {
+        assert_eq!(
+            vec![vec![0, 2, 4], vec![1, 2, 3]],
+            find_all_k_sums(&[1, 2, 3, 4, 5], 3, 9)
+        );
+        assert_eq!(
+            vec![vec![0, 1], vec![0, 2], vec![1, 2]],
+            find_all_k_sums(&[5, 5, 5], 2, 10)
+        );
+        assert_eq!(
+            vec![vec![0, 1, 4, 5], vec![0, 2, 3, 5], vec![1, 2, 3, 4]],
+            find_all_k_sums(&[1, 2, 3, 4, 5, 6], 4, 14)
+        );
+        assert_eq!(vec![Vec::<usize>::new()], find_all_k_sums(&[1, 2], 0, 0));
+    }
+
+    //  Every combination of entries, checked one by one and grouped by how
+    //  many there are and what they sum to.
+    fn brute_force(inputs: &[i32]) -> HashMap<(usize, i64), Vec<Vec<usize>>> {
+        let mut found = HashMap::<(usize, i64), Vec<Vec<usize>>>::new();
+        for mask in 0u32..1 << inputs.len() {
+            let indices: Vec<usize> = (0..inputs.len()).filter(|i| mask & 1 << i != 0).collect();
+            let sum = indices.iter().map(|&i| inputs[i] as i64).sum();
+            found.entry((indices.len(), sum)).or_default().push(indices);
+        }
+        found
+    }
+
+    #[test]
+    fn agree_with_a_brute_force_search() {
+        let mut seed = 7u32;
+        for _ in 0..20 {
+            let inputs: Vec<i32> = (0..10)
+                .map(|_| {
+                    seed = seed.wrapping_mul(1103515245).wrapping_add(12345);
+                    (seed >> 16) as i32 % 10 - 3
+                })
+                .collect();
+            let expected = brute_force(&inputs);
+
+            for k in 0..=6 {
+                for target in -6..=12 {
+                    let mut sets = expected
+                        .get(&(k, target as i64))
+                        .cloned()
+                        .unwrap_or_default();
+                    sets.sort();
+                    assert_eq!(
+                        sets,
+                        find_all_k_sums(&inputs, k, target),
+                        "{:?}, k = {}, target = {}",
+                        inputs,
+                        k,
+                        target
+                    );
+                }
+            }
+        }
+    }
+}
+
+use aoc_common::input::numbers;
+use aoc_common::{Error, Result, Solution};
+use std::collections::{HashMap, HashSet};
+
+pub struct Day1;
+
+//  Finds sets of k entries, as positions in the sorted values, whose sum is
+//  the target. Fixing the smallest entry leaves a smaller problem, down to a
+//  pair, which is found by closing in from both ends.
+fn k_sum(
+    sorted: &[i64],
+    start: usize,
+    k: usize,
+    target: i64,
+    chosen: &mut Vec<usize>,
+    found: &mut Vec<Vec<usize>>,
+) {
+    let end = sorted.len();
+
+    match k {
+        0 => {
+            if target == 0 {
+                found.push(chosen.clone());
+            }
+        }
+        1 => {
+            for (position, &n) in sorted.iter().enumerate().skip(start) {
+                if n == target {
+                    found.push([chosen.as_slice(), &[position]].concat());
+                }
+            }
+        }
+        2 => {
+            let (mut low, mut high) = (start, end);
+            while low + 1 < high {
+                let sum = sorted[low] + sorted[high - 1];
+                if sum < target {
+                    low += 1;
+                } else if sum > target {
+                    high -= 1;
+                } else if sorted[low] == sorted[high - 1] {
+                    //  Every pair in between has the same sum.
+                    for first in low..high {
+                        for second in first + 1..high {
+                            found.push([chosen.as_slice(), &[first, second]].concat());
+                        }
+                    }
+                    return;
+                } else {
+                    let low_end = low + sorted[low..].partition_point(|&n| n == sorted[low]);
+                    let high_start = sorted[..high].partition_point(|&n| n < sorted[high - 1]);
+                    for first in low..low_end {
+                        for second in high_start..high {
+                            found.push([chosen.as_slice(), &[first, second]].concat());
+                        }
+                    }
+                    low = low_end;
+                    high = high_start;
+                }
+            }
+        }
+        _ => {
+            for position in start..end {
+                //  The rest are no smaller, so their sum can only grow.
+                if sorted[position] * k as i64 > target {
+                    break;
+                }
+
+                chosen.push(position);
+                k_sum(
+                    sorted,
+                    position + 1,
+                    k - 1,
+                    target - sorted[position],
+                    chosen,
+                    found,
+                );
+                chosen.pop();
+            }
+        }
+    }
+}
+
+//  Calls back with every set of `size` positions from `start` on, in order.
+fn combinations(
+    end: usize,
+    start: usize,
+    size: usize,
+    chosen: &mut Vec<usize>,
+    each: &mut impl FnMut(&[usize]),
+) {
+    if chosen.len() == size {
+        each(chosen);
+        return;
+    }
+
+    for position in start..end {
+        chosen.push(position);
+        combinations(end, position + 1, size, chosen, each);
+        chosen.pop();
+    }
+}
+
+//  Splits each set into its smaller and larger halves by position, and
+//  matches every lower half against the upper halves which complete the sum
+//  and all come after it. This takes about n^(k/2) steps instead of n^(k-1).
+fn meet_in_the_middle(sorted: &[i64], k: usize, target: i64) -> Vec<Vec<usize>> {
+    let lower = k / 2;
+    let mut uppers = HashMap::<i64, Vec<Vec<usize>>>::new();
+    combinations(sorted.len(), 0, k - lower, &mut vec![], &mut |upper| {
+        let sum = upper.iter().map(|&position| sorted[position]).sum();
+        uppers.entry(sum).or_default().push(upper.to_vec());
+    });
+
+    let mut found = vec![];
+    combinations(sorted.len(), 0, lower, &mut vec![], &mut |half| {
+        let sum: i64 = half.iter().map(|&position| sorted[position]).sum();
+        let last = half[half.len() - 1];
+
+        for upper in uppers.get(&(target - sum)).into_iter().flatten() {
+            if upper[0] > last {
+                found.push([half, upper.as_slice()].concat());
+            }
+        }
+    });
+
+    found
+}
+
+//  Every set of k different entries that sums to the target, as their
+//  indices in ascending order. Equal entries at different indices count as
+//  different sets.
+pub fn find_all_k_sums(inputs: &[i32], k: usize, target: i32) -> Vec<Vec<usize>> {
+    let mut order: Vec<usize> = (0..inputs.len()).collect();
+    order.sort_by_key(|&i| inputs[i]);
+    let sorted: Vec<i64> = order.iter().map(|&i| inputs[i] as i64).collect();
+
+    let found = if k >= 4 {
+        meet_in_the_middle(&sorted, k, target as i64)
+    } else {
+        let mut found = vec![];
+        k_sum(&sorted, 0, k, target as i64, &mut vec![], &mut found);
+        found
+    };
+
+    let mut found: Vec<Vec<usize>> = found
+        .into_iter()
+        .map(|positions| {
+            let mut indices: Vec<usize> = positions.into_iter().map(|p| order[p]).collect();
+            indices.sort_unstable();
+            indices
+        })
+        .collect();
+    found.sort_unstable();
+
+    found
+}
+
+//  The first set of k entries that sums to the target, by their indices.
+pub fn find_k_sum(inputs: &[i32], k: usize, target: i32) -> Option<Vec<usize>> {
+    find_all_k_sums(inputs, k, target).into_iter().next()
+}
+
+fn join(values: &[i32], separator: &str) -> String {
+    values
+        .iter()
+        .map(i32::to_string)
+        .collect::<Vec<String>>()
+        .join(separator)
+}
+
+//  Equal entries at different indices give the same answer, so only sets
+//  with different values make the answer ambiguous.
+fn product_of_k_sum(inputs: &[i32], k: usize, target: i32, entries: &str) -> Result<i32> {
+    let mut seen = HashSet::<Vec<i32>>::new();
+    let mut candidates: Vec<Vec<i32>> = vec![];
+    for indices in find_all_k_sums(inputs, k, target) {
+        let values: Vec<i32> = indices.iter().map(|&i| inputs[i]).collect();
+        let mut key = values.clone();
+        key.sort_unstable();
+        if seen.insert(key) {
+            candidates.push(values);
+        }
+    }
+
+    match candidates.as_slice() {
+        [] => Err(Error::no_solution(format!(
+            "no {} entries sum to {}",
+            entries, target
+        ))),
+        [values] => values
+            .iter()
+            .try_fold(1i32, |product, &value| product.checked_mul(value))
+            .ok_or_else(|| {
+                Error::no_solution(format!(
+                    "{} is too large for an answer",
+                    join(values, " * ")
+                ))
+            }),
+        _ => Err(Error::ambiguous(format!(
+            "{} different {} of entries sum to {}: {}",
+            candidates.len(),
+            if k == 2 { "pairs" } else { "sets" },
+            target,
+            candidates
+                .iter()
+                .map(|values| join(values, " + "))
+                .collect::<Vec<String>>()
+                .join(", ")
+        ))),
+    }
+}
+
+pub fn day1a(inputs: &[i32]) -> Result<i32> {
+    product_of_k_sum(inputs, 2, 2020, "two")
+}
+
+pub fn day1b(inputs: &[i32]) -> Result<i32> {
+    product_of_k_sum(inputs, 3, 2020, "three")
+}
+
+impl Solution for Day1 {
+    type Input = Vec<i32>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Vec<i32>> {
+        numbers(input)
+    }
+
+    fn part1(inputs: &Vec<i32>) -> Result<i32> {
+        day1a(inputs)
+    }
+
+    fn part2(inputs: &Vec<i32>) -> Result<i32> {
+        day1b(inputs)
+    }
+}
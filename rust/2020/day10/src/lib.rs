@@ -0,0 +1,217 @@
+use aoc_common::input::numbers;
+use aoc_common::{Error, Result, Solution};
+use std::cmp::max;
+use std::cmp::min;
+use std::collections::HashMap;
+
+#[cfg(test)]
+mod day10_tests {
+    use super::*;
+
+    static TEST_INPUT: &str = "
+16
+10
+15
+5
+1
+11
+7
+19
+6
+12
+4";
+
+    static TEST2_INPUT: &str = "
+28
+33
+18
+42
+31
+14
+46
+20
+48
+47
+24
+23
+49
+45
+19
+38
+39
+11
+1
+32
+25
+35
+8
+17
+7
+9
+4
+2
+34
+10
+3";
+
+    static TEST2_PARSED: [u32; 31] = [
+        28, 33, 18, 42, 31, 14, 46, 20, 48, 47, 24, 23, 49, 45, 19, 38, 39, 11, 1, 32, 25, 35, 8,
+        17, 7, 9, 4, 2, 34, 10, 3,
+    ];
+
+    static TEST_PARSED: [u32; 11] = [16, 10, 15, 5, 1, 11, 7, 19, 6, 12, 4];
+
+    #[test]
+    fn should_parse_input_to_numbers() {
+        assert_eq!(Vec::from(TEST_PARSED), numbers::<u32>(TEST_INPUT).unwrap());
+    }
+
+    #[test]
+    fn should_calculate_differences_between_voltages() {
+        let mut input = Vec::from(TEST_PARSED);
+        input.sort();
+        let result = calculate_difference_jumps(&input).unwrap();
+        assert_eq!(7, result.0);
+        assert_eq!(0, result.1);
+        assert_eq!(5, result.2);
+
+        let mut input = Vec::from(TEST2_PARSED);
+        input.sort();
+        let result = calculate_difference_jumps(&input).unwrap();
+        assert_eq!(22, result.0);
+        assert_eq!(0, result.1);
+        assert_eq!(10, result.2);
+    }
+
+    #[test]
+    fn should_calculate_permutations_of_a_subblock() {
+        let mut input = Vec::from(TEST_PARSED);
+        input.sort();
+        // input.insert(0, 0);
+        assert_eq!(8, calculate_permutations(&input));
+
+        let mut input = Vec::from(TEST2_PARSED);
+        input.sort();
+        // input.insert(0, 0);
+        assert_eq!(19208, calculate_permutations(&input));
+    }
+
+    #[test]
+    fn day10a_test() {
+        assert_eq!(35, day10a(&parse_input(TEST_INPUT).unwrap()).unwrap());
+        assert_eq!(220, day10a(&parse_input(TEST2_INPUT).unwrap()).unwrap());
+    }
+
+    #[test]
+    fn should_fail_when_the_adapters_cannot_be_chained() {
+        assert_eq!(
+            "no solution: 5 jolts is 5 more than the 0 before it",
+            day10a(&parse_input("5").unwrap()).unwrap_err().to_string()
+        );
+        assert_eq!(
+            "no solution: two adapters are both rated 2 jolts",
+            day10b(&parse_input("1\n2\n2\n3").unwrap())
+                .unwrap_err()
+                .to_string()
+        );
+        assert!(day10b(&parse_input("1\n4\n8").unwrap()).is_err());
+    }
+
+    #[test]
+    fn day10b_test() {
+        assert_eq!(8, day10b(&parse_input(TEST_INPUT).unwrap()).unwrap());
+        assert_eq!(19208, day10b(&parse_input(TEST2_INPUT).unwrap()).unwrap());
+    }
+}
+
+fn calculate_permutations(block: &[u32]) -> u64 {
+    let mut seen_map = HashMap::<u32, u64>::new();
+
+    for i in (0..block.len()).rev() {
+        let current = block[i];
+        let mut current_permutations = 0;
+
+        for j in block[i + 1..min(i + 4, block.len())]
+            .iter()
+            .filter(|&&x| x <= current + 3)
+        {
+            current_permutations += seen_map.get(j).unwrap_or(&0);
+        }
+
+        current_permutations = max(current_permutations, 1);
+        seen_map.insert(current, current_permutations);
+    }
+
+    (0..min(3, block.len()))
+        .map(|i| block[i])
+        .filter(|&n| n <= 3)
+        .map(|n| seen_map.get(&n).unwrap_or(&0))
+        .sum()
+}
+
+//  Every adapter has to be used, so two that are equal, or more than three
+//  jolts apart, leave no chain at all.
+fn calculate_difference_jumps(sorted_input: &[u32]) -> Result<(usize, usize, usize)> {
+    let mut last = 0;
+    let mut result = (0, 0, 1);
+
+    for &num in sorted_input {
+        match num - last {
+            1 => result.0 += 1,
+            2 => result.1 += 1,
+            3 => result.2 += 1,
+            0 => {
+                return Err(Error::no_solution(format!(
+                    "two adapters are both rated {} jolts",
+                    num
+                )))
+            }
+            difference => {
+                return Err(Error::no_solution(format!(
+                    "{} jolts is {} more than the {} before it",
+                    num, difference, last
+                )))
+            }
+        }
+
+        last = num;
+    }
+
+    Ok(result)
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<u32>> {
+    let mut numbers = numbers(input)?;
+    numbers.sort();
+    Ok(numbers)
+}
+
+pub fn day10a(sorted_numbers: &[u32]) -> Result<usize> {
+    let result = calculate_difference_jumps(sorted_numbers)?;
+    Ok(result.0 * result.2)
+}
+
+pub fn day10b(sorted_numbers: &[u32]) -> Result<u64> {
+    calculate_difference_jumps(sorted_numbers)?;
+    Ok(calculate_permutations(sorted_numbers))
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<u32>;
+    type Part1 = usize;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Vec<u32>> {
+        parse_input(input)
+    }
+
+    fn part1(sorted_numbers: &Vec<u32>) -> Result<usize> {
+        day10a(sorted_numbers)
+    }
+
+    fn part2(sorted_numbers: &Vec<u32>) -> Result<u64> {
+        day10b(sorted_numbers)
+    }
+}
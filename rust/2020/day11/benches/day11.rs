@@ -0,0 +1,38 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use day11::{day11a, day11b, parse_seat_map};
+
+static TEST_INPUT: &str = include_str!("../example.txt");
+
+//  Inputs of other sizes from the generator, seeded by their size.
+fn synthetic_input(size: usize) -> String {
+    aoc_generate::generate("2020", 11, size as u64, Some(size))
+        .unwrap()
+        .input
+}
+
+fn day11_benches(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day11");
+    group.sample_size(10);
+
+    let inputs = vec![
+        (String::from("example"), String::from(TEST_INPUT)),
+        (String::from("50x50"), synthetic_input(50)),
+        (String::from("100x100"), synthetic_input(100)),
+    ];
+
+    for (name, input) in &inputs {
+        let map = parse_seat_map(input).unwrap();
+
+        group.bench_with_input(BenchmarkId::new("part1", name), &map, |b, map| {
+            b.iter(|| day11a(map))
+        });
+        group.bench_with_input(BenchmarkId::new("part2", name), &map, |b, map| {
+            b.iter(|| day11b(map))
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, day11_benches);
+criterion_main!(benches);
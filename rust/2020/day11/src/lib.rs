@@ -0,0 +1,494 @@
+use aoc_common::automaton::{Automaton, FiniteGrid, Rule};
+use aoc_common::grid::{Grid, Point};
+use aoc_common::trace::{trace, Frame, NoTrace, Traced, Tracer};
+use aoc_common::{Result, Solution};
+
+//  Floor is None, and each seat is whether or not it is taken.
+pub type SeatMap = Grid<Option<bool>>;
+
+#[cfg(test)]
+mod day11_tests {
+    use super::*;
+    use aoc_common::automaton::Topology;
+
+    static TEST_INPUT: &str = include_str!("../example.txt");
+
+    fn neighbours(topology: &FiniteGrid, seat: Point) -> Vec<Point> {
+        let mut neighbours = vec![];
+        topology.neighbours(seat, |neighbour| neighbours.push(neighbour));
+        neighbours
+    }
+
+    fn taken_neighbours(seat: Point, map: &SeatMap, topology: &FiniteGrid) -> usize {
+        neighbours(topology, seat)
+            .into_iter()
+            .filter(|&neighbour| is_seat_taken(neighbour, map))
+            .count()
+    }
+
+    #[test]
+    fn should_parse_a_seat_map() {
+        assert_eq!(
+            vec![
+                Some(false),
+                None,
+                Some(false),
+                None,
+                Some(false),
+                None,
+                Some(false),
+                None,
+                Some(false)
+            ],
+            parse_seat_map(
+                "L.L
+.L.
+L.L"
+            )
+            .unwrap()
+            .iter()
+            .copied()
+            .collect::<Vec<Option<bool>>>()
+        );
+    }
+
+    #[test]
+    fn should_parse_file_input_to_seat_map() {
+        let result = parse_seat_map(TEST_INPUT).unwrap();
+        assert_eq!(10, result.height());
+        assert_eq!(10, result.width());
+        assert!(result[(0, 0)].is_some());
+        assert_eq!(false, result[(0, 0)].unwrap());
+        assert!(result[(1, 0)].is_none());
+        assert!(result[(1, 1)].is_some());
+        assert!(result[(7, 1)].is_none());
+    }
+
+    #[test]
+    fn should_reject_malformed_seat_maps() {
+        assert_eq!(
+            "line 2, column 2: unexpected 'x'",
+            parse_seat_map("L.L\n.x.").unwrap_err().to_string()
+        );
+        assert_eq!(
+            "line 2, column 1: expected a row of 3 cells, found 2",
+            parse_seat_map("L.L\n.L").unwrap_err().to_string()
+        );
+        assert!(parse_seat_map("").is_err());
+    }
+
+    #[test]
+    fn should_detect_taken_seats() {
+        let map = Grid::from_fn(3, 3, |(x, y)| Some((x + y) % 2 == 0));
+
+        assert!(is_seat_taken((0, 0), &map));
+        assert!(!is_seat_taken((1, 0), &map));
+        assert!(is_seat_taken((2, 0), &map));
+        assert!(!is_seat_taken((0, 1), &map));
+        assert!(is_seat_taken((1, 1), &map));
+        assert!(!is_seat_taken((2, 1), &map));
+        assert!(is_seat_taken((0, 2), &map));
+        assert!(!is_seat_taken((1, 2), &map));
+        assert!(is_seat_taken((2, 2), &map));
+    }
+
+    #[test]
+    fn should_taken_neighbours() {
+        let map = Grid::from_fn(3, 3, |_| Some(true));
+        let neighbours = find_all_neighbours(&map);
+
+        assert_eq!(8, taken_neighbours((1, 1), &map, &neighbours));
+        assert_eq!(3, taken_neighbours((0, 0), &map, &neighbours));
+        assert_eq!(5, taken_neighbours((1, 0), &map, &neighbours));
+        assert_eq!(5, taken_neighbours((0, 1), &map, &neighbours));
+        assert_eq!(5, taken_neighbours((1, 2), &map, &neighbours));
+        assert_eq!(5, taken_neighbours((2, 1), &map, &neighbours));
+        assert_eq!(3, taken_neighbours((2, 2), &map, &neighbours));
+
+        let map = Grid::from_fn(3, 3, |(x, y)| Some((x + y) % 2 == 0));
+
+        assert_eq!(4, taken_neighbours((1, 1), &map, &neighbours));
+        assert_eq!(1, taken_neighbours((0, 0), &map, &neighbours));
+        assert_eq!(3, taken_neighbours((1, 0), &map, &neighbours));
+        assert_eq!(3, taken_neighbours((0, 1), &map, &neighbours));
+        assert_eq!(3, taken_neighbours((1, 2), &map, &neighbours));
+        assert_eq!(3, taken_neighbours((2, 1), &map, &neighbours));
+        assert_eq!(1, taken_neighbours((2, 2), &map, &neighbours));
+    }
+
+    #[test]
+    fn should_fill_an_empty_seat_with_no_neighbours() {
+        let map = Grid::from_fn(5, 5, |(x, y)| Some(x == 0 || x == 4 || y == 0 || y == 4));
+        let neighbours = find_all_neighbours(&map);
+
+        let mut seating = seating(&map, neighbours, 4);
+        seating.step();
+        assert!(seating.is_alive((2, 2)));
+    }
+
+    #[test]
+    fn should_not_fill_an_empty_seat_with_neighbours() {
+        let map = Grid::from_fn(5, 5, |(x, y)| Some(x == 0 || x == 4 || y == 0 || y == 4));
+        let neighbours = find_all_neighbours(&map);
+
+        let mut result = seating(&map, neighbours.clone(), 4);
+        result.step();
+        assert!(!result.is_alive((1, 1)));
+        assert!(!result.is_alive((2, 1)));
+        assert!(!result.is_alive((3, 1)));
+        assert!(!result.is_alive((1, 2)));
+        assert!(!result.is_alive((3, 2)));
+        assert!(!result.is_alive((1, 3)));
+        assert!(!result.is_alive((2, 3)));
+        assert!(!result.is_alive((3, 3)));
+    }
+
+    #[test]
+    fn should_empty_a_taken_seat_with_over_4_neighbours() {
+        let map = Grid::from_fn(3, 3, |_| Some(true));
+        let neighbours = find_all_neighbours(&map);
+
+        let mut result = seating(&map, neighbours.clone(), 4);
+        result.step();
+        assert!(!result.is_alive((1, 0)));
+        assert!(!result.is_alive((0, 1)));
+        assert!(!result.is_alive((1, 1)));
+        assert!(!result.is_alive((2, 1)));
+        assert!(!result.is_alive((1, 2)));
+
+        let map = Grid::from_fn(3, 3, |(x, y)| Some((x + y) % 2 == 0));
+
+        let mut result = seating(&map, neighbours, 4);
+        result.step();
+        assert!(!result.is_alive((1, 1)));
+    }
+
+    #[test]
+    fn should_not_empty_a_taken_seat_with_below_4_neighbours() {
+        let map = Grid::from_fn(3, 3, |_| Some(true));
+        let neighbours = find_all_neighbours(&map);
+
+        let mut result = seating(&map, neighbours.clone(), 4);
+        result.step();
+        assert!(result.is_alive((0, 0)));
+        assert!(result.is_alive((2, 0)));
+        assert!(result.is_alive((0, 2)));
+        assert!(result.is_alive((2, 2)));
+
+        let map = Grid::from_fn(3, 3, |(x, y)| Some((x + y) % 2 == 0));
+
+        let mut result = seating(&map, neighbours, 4);
+        result.step();
+        assert!(result.is_alive((0, 0)));
+        assert!(result.is_alive((2, 0)));
+        assert!(result.is_alive((0, 2)));
+        assert!(result.is_alive((2, 2)));
+    }
+
+    #[test]
+    fn should_find_neighbours() {
+        let map = Grid::from_fn(3, 3, |_| Some(true));
+        assert_eq!(
+            vec![(1, 0), (0, 1), (1, 1)],
+            neighbours(&find_all_neighbours(&map), (0, 0))
+        );
+        assert_eq!(
+            vec![
+                (0, 0),
+                (1, 0),
+                (2, 0),
+                (0, 1),
+                (2, 1),
+                (0, 2),
+                (1, 2),
+                (2, 2)
+            ],
+            neighbours(&find_all_neighbours(&map), (1, 1))
+        );
+        assert_eq!(
+            vec![(1, 1), (2, 1), (1, 2)],
+            neighbours(&find_all_neighbours(&map), (2, 2))
+        );
+        assert_eq!(
+            vec![(0, 0), (2, 0), (0, 1), (1, 1), (2, 1)],
+            neighbours(&find_all_neighbours(&map), (1, 0))
+        );
+        assert_eq!(
+            vec![(1, 0), (2, 0), (1, 1), (1, 2), (2, 2)],
+            neighbours(&find_all_neighbours(&map), (2, 1))
+        );
+
+        let map = Grid::from_fn(5, 5, |(x, y)| {
+            if (x == 2 && y == 2) || (x == 0 || x == 4 || y == 0 || y == 4) {
+                Some(false)
+            } else {
+                None
+            }
+        });
+        assert_eq!(
+            vec![(1, 0), (0, 1)],
+            neighbours(&find_all_neighbours(&map), (0, 0))
+        );
+        assert_eq!(
+            Vec::<Point>::new(),
+            neighbours(&find_all_neighbours(&map), (1, 2))
+        );
+        assert_eq!(
+            Vec::<Point>::new(),
+            neighbours(&find_all_neighbours(&map), (2, 2))
+        );
+        assert_eq!(
+            vec![(1, 0), (3, 0)],
+            neighbours(&find_all_neighbours(&map), (2, 0))
+        );
+    }
+
+    #[test]
+    fn should_find_nearest_seats() {
+        let map = Grid::from_fn(5, 5, |(x, y)| {
+            if (x == 2 && y == 2) || (x == 0 || x == 4 || y == 0 || y == 4) {
+                Some(false)
+            } else {
+                None
+            }
+        });
+
+        assert_eq!(
+            vec![(1, 0), (0, 1), (2, 2)],
+            neighbours(&find_all_nearest_seats(&map), (0, 0))
+        );
+        assert_eq!(
+            vec![(0, 0), (2, 0), (0, 1), (1, 4), (4, 3)],
+            neighbours(&find_all_nearest_seats(&map), (1, 0))
+        );
+        assert_eq!(
+            vec![(1, 0), (3, 0), (0, 2), (2, 2), (4, 2)],
+            neighbours(&find_all_nearest_seats(&map), (2, 0))
+        );
+        assert_eq!(
+            vec![(2, 0), (4, 0), (0, 3), (3, 4), (4, 1)],
+            neighbours(&find_all_nearest_seats(&map), (3, 0))
+        );
+        assert_eq!(
+            vec![(3, 0), (2, 2), (4, 1)],
+            neighbours(&find_all_nearest_seats(&map), (4, 0))
+        );
+        assert_eq!(
+            vec![(0, 0), (1, 0), (4, 1), (0, 2), (3, 4)],
+            neighbours(&find_all_nearest_seats(&map), (0, 1))
+        );
+        assert_eq!(
+            vec![(3, 0), (4, 0), (0, 1), (1, 4), (4, 2)],
+            neighbours(&find_all_nearest_seats(&map), (4, 1))
+        );
+        assert_eq!(
+            vec![(0, 1), (2, 0), (2, 2), (0, 3), (2, 4)],
+            neighbours(&find_all_nearest_seats(&map), (0, 2))
+        );
+        assert_eq!(
+            vec![
+                (0, 0),
+                (2, 0),
+                (4, 0),
+                (0, 2),
+                (4, 2),
+                (0, 4),
+                (2, 4),
+                (4, 4)
+            ],
+            neighbours(&find_all_nearest_seats(&map), (2, 2))
+        );
+        assert_eq!(
+            vec![(2, 0), (4, 1), (2, 2), (2, 4), (4, 3)],
+            neighbours(&find_all_nearest_seats(&map), (4, 2))
+        );
+        assert_eq!(
+            vec![(0, 2), (3, 0), (4, 3), (0, 4), (1, 4)],
+            neighbours(&find_all_nearest_seats(&map), (0, 3))
+        );
+        assert_eq!(
+            vec![(1, 0), (4, 2), (0, 3), (3, 4), (4, 4)],
+            neighbours(&find_all_nearest_seats(&map), (4, 3))
+        );
+        assert_eq!(
+            vec![(0, 3), (2, 2), (1, 4)],
+            neighbours(&find_all_nearest_seats(&map), (0, 4))
+        );
+        assert_eq!(
+            vec![(0, 3), (1, 0), (4, 1), (0, 4), (2, 4)],
+            neighbours(&find_all_nearest_seats(&map), (1, 4))
+        );
+        assert_eq!(
+            vec![(0, 2), (2, 2), (4, 2), (1, 4), (3, 4)],
+            neighbours(&find_all_nearest_seats(&map), (2, 4))
+        );
+        assert_eq!(
+            vec![(0, 1), (3, 0), (4, 3), (2, 4), (4, 4)],
+            neighbours(&find_all_nearest_seats(&map), (3, 4))
+        );
+        assert_eq!(
+            vec![(2, 2), (4, 3), (3, 4)],
+            neighbours(&find_all_nearest_seats(&map), (4, 4))
+        );
+    }
+
+    #[test]
+    fn day11a_test() {
+        assert_eq!(37, day11a(&parse_seat_map(TEST_INPUT).unwrap()).unwrap());
+    }
+
+    #[test]
+    fn day11b_test() {
+        assert_eq!(26, day11b(&parse_seat_map(TEST_INPUT).unwrap()).unwrap());
+    }
+
+    #[test]
+    fn should_fail_when_the_seats_never_settle() {
+        let map = parse_seat_map(".LL.\nLLLL\nLLLL\n.LL.").unwrap();
+        assert_eq!(
+            "no solution: still changing after 1000 generations",
+            day11a(&map).unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn should_trace_each_round() {
+        let mut frames = vec![];
+        Day11::trace_part1(&parse_seat_map(TEST_INPUT).unwrap(), &mut frames).unwrap();
+
+        assert_eq!(7, frames.len());
+        assert_eq!("L.LL.LL.LL", frames[0].picture[0]);
+        assert_eq!("#.##.##.##", frames[1].picture[0]);
+        assert_eq!("#.#L.L#.##", frames[6].picture[0]);
+        assert_eq!("round 6: 37 seats taken", frames[6].caption);
+    }
+}
+
+fn is_seat_taken(seat: Point, map: &SeatMap) -> bool {
+    map[seat].unwrap_or(false)
+}
+
+pub fn parse_seat_map(file: &str) -> Result<SeatMap> {
+    Grid::parse(file, |c| match c {
+        'L' => Some(Some(false)),
+        '#' => Some(Some(true)),
+        '.' => Some(None),
+        _ => None,
+    })
+}
+
+fn seats(map: &SeatMap) -> Grid<bool> {
+    map.map(Option::is_some)
+}
+
+fn find_all_neighbours(map: &SeatMap) -> FiniteGrid {
+    FiniteGrid::adjacent(&seats(map))
+}
+
+fn find_all_nearest_seats(map: &SeatMap) -> FiniteGrid {
+    FiniteGrid::line_of_sight(&seats(map))
+}
+
+//  An empty seat is taken if none of its neighbours are, and a taken seat is
+//  left once too many of them are.
+fn seating(
+    map: &SeatMap,
+    neighbours: FiniteGrid,
+    max_neighbours: usize,
+) -> Automaton<FiniteGrid, impl Rule> {
+    Automaton::new(
+        neighbours,
+        move |taken: bool, neighbours: usize| {
+            if taken {
+                neighbours < max_neighbours
+            } else {
+                neighbours == 0
+            }
+        },
+        map.points().filter(|&seat| is_seat_taken(seat, map)),
+    )
+}
+
+fn seating_frame(map: &SeatMap, seating: &Automaton<FiniteGrid, impl Rule>) -> Frame {
+    let picture = (0..map.height())
+        .map(|y| {
+            (0..map.width())
+                .map(|x| match map[(x, y)] {
+                    None => '.',
+                    Some(_) if seating.is_alive((x, y)) => '#',
+                    Some(_) => 'L',
+                })
+                .collect()
+        })
+        .collect();
+
+    Frame::new(
+        seating.generation(),
+        format!(
+            "round {}: {} seats taken",
+            seating.generation(),
+            seating.population()
+        ),
+    )
+    .picture(picture)
+    .state("taken", seating.population())
+}
+
+//  The puzzle's maps settle within a hundred or so rounds, so one still
+//  changing after this many is taken never to settle.
+const ROUND_LIMIT: usize = 1000;
+
+//  Steps until nothing changes and counts the taken seats. Not every map
+//  settles; some go round the same states forever.
+fn count_settled_seats<T: Tracer + ?Sized>(
+    map: &SeatMap,
+    neighbours: FiniteGrid,
+    max_neighbours: usize,
+    tracer: &mut T,
+) -> Result<usize> {
+    let mut seating = seating(map, neighbours, max_neighbours);
+    trace(tracer, || seating_frame(map, &seating));
+    seating.run_until_stable_watching(ROUND_LIMIT, |seating| {
+        trace(tracer, || seating_frame(map, seating))
+    })?;
+
+    Ok(seating.population())
+}
+
+pub fn day11a(map: &SeatMap) -> Result<usize> {
+    count_settled_seats(map, find_all_neighbours(map), 4, &mut NoTrace)
+}
+
+pub fn day11b(map: &SeatMap) -> Result<usize> {
+    count_settled_seats(map, find_all_nearest_seats(map), 5, &mut NoTrace)
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = SeatMap;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<SeatMap> {
+        parse_seat_map(input)
+    }
+
+    fn part1(map: &SeatMap) -> Result<usize> {
+        day11a(map)
+    }
+
+    fn part2(map: &SeatMap) -> Result<usize> {
+        day11b(map)
+    }
+}
+
+impl Traced for Day11 {
+    fn trace_part1(map: &SeatMap, tracer: &mut dyn Tracer) -> Result<usize> {
+        count_settled_seats(map, find_all_neighbours(map), 4, tracer)
+    }
+
+    fn trace_part2(map: &SeatMap, tracer: &mut dyn Tracer) -> Result<usize> {
+        count_settled_seats(map, find_all_nearest_seats(map), 5, tracer)
+    }
+}
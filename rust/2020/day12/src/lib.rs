@@ -1,6 +1,9 @@
-use std::fs::File;
-use std::io::{BufReader, Read, Result};
+use aoc_common::input::lines;
+use aoc_common::trace::{trace, Frame, NoTrace, Traced, Tracer};
+use aoc_common::{Error, Result, Solution};
+use std::fmt;
 use std::ops::{Add, Mul};
+use std::str::FromStr;
 
 macro_rules! point {
     ($x: expr, $y: expr) => {
@@ -20,10 +23,10 @@ F11";
 
     #[test]
     fn should_parse_a_direction() {
-        assert_eq!(Action::MoveNorth(10), Action::from("N10"));
-        assert_eq!(Action::Left(90), Action::from("L90"));
-        assert_eq!(Action::Forward(5), Action::from("F5"));
-        assert_eq!(Action::Right(270), Action::from("R270"));
+        assert_eq!(Action::MoveNorth(10), "N10".parse().unwrap());
+        assert_eq!(Action::Left(90), "L90".parse().unwrap());
+        assert_eq!(Action::Forward(5), "F5".parse().unwrap());
+        assert_eq!(Action::Right(270), "R270".parse().unwrap());
     }
 
     #[test]
@@ -181,14 +184,43 @@ F11";
 
     #[test]
     fn day12a_test() {
-        let actions = TEST_INPUT.lines().map(Action::from).collect();
-        assert_eq!(25, day12a(&actions));
+        let actions = parse_input(TEST_INPUT).unwrap();
+        assert_eq!(25, day12a(&actions).unwrap());
     }
 
     #[test]
     fn day12b_test() {
-        let actions = TEST_INPUT.lines().map(Action::from).collect();
-        assert_eq!(286, day12b(&actions));
+        let actions = parse_input(TEST_INPUT).unwrap();
+        assert_eq!(286, day12b(&actions).unwrap());
+    }
+
+    #[test]
+    fn should_report_where_an_action_is_invalid() {
+        assert_eq!(
+            "line 2, column 1: unknown action 'X'",
+            parse_input("F10\nX3").unwrap_err().to_string()
+        );
+        assert_eq!(
+            "line 1, column 2: '45' is not a multiple of 90 degrees",
+            parse_input("R45").unwrap_err().to_string()
+        );
+        assert!(parse_input("F-2").is_err());
+    }
+
+    #[test]
+    fn should_trace_each_action() {
+        let actions = parse_input(TEST_INPUT).unwrap();
+        let mut frames = vec![];
+        Day12::trace_part1(&actions, &mut frames).unwrap();
+        assert_eq!(6, frames.len());
+        assert_eq!("R90: ship at (17, 3) facing South", frames[4].caption);
+
+        let mut frames = vec![];
+        Day12::trace_part2(&actions, &mut frames).unwrap();
+        assert_eq!(
+            "F11: ship at (214, -72), waypoint (4, -10)",
+            frames[5].caption
+        );
     }
 }
 
@@ -277,7 +309,7 @@ impl Direction {
 }
 
 #[derive(Debug, PartialEq, Copy, Clone)]
-enum Action {
+pub enum Action {
     MoveNorth(isize),
     MoveSouth(isize),
     MoveEast(isize),
@@ -287,20 +319,58 @@ enum Action {
     Forward(isize),
 }
 
-impl From<&str> for Action {
-    fn from(input: &str) -> Action {
-        let (direction, number) = input.split_at(1);
-        let distance = number.parse::<isize>().unwrap();
+impl FromStr for Action {
+    type Err = Error;
+
+    fn from_str(line: &str) -> Result<Action> {
+        Action::parse(line, line)
+    }
+}
+
+impl Action {
+    //  Any error is reported relative to `input`, which `line` is a part of.
+    fn parse(input: &str, line: &str) -> Result<Action> {
+        let split = line.char_indices().nth(1).map_or(line.len(), |(i, _)| i);
+        let (direction, number) = line.split_at(split);
+        let distance = number.parse::<usize>().map_err(|_| {
+            Error::parse_at(input, number, format!("'{}' is not a distance", number))
+        })? as isize;
+
+        if (direction == "L" || direction == "R") && distance % 90 != 0 {
+            return Err(Error::parse_at(
+                input,
+                number,
+                format!("'{}' is not a multiple of 90 degrees", number),
+            ));
+        }
 
         match direction {
-            "N" => Action::MoveNorth(distance),
-            "S" => Action::MoveSouth(distance),
-            "E" => Action::MoveEast(distance),
-            "W" => Action::MoveWest(distance),
-            "L" => Action::Left(distance),
-            "R" => Action::Right(distance),
-            "F" => Action::Forward(distance),
-            _ => unimplemented!(),
+            "N" => Ok(Action::MoveNorth(distance)),
+            "S" => Ok(Action::MoveSouth(distance)),
+            "E" => Ok(Action::MoveEast(distance)),
+            "W" => Ok(Action::MoveWest(distance)),
+            "L" => Ok(Action::Left(distance)),
+            "R" => Ok(Action::Right(distance)),
+            "F" => Ok(Action::Forward(distance)),
+            _ => Err(Error::parse_at(
+                input,
+                direction,
+                format!("unknown action '{}'", direction),
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Action::MoveNorth(n) => write!(f, "N{}", n),
+            Action::MoveSouth(n) => write!(f, "S{}", n),
+            Action::MoveEast(n) => write!(f, "E{}", n),
+            Action::MoveWest(n) => write!(f, "W{}", n),
+            Action::Left(n) => write!(f, "L{}", n),
+            Action::Right(n) => write!(f, "R{}", n),
+            Action::Forward(n) => write!(f, "F{}", n),
         }
     }
 }
@@ -330,32 +400,68 @@ impl Action {
     }
 }
 
-fn read_file(path: &str) -> Result<String> {
-    let file = File::open(path)?;
-    let mut buf_reader = BufReader::new(file);
-    let mut input = String::new();
-    buf_reader.read_to_string(&mut input)?;
-
-    Ok(input)
+pub fn parse_input(input: &str) -> Result<Vec<Action>> {
+    lines(input)
+        .map(|line| Action::parse(input, line))
+        .collect()
 }
 
-fn day12a(actions: &Vec<Action>) -> isize {
+fn sail<T: Tracer + ?Sized>(actions: &[Action], tracer: &mut T) -> isize {
     let mut current_position = point!(0, 0);
     let mut current_direction = Direction::East;
+    let mut frame = |step: usize, action: String, position: Point, direction: Direction| {
+        trace(tracer, || {
+            Frame::new(
+                step,
+                format!(
+                    "{}: ship at ({}, {}) facing {:?}",
+                    action, position.x, position.y, direction
+                ),
+            )
+            .state("ship", (position.x, position.y))
+            .state("facing", format!("{:?}", direction))
+        })
+    };
+    frame(
+        0,
+        String::from("start"),
+        current_position,
+        current_direction,
+    );
 
-    for action in actions {
+    for (step, action) in actions.iter().enumerate() {
         current_position = action.next_position(current_position, current_direction);
         current_direction = action.next_direction(current_direction);
+        frame(
+            step + 1,
+            action.to_string(),
+            current_position,
+            current_direction,
+        );
     }
 
     current_position.get_manhattan()
 }
 
-fn day12b(actions: &Vec<Action>) -> isize {
+fn sail_by_waypoint<T: Tracer + ?Sized>(actions: &[Action], tracer: &mut T) -> isize {
     let mut current_position = point!(0, 0);
     let mut waypoint = point!(10, 1);
+    let mut frame = |step: usize, action: String, position: Point, waypoint: Point| {
+        trace(tracer, || {
+            Frame::new(
+                step,
+                format!(
+                    "{}: ship at ({}, {}), waypoint ({}, {})",
+                    action, position.x, position.y, waypoint.x, waypoint.y
+                ),
+            )
+            .state("ship", (position.x, position.y))
+            .state("waypoint", (waypoint.x, waypoint.y))
+        })
+    };
+    frame(0, String::from("start"), current_position, waypoint);
 
-    for action in actions {
+    for (step, action) in actions.iter().enumerate() {
         match action {
             Action::Left(angle) => {
                 waypoint = (0..(angle / 90)).fold(waypoint, |dir, _| dir.rotate_left())
@@ -366,33 +472,46 @@ fn day12b(actions: &Vec<Action>) -> isize {
             Action::Forward(units) => current_position = current_position + (waypoint * *units),
             _ => waypoint = action.next_position(waypoint, Direction::East),
         }
+        frame(step + 1, action.to_string(), current_position, waypoint);
     }
 
     current_position.get_manhattan()
 }
 
-fn main() -> Result<()> {
-    let input = read_file("input")?;
-    let actions = input.lines().map(Action::from).collect();
+pub fn day12a(actions: &[Action]) -> Result<isize> {
+    Ok(sail(actions, &mut NoTrace))
+}
 
-    use std::time::Instant;
-    let total = Instant::now();
+pub fn day12b(actions: &[Action]) -> Result<isize> {
+    Ok(sail_by_waypoint(actions, &mut NoTrace))
+}
 
-    let result = day12a(&actions);
-    println!(
-        "Day 12A - {} ({:.2}ms)",
-        result,
-        total.elapsed().as_millis()
-    );
+pub struct Day12;
 
-    let part2 = Instant::now();
-    let result = day12b(&actions);
-    println!(
-        "Day 12B - {} ({:.2}ms)",
-        result,
-        part2.elapsed().as_millis()
-    );
-    println!("Total ({:.2}ms)", total.elapsed().as_millis());
+impl Solution for Day12 {
+    type Input = Vec<Action>;
+    type Part1 = isize;
+    type Part2 = isize;
 
-    Ok(())
+    fn parse(input: &str) -> Result<Vec<Action>> {
+        parse_input(input)
+    }
+
+    fn part1(actions: &Vec<Action>) -> Result<isize> {
+        day12a(actions)
+    }
+
+    fn part2(actions: &Vec<Action>) -> Result<isize> {
+        day12b(actions)
+    }
+}
+
+impl Traced for Day12 {
+    fn trace_part1(actions: &Vec<Action>, tracer: &mut dyn Tracer) -> Result<isize> {
+        Ok(sail(actions, tracer))
+    }
+
+    fn trace_part2(actions: &Vec<Action>, tracer: &mut dyn Tracer) -> Result<isize> {
+        Ok(sail_by_waypoint(actions, tracer))
+    }
 }
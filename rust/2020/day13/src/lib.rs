@@ -0,0 +1,140 @@
+use aoc_common::numtheory::crt;
+use aoc_common::{Error, Result, Solution};
+
+#[cfg(test)]
+mod day13_tests {
+    use super::*;
+
+    #[test]
+    fn should_find_time_until_next_bus() {
+        assert_eq!(6, time_until_next(939, 7));
+        assert_eq!(10, time_until_next(939, 13));
+        assert_eq!(5, time_until_next(939, 59));
+        assert_eq!(22, time_until_next(939, 31));
+        assert_eq!(11, time_until_next(939, 19));
+    }
+
+    #[test]
+    fn day13a_test() {
+        let bus_ids = vec![
+            Some(7),
+            Some(13),
+            None,
+            None,
+            Some(59),
+            None,
+            Some(31),
+            Some(19),
+        ];
+        assert_eq!(295, day13a(939, &bus_ids).unwrap())
+    }
+
+    #[test]
+    fn day13b_test() {
+        let bus_ids = vec![
+            Some(7),
+            Some(13),
+            None,
+            None,
+            Some(59),
+            None,
+            Some(31),
+            Some(19),
+        ];
+        assert_eq!(1068781, day13b(&bus_ids).unwrap())
+    }
+
+    #[test]
+    fn should_line_up_buses_that_share_factors() {
+        assert_eq!(4, day13b(&[Some(4), None, Some(6)]).unwrap());
+        assert!(day13b(&[Some(4), Some(6)]).is_err());
+    }
+
+    #[test]
+    fn should_report_where_a_bus_id_is_invalid() {
+        assert_eq!(
+            "line 2, column 6: 'y' is not a bus id",
+            parse_input("939\n7,13,y,x").unwrap_err().to_string()
+        );
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<(i64, Vec<Option<i64>>)> {
+    let mut lines = input.lines();
+    let (time_line, bus_line) = match (lines.next(), lines.next()) {
+        (Some(time_line), Some(bus_line)) => (time_line.trim(), bus_line.trim()),
+        _ => {
+            return Err(Error::invalid_shape(
+                "expected a time and a list of bus ids",
+            ))
+        }
+    };
+
+    let current_time = time_line
+        .parse::<i64>()
+        .map_err(|_| Error::parse_at(input, time_line, format!("'{}' is not a time", time_line)))?;
+    let bus_ids = bus_line
+        .split(",")
+        .map(|bus_id| match bus_id {
+            "x" => Ok(None),
+            n => match n.parse::<i64>() {
+                Ok(id) if id > 0 => Ok(Some(id)),
+                _ => Err(Error::parse_at(
+                    input,
+                    n,
+                    format!("'{}' is not a bus id", n),
+                )),
+            },
+        })
+        .collect::<Result<Vec<Option<i64>>>>()?;
+
+    Ok((current_time, bus_ids))
+}
+
+fn time_until_next(current_time: i64, bus_id: i64) -> i64 {
+    bus_id - (current_time % bus_id)
+}
+
+pub fn day13a(current_time: i64, bus_ids: &[Option<i64>]) -> Result<i64> {
+    let result = bus_ids
+        .iter()
+        .filter_map(|&x| x)
+        .map(|bus_id| (bus_id, time_until_next(current_time, bus_id)))
+        .min_by(|a, b| a.1.cmp(&b.1))
+        .ok_or_else(|| Error::no_solution("there are no buses in service"))?;
+
+    Ok(result.0 * result.1)
+}
+
+//  Bus i leaves at t + i, so t = -i modulo each bus id.
+pub fn day13b(bus_ids: &[Option<i64>]) -> Result<i64> {
+    let congruences: Vec<(i64, i64)> = bus_ids
+        .iter()
+        .enumerate()
+        .filter_map(|(i, &opt)| opt.map(|bus_id| (-(i as i64), bus_id)))
+        .collect();
+
+    crt(&congruences)
+        .map(|(time, _)| time)
+        .ok_or_else(|| Error::no_solution("the buses never leave at the right offsets"))
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = (i64, Vec<Option<i64>>);
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<(i64, Vec<Option<i64>>)> {
+        parse_input(input)
+    }
+
+    fn part1(input: &(i64, Vec<Option<i64>>)) -> Result<i64> {
+        day13a(input.0, &input.1)
+    }
+
+    fn part2(input: &(i64, Vec<Option<i64>>)) -> Result<i64> {
+        day13b(&input.1)
+    }
+}
@@ -1,9 +1,9 @@
+use aoc_common::input::lines;
+use aoc_common::{Error, Result, Solution};
 use std::collections::HashMap;
-use std::fs::File;
-use std::io::{BufReader, Read, Result};
 
 #[derive(Debug, PartialEq)]
-enum Command {
+pub enum Command {
     SetMask(String),
     SetMemory(usize, usize),
 }
@@ -24,7 +24,7 @@ mem[26] = 1";
 
     #[test]
     fn should_parse_the_input() {
-        let commands = parse_input(TEST_INPUT);
+        let commands = parse_input(TEST_INPUT).unwrap();
         assert_eq!(
             Command::SetMask(String::from("XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X")),
             commands[0]
@@ -79,18 +79,29 @@ mem[26] = 1";
 
     #[test]
     fn day14a_test() {
-        let commands = parse_input(TEST_INPUT);
-        assert_eq!(165, day14a(&commands));
+        let commands = parse_input(TEST_INPUT).unwrap();
+        assert_eq!(165, day14a(&commands).unwrap());
     }
 
     #[test]
     fn day14b_test() {
-        let commands = parse_input(TEST2_INPUT);
-        assert_eq!(208, day14b(&commands));
+        let commands = parse_input(TEST2_INPUT).unwrap();
+        assert_eq!(208, day14b(&commands).unwrap());
+    }
+
+    #[test]
+    fn should_reject_malformed_instructions() {
+        assert_eq!(
+            "line 2, column 1: invalid instruction 'mem[x] = 11'",
+            parse_input("mask = XXXXXXXXXXXXXXXXXXXXXXXXXXXXX1XXXX0X\nmem[x] = 11")
+                .unwrap_err()
+                .to_string()
+        );
+        assert!(parse_input("mask = XX1").is_err());
     }
 }
 
-fn calculate_memory_locations(location: usize, mask: &String) -> Vec<usize> {
+fn calculate_memory_locations(location: usize, mask: &str) -> Vec<usize> {
     let bits: Vec<usize> = format!("{:b}", location)
         .chars()
         .rev()
@@ -116,7 +127,7 @@ fn calculate_memory_locations(location: usize, mask: &String) -> Vec<usize> {
         })
 }
 
-fn convert_mask(mask: &String) -> (usize, usize) {
+fn convert_mask(mask: &str) -> (usize, usize) {
     mask.chars()
         .map(|c| match c {
             '1' => (1, 1),
@@ -129,8 +140,13 @@ fn convert_mask(mask: &String) -> (usize, usize) {
 }
 
 fn parse_mask(input: &str) -> Option<Command> {
-    let bitmask = input.split("=").nth(1)?;
-    Some(Command::SetMask(bitmask.trim().to_string()))
+    let bitmask = input.split("=").nth(1)?.trim();
+
+    if bitmask.len() != 36 || bitmask.chars().any(|c| !"01X".contains(c)) {
+        return None;
+    }
+
+    Some(Command::SetMask(bitmask.to_string()))
 }
 
 fn parse_memset(input: &str) -> Option<Command> {
@@ -138,37 +154,28 @@ fn parse_memset(input: &str) -> Option<Command> {
     let memory_location = input_parts
         .next()?
         .trim()
-        .trim_start_matches("mem[")
-        .trim_end_matches("]")
+        .strip_prefix("mem[")?
+        .strip_suffix("]")?
         .parse::<usize>()
-        .unwrap();
-    let new_value = input_parts.next()?.trim().parse::<usize>().unwrap();
+        .ok()?;
+    let new_value = input_parts.next()?.trim().parse::<usize>().ok()?;
     Some(Command::SetMemory(memory_location, new_value))
 }
 
-fn parse_input(input: &str) -> Vec<Command> {
-    input
-        .trim()
-        .lines()
-        .map(|line| match line {
-            l if l.starts_with("mask") => parse_mask(l),
-            l if l.starts_with("mem") => parse_memset(l),
-            _ => None,
+pub fn parse_input(input: &str) -> Result<Vec<Command>> {
+    lines(input)
+        .map(|line| {
+            match line {
+                l if l.starts_with("mask") => parse_mask(l),
+                l if l.starts_with("mem") => parse_memset(l),
+                _ => None,
+            }
+            .ok_or_else(|| Error::parse_at(input, line, format!("invalid instruction '{}'", line)))
         })
-        .filter_map(|x| x)
         .collect()
 }
 
-fn read_file(path: &str) -> Result<String> {
-    let file = File::open(path)?;
-    let mut buf_reader = BufReader::new(file);
-    let mut input = String::new();
-    buf_reader.read_to_string(&mut input)?;
-
-    Ok(input)
-}
-
-fn day14a(program: &Vec<Command>) -> usize {
+pub fn day14a(program: &[Command]) -> Result<usize> {
     let mut and_mask = 68719476735;
     let mut or_mask = 0;
     let mut memory = HashMap::<usize, usize>::new();
@@ -186,10 +193,10 @@ fn day14a(program: &Vec<Command>) -> usize {
         }
     }
 
-    memory.values().filter(|&&x| x > 0).sum()
+    Ok(memory.values().filter(|&&x| x > 0).sum())
 }
 
-fn day14b(program: &Vec<Command>) -> usize {
+pub fn day14b(program: &[Command]) -> Result<usize> {
     let mut mask: String = String::from("000000000000000000000000000000000000");
     let mut memory = HashMap::<usize, usize>::new();
 
@@ -206,31 +213,25 @@ fn day14b(program: &Vec<Command>) -> usize {
         }
     }
 
-    memory.values().filter(|&&x| x > 0).sum()
+    Ok(memory.values().filter(|&&x| x > 0).sum())
 }
 
-fn main() -> Result<()> {
-    let input = read_file("input")?;
-    let program = parse_input(&input);
-
-    use std::time::Instant;
-    let total = Instant::now();
-
-    let result = day14a(&program);
-    println!(
-        "Day 14A - {} ({:.2}ms)",
-        result,
-        total.elapsed().as_millis()
-    );
-
-    let part2 = Instant::now();
-    let result = day14b(&program);
-    println!(
-        "Day 14B - {} ({:.2}ms)",
-        result,
-        part2.elapsed().as_millis()
-    );
-    println!("Total ({:.2}ms)", total.elapsed().as_millis());
-
-    Ok(())
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Vec<Command>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<Command>> {
+        parse_input(input)
+    }
+
+    fn part1(program: &Vec<Command>) -> Result<usize> {
+        day14a(program)
+    }
+
+    fn part2(program: &Vec<Command>) -> Result<usize> {
+        day14b(program)
+    }
 }
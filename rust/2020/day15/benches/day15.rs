@@ -0,0 +1,23 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use day15::{parse_input, play_game};
+
+static TEST_INPUT: &str = "0,3,6";
+
+//  The game is the same whatever the input, so scale it by the number of turns.
+fn day15_benches(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day15");
+    group.sample_size(10);
+
+    let starting_numbers = parse_input(TEST_INPUT).unwrap();
+
+    for &turns in &[2020, 300000, 3000000, 30000000] {
+        group.bench_with_input(BenchmarkId::new("turns", turns), &turns, |b, &turns| {
+            b.iter(|| play_game(&starting_numbers, turns))
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, day15_benches);
+criterion_main!(benches);
@@ -0,0 +1,150 @@
+use aoc_common::{Error, Result, Solution};
+use std::collections::HashMap;
+
+//  The turn each number was last spoken on. Every number spoken after the
+//  starting ones is a gap between turns, so is less than the last turn and
+//  fits the table. Only starting numbers can be larger, and those are kept
+//  to one side.
+struct LastSeen {
+    small: Vec<Option<usize>>,
+    large: HashMap<usize, usize>,
+}
+
+#[cfg(test)]
+mod day15_test {
+    use super::*;
+
+    #[test]
+    fn day15a_test() {
+        assert_eq!(436, day15a(&[0, 3, 6]).unwrap());
+        assert_eq!(1, day15a(&[1, 3, 2]).unwrap());
+        assert_eq!(10, day15a(&[2, 1, 3]).unwrap());
+        assert_eq!(27, day15a(&[1, 2, 3]).unwrap());
+        assert_eq!(78, day15a(&[2, 3, 1]).unwrap());
+        assert_eq!(438, day15a(&[3, 2, 1]).unwrap());
+        assert_eq!(1836, day15a(&[3, 1, 2]).unwrap());
+    }
+
+    #[test]
+    fn day15b_test() {
+        assert_eq!(175594, day15b(&[0, 3, 6]).unwrap());
+        assert_eq!(2578, day15b(&[1, 3, 2]).unwrap());
+        assert_eq!(3544142, day15b(&[2, 1, 3]).unwrap());
+        assert_eq!(261214, day15b(&[1, 2, 3]).unwrap());
+        assert_eq!(6895259, day15b(&[2, 3, 1]).unwrap());
+        assert_eq!(18, day15b(&[3, 2, 1]).unwrap());
+        assert_eq!(362, day15b(&[3, 1, 2]).unwrap());
+    }
+
+    #[test]
+    fn should_handle_large_starting_numbers() {
+        assert_eq!(0, day15a(&[5000, 1]).unwrap());
+        //  Numbers larger than the last turn are only ever spoken at the start
+        let expected = day15a(&[0, 3, 5000]).unwrap();
+        assert_eq!(expected, day15a(&[0, 3, usize::MAX]).unwrap());
+        assert_eq!(expected, day15a(&[0, 3, 99999999999999]).unwrap());
+        assert_eq!(
+            day15a(&[5000, 5, 5000]).unwrap(),
+            day15a(&[99999999999999, 5, 99999999999999]).unwrap()
+        );
+    }
+
+    #[test]
+    fn should_reject_bad_starting_numbers() {
+        assert_eq!(
+            "line 1, column 5: 'x' is not a starting number",
+            parse_input("0,3,x").unwrap_err().to_string()
+        );
+        assert!(parse_input("").is_err());
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<usize>> {
+    input
+        .trim()
+        .split(',')
+        .map(|n| {
+            let n = n.trim();
+            n.parse::<usize>()
+                .map_err(|_| Error::parse_at(input, n, format!("'{}' is not a starting number", n)))
+        })
+        .collect()
+}
+
+impl LastSeen {
+    fn new(last_turn: usize) -> LastSeen {
+        LastSeen {
+            small: vec![None; last_turn],
+            large: HashMap::new(),
+        }
+    }
+
+    //  Records the number as spoken on this turn, returning the turn it was
+    //  last spoken on before.
+    fn replace(&mut self, number: usize, turn: usize) -> Option<usize> {
+        match self.small.get_mut(number) {
+            Some(seen) => seen.replace(turn),
+            None => self.large.insert(number, turn),
+        }
+    }
+}
+
+pub fn play_game(starting_numbers: &[usize], last_turn: usize) -> Result<usize> {
+    if last_turn == 0 {
+        return Err(Error::invalid_shape("the game has no turns"));
+    }
+
+    let mut last_number = *starting_numbers
+        .last()
+        .ok_or_else(|| Error::invalid_shape("there are no starting numbers"))?;
+
+    if starting_numbers.len() >= last_turn {
+        return Ok(starting_numbers[last_turn - 1]);
+    }
+
+    let mut last_seen = LastSeen::new(last_turn);
+
+    for (turn, &number) in starting_numbers[..starting_numbers.len() - 1]
+        .iter()
+        .enumerate()
+    {
+        last_seen.replace(number, turn);
+    }
+
+    for turn in (starting_numbers.len() - 1)..(last_turn - 1) {
+        last_number = match last_seen.replace(last_number, turn) {
+            Some(last_turn) => turn - last_turn,
+            None => 0,
+        };
+    }
+
+    Ok(last_number)
+}
+
+pub fn day15a(starting_numbers: &[usize]) -> Result<usize> {
+    play_game(starting_numbers, 2020)
+}
+
+pub fn day15b(starting_numbers: &[usize]) -> Result<usize> {
+    play_game(starting_numbers, 30000000)
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Vec<usize>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<usize>> {
+        parse_input(input)
+    }
+
+    fn part1(starting_numbers: &Vec<usize>) -> Result<usize> {
+        day15a(starting_numbers)
+    }
+
+    fn part2(starting_numbers: &Vec<usize>) -> Result<usize> {
+        day15b(starting_numbers)
+    }
+}
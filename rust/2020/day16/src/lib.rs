@@ -0,0 +1,431 @@
+use aoc_common::input::groups;
+use aoc_common::{Error, Result, Solution};
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+#[derive(Debug, PartialEq)]
+struct TicketSpec {
+    fields: HashMap<String, HashSet<usize>>,
+    all_fields: HashSet<usize>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Input {
+    spec: TicketSpec,
+    your_ticket: Vec<usize>,
+    other_tickets: Vec<Vec<usize>>,
+}
+
+#[cfg(test)]
+mod day16_tests {
+    use super::*;
+
+    static TEST_INPUT: &str = "class: 1-3 or 5-7
+row: 6-11 or 33-44
+seat: 13-40 or 45-50
+
+your ticket:
+7,1,14
+
+nearby tickets:
+7,3,47
+40,4,50
+55,2,20
+38,6,12
+";
+
+    static TEST2_INPUT: &str = "class: 0-1 or 4-19
+row: 0-5 or 8-19
+seat: 0-13 or 16-19
+
+your ticket:
+11,12,13
+
+nearby tickets:
+3,9,18
+15,1,5
+5,14,9";
+
+    fn get_test_input() -> Input {
+        let fields: HashMap<String, HashSet<usize>> = [
+            ("class", [(1..=3), (5..=7)]),
+            ("row", [(6..=11), (33..=44)]),
+            ("seat", [(13..=40), (45..=50)]),
+        ]
+        .iter()
+        .map(|(field, ranges)| {
+            (
+                field.to_string(),
+                ranges.iter().flat_map(|it| it.clone()).collect(),
+            )
+        })
+        .collect();
+        Input {
+            spec: TicketSpec {
+                fields: fields.clone(),
+                all_fields: fields.values().flatten().copied().collect(),
+            },
+            your_ticket: vec![7, 1, 14],
+            other_tickets: vec![
+                vec![7, 3, 47],
+                vec![40, 4, 50],
+                vec![55, 2, 20],
+                vec![38, 6, 12],
+            ],
+        }
+    }
+
+    fn get_test2_input() -> Input {
+        let fields: HashMap<String, HashSet<usize>> = [
+            ("class", [(0..=1), (4..=19)]),
+            ("row", [(0..=5), (8..=19)]),
+            ("seat", [(0..=13), (16..=19)]),
+        ]
+        .iter()
+        .map(|(field, ranges)| {
+            (
+                field.to_string(),
+                ranges.iter().flat_map(|it| it.clone()).collect(),
+            )
+        })
+        .collect();
+        Input {
+            spec: TicketSpec {
+                fields: fields.clone(),
+                all_fields: fields.values().flatten().copied().collect(),
+            },
+            your_ticket: vec![11, 12, 13],
+            other_tickets: vec![vec![3, 9, 18], vec![15, 1, 5], vec![5, 14, 9]],
+        }
+    }
+
+    #[test]
+    fn should_parse_an_input() {
+        assert_eq!(get_test_input(), parse_input(TEST_INPUT).unwrap());
+        assert_eq!(get_test2_input(), parse_input(TEST2_INPUT).unwrap());
+    }
+
+    #[test]
+    fn should_find_invalid_values() {
+        let input = get_test_input();
+        let all_ticket_fields: Vec<usize> = input.other_tickets.iter().flatten().copied().collect();
+        assert_eq!(
+            vec![4, 55, 12],
+            find_invalid_values(&input.spec.all_fields, &all_ticket_fields)
+        );
+    }
+
+    #[test]
+    fn should_filter_invalid_tickets() {
+        assert_eq!(vec![vec![7, 3, 47]], find_valid_tickets(&get_test_input()));
+
+        let input2 = get_test2_input();
+        assert_eq!(input2.other_tickets, find_valid_tickets(&input2));
+    }
+
+    #[test]
+    fn should_find_matching_fields_for_ticket_index() {
+        let input = get_test2_input();
+        assert_eq!(
+            vec!["row".to_string()],
+            find_matching_fields(&input.spec.fields, &input.other_tickets, 0)
+        );
+        assert_eq!(
+            vec!["class".to_string(), "row".to_string()],
+            find_matching_fields(&input.spec.fields, &input.other_tickets, 1)
+        );
+        assert_eq!(
+            vec!["class".to_string(), "row".to_string(), "seat".to_string()],
+            find_matching_fields(&input.spec.fields, &input.other_tickets, 2)
+        );
+    }
+
+    #[test]
+    fn should_find_matching_indexes_for_fields() {
+        let input = get_test2_input();
+        assert_eq!(
+            vec![0, 1, 2],
+            find_matching_indexes(input.spec.fields.get("row").unwrap(), &input.other_tickets)
+        );
+        assert_eq!(
+            vec![1, 2],
+            find_matching_indexes(
+                input.spec.fields.get("class").unwrap(),
+                &input.other_tickets
+            )
+        );
+        assert_eq!(
+            vec![2],
+            find_matching_indexes(input.spec.fields.get("seat").unwrap(), &input.other_tickets)
+        );
+    }
+
+    #[test]
+    fn should_find_field_order() {
+        assert_eq!(
+            vec!["row", "class", "seat"],
+            find_field_order(&get_test2_input()).unwrap()
+        );
+    }
+
+    #[test]
+    fn should_reject_malformed_notes() {
+        assert_eq!(
+            "line 2, column 6: invalid range '6-x'",
+            parse_input(&TEST_INPUT.replace("6-11", "6-x"))
+                .unwrap_err()
+                .to_string()
+        );
+        assert_eq!(
+            "line 10, column 1: expected 3 values, found '40,4'",
+            parse_input(&TEST_INPUT.replace("40,4,50", "40,4"))
+                .unwrap_err()
+                .to_string()
+        );
+        assert!(parse_input(TEST_INPUT.split("nearby").next().unwrap()).is_err());
+    }
+
+    #[test]
+    fn should_fail_when_fields_cannot_be_told_apart() {
+        let input = parse_input(
+            "a: 0-9 or 10-19\nb: 0-9 or 10-19\n\nyour ticket:\n1,2\n\nnearby tickets:\n3,4",
+        )
+        .unwrap();
+        assert!(day16b(&input).is_err());
+    }
+
+    #[test]
+    fn day16a_test() {
+        assert_eq!(71, day16a(&get_test_input()).unwrap());
+    }
+}
+
+fn find_field_order(input: &Input) -> Result<Vec<&str>> {
+    let valid_tickets = find_valid_tickets(input);
+    let mut field_order: Vec<Option<&str>> = (0..input.spec.fields.len()).map(|_| None).collect();
+    let mut matching_fields: HashMap<&str, Vec<usize>> = input
+        .spec
+        .fields
+        .iter()
+        .map(|(field, values)| {
+            (
+                field.as_str(),
+                find_matching_indexes(values, &valid_tickets),
+            )
+        })
+        .collect();
+
+    while !matching_fields.is_empty() {
+        let remaining = matching_fields.len();
+
+        for (field, indexes) in matching_fields.clone() {
+            let options: Vec<usize> = indexes
+                .iter()
+                .filter(|&n| field_order[*n].is_none())
+                .copied()
+                .collect();
+
+            if options.len() == 1 {
+                field_order[options[0]] = Some(field);
+                matching_fields.remove(field);
+            } else if options.is_empty() {
+                return Err(Error::no_solution(format!(
+                    "no position on the ticket fits the {} field",
+                    field
+                )));
+            }
+        }
+
+        if matching_fields.len() == remaining {
+            return Err(Error::no_solution(
+                "more than one order of fields fits the tickets",
+            ));
+        }
+    }
+
+    Ok(field_order.iter().filter_map(|&f| f).collect())
+}
+
+#[allow(dead_code)]
+fn find_matching_fields(
+    fields: &HashMap<String, HashSet<usize>>,
+    valid_tickets: &[Vec<usize>],
+    ticket_index: usize,
+) -> Vec<String> {
+    let values: Vec<usize> = valid_tickets.iter().map(|v| v[ticket_index]).collect();
+    let mut matching_fields: Vec<String> = fields
+        .iter()
+        .filter(|(_, value_set)| !values.iter().any(|n| !value_set.contains(n)))
+        .map(|(field, _)| field.clone())
+        .collect();
+
+    //  HashMap iteration order is random, so sort to keep the result stable
+    matching_fields.sort();
+    matching_fields
+}
+
+fn find_matching_indexes(values: &HashSet<usize>, valid_tickets: &[Vec<usize>]) -> Vec<usize> {
+    let ticket_len: usize = valid_tickets.first().map_or(0, |ticket| ticket.len());
+
+    (0..ticket_len)
+        .filter(|&index| !valid_tickets.iter().any(|v| !values.contains(&v[index])))
+        .collect()
+}
+
+fn find_valid_tickets(input: &Input) -> Vec<Vec<usize>> {
+    input
+        .other_tickets
+        .iter()
+        .filter(|&ticket| !ticket.iter().any(|n| !input.spec.all_fields.contains(n)))
+        .cloned()
+        .collect()
+}
+
+fn find_invalid_values(valid_fields: &HashSet<usize>, input_values: &[usize]) -> Vec<usize> {
+    input_values
+        .iter()
+        .filter(|n| !valid_fields.contains(n))
+        .copied()
+        .collect()
+}
+
+fn parse_range(input: &str, range: &str) -> Result<(usize, usize)> {
+    let mut bounds = range.splitn(2, '-').map(|n| n.parse::<usize>());
+
+    match (bounds.next(), bounds.next()) {
+        (Some(Ok(low)), Some(Ok(high))) if low <= high => Ok((low, high)),
+        _ => Err(Error::parse_at(
+            input,
+            range,
+            format!("invalid range '{}'", range),
+        )),
+    }
+}
+
+//  Any error is reported relative to `input`, which `line` is a part of.
+fn parse_field(input: &str, line: &str) -> Result<(String, HashSet<usize>)> {
+    let mut parts = line.splitn(2, ":");
+    let field = parts.next().unwrap_or("").trim().to_string();
+    let ranges = parts.next().ok_or_else(|| {
+        Error::parse_at(
+            input,
+            line,
+            format!("expected a field rule, found '{}'", line),
+        )
+    })?;
+
+    let mut options = HashSet::<usize>::new();
+    for range in ranges.split("or") {
+        let (low, high) = parse_range(input, range.trim())?;
+        options.extend(low..=high);
+    }
+
+    Ok((field, options))
+}
+
+fn parse_ticket(input: &str, line: &str, field_count: usize) -> Result<Vec<usize>> {
+    let ticket = line
+        .split(",")
+        .map(|n| {
+            n.parse::<usize>()
+                .map_err(|_| Error::parse_at(input, n, format!("'{}' is not a number", n)))
+        })
+        .collect::<Result<Vec<usize>>>()?;
+
+    if ticket.len() != field_count {
+        return Err(Error::parse_at(
+            input,
+            line,
+            format!("expected {} values, found '{}'", field_count, line),
+        ));
+    }
+
+    Ok(ticket)
+}
+
+fn parse_tickets(
+    input: &str,
+    part: &str,
+    heading: &str,
+    field_count: usize,
+) -> Result<Vec<Vec<usize>>> {
+    let mut lines = part.lines();
+
+    match lines.next() {
+        Some(line) if line.trim() == heading => lines
+            .map(|line| parse_ticket(input, line.trim(), field_count))
+            .collect(),
+        _ => Err(Error::parse_at(
+            input,
+            part,
+            format!("expected '{}'", heading),
+        )),
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Input> {
+    let parts = groups(input);
+    if parts.len() != 3 {
+        return Err(Error::invalid_shape(
+            "expected fields, your ticket and nearby tickets separated by blank lines",
+        ));
+    }
+
+    let fields: HashMap<String, HashSet<usize>> = parts[0]
+        .lines()
+        .map(|line| parse_field(input, line))
+        .collect::<Result<HashMap<String, HashSet<usize>>>>()?;
+    let all_fields: HashSet<usize> = fields
+        .values()
+        .flat_map(|field| field.iter().copied())
+        .collect();
+
+    let mut your_tickets = parse_tickets(input, parts[1], "your ticket:", fields.len())?;
+    if your_tickets.len() != 1 {
+        return Err(Error::invalid_shape(
+            "expected exactly one ticket of your own",
+        ));
+    }
+
+    Ok(Input {
+        your_ticket: your_tickets.remove(0),
+        other_tickets: parse_tickets(input, parts[2], "nearby tickets:", fields.len())?,
+        spec: TicketSpec { fields, all_fields },
+    })
+}
+
+pub fn day16a(program: &Input) -> Result<usize> {
+    let all_values: Vec<usize> = program.other_tickets.iter().flatten().copied().collect();
+    let invalid_fields = find_invalid_values(&program.spec.all_fields, &all_values);
+    Ok(invalid_fields.iter().sum())
+}
+
+pub fn day16b(program: &Input) -> Result<usize> {
+    let field_order = find_field_order(program)?;
+    Ok(field_order
+        .iter()
+        .enumerate()
+        .filter(|(_, field)| field.contains("departure"))
+        .map(|(index, _)| program.your_ticket[index])
+        .product())
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Input;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Input> {
+        parse_input(input)
+    }
+
+    fn part1(program: &Input) -> Result<usize> {
+        day16a(program)
+    }
+
+    fn part2(program: &Input) -> Result<usize> {
+        day16b(program)
+    }
+}
@@ -0,0 +1,38 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use day17::{day17a, day17b, parse_input};
+
+static TEST_INPUT: &str = include_str!("../example.txt");
+
+//  Inputs of other sizes from the generator, seeded by their size.
+fn synthetic_input(size: usize) -> String {
+    aoc_generate::generate("2020", 17, size as u64, Some(size))
+        .unwrap()
+        .input
+}
+
+fn day17_benches(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day17");
+    group.sample_size(10);
+
+    let inputs = vec![
+        (String::from("example"), String::from(TEST_INPUT)),
+        (String::from("8x8"), synthetic_input(8)),
+        (String::from("16x16"), synthetic_input(16)),
+    ];
+
+    for (name, input) in &inputs {
+        let state = parse_input(input).unwrap();
+
+        group.bench_with_input(BenchmarkId::new("part1", name), &state, |b, state| {
+            b.iter(|| day17a(state))
+        });
+        group.bench_with_input(BenchmarkId::new("part2", name), &state, |b, state| {
+            b.iter(|| day17b(state))
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, day17_benches);
+criterion_main!(benches);
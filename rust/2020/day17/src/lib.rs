@@ -0,0 +1,231 @@
+use aoc_common::automaton::{Automaton, BirthSurvival, Cartesian};
+use aoc_common::trace::{bounds, draw_points, trace, Frame, NoTrace, Traced, Tracer};
+use aoc_common::{Error, Result, Solution};
+use std::collections::BTreeMap;
+
+//  A cube in the starting slice.
+type Point = (isize, isize);
+
+#[cfg(test)]
+mod day17_tests {
+    use super::*;
+
+    static TEST_INPUT: &str = include_str!("../example.txt");
+
+    fn get_test_input() -> Vec<Point> {
+        vec![(1, 0), (2, 1), (0, 2), (1, 2), (2, 2)]
+    }
+
+    #[test]
+    fn should_parse_input() {
+        assert_eq!(get_test_input(), parse_input(TEST_INPUT).unwrap());
+        assert_eq!(
+            "line 2, column 3: unknown cube state 'o'",
+            parse_input(".#.\n..o\n###").unwrap_err().to_string()
+        );
+    }
+
+    fn update_point(point: [isize; 3], active: &[[isize; 3]]) -> bool {
+        let mut automaton = pocket_dimension(active.iter().copied());
+        automaton.step();
+        automaton.is_alive(point)
+    }
+
+    fn get_test_cubes() -> Vec<[isize; 3]> {
+        get_test_input().iter().map(|&point| lift(point)).collect()
+    }
+
+    #[test]
+    fn should_mark_active_if_active_and_has_2_or_3_neighbours() {
+        let input = get_test_cubes();
+        assert!(update_point([1, 2, 0], &input));
+        assert!(update_point([2, 2, 0], &input));
+        assert!(update_point([2, 1, 0], &input));
+    }
+
+    #[test]
+    fn should_mark_inactive_if_active_and_less_than_2_or_more_than_3_neighbours() {
+        let input = get_test_cubes();
+        assert!(!update_point([1, 0, 0], &input));
+        assert!(!update_point([0, 2, 0], &input));
+    }
+
+    #[test]
+    fn should_mark_active_if_inactive_and_has_3_neighbours() {
+        let input = get_test_cubes();
+        assert!(update_point([0, 1, 0], &input));
+        assert!(update_point([1, 3, 0], &input));
+    }
+
+    #[test]
+    fn should_mark_inactive_if_inactive_and_does_not_have_3_neighbours() {
+        let input = get_test_cubes();
+        assert!(!update_point([0, 0, 0], &input));
+        assert!(!update_point([1, 1, 0], &input));
+    }
+
+    #[test]
+    fn should_run_a_cycle_in_each_dimension() {
+        let mut automaton = pocket_dimension(get_test_cubes());
+        automaton.step();
+        assert_eq!(11, automaton.population());
+
+        let mut automaton =
+            pocket_dimension(get_test_input().iter().map(|&point| lift::<4>(point)));
+        automaton.step();
+        assert_eq!(29, automaton.population());
+    }
+
+    #[test]
+    fn day17a_test() {
+        assert_eq!(112, day17a(&get_test_input()).unwrap());
+    }
+
+    #[test]
+    fn day17b_test() {
+        assert_eq!(848, day17b(&get_test_input()).unwrap());
+    }
+
+    #[test]
+    fn should_trace_each_cycle() {
+        let mut frames = vec![];
+        Day17::trace_part1(&get_test_input(), &mut frames).unwrap();
+
+        assert_eq!(7, frames.len());
+        assert_eq!("cycle 0: 5 active cubes", frames[0].caption);
+        assert_eq!(vec![".#.", "..#", "###"], frames[0].picture);
+        assert_eq!("cycle 1: 11 active cubes", frames[1].caption);
+        assert_eq!(
+            vec!["#.. #.# #..", "..# .## ..#", ".#. .#. .#."],
+            frames[1].picture
+        );
+        assert_eq!("cycle 6: 112 active cubes", frames[6].caption);
+    }
+}
+
+//  Cubes start in a single 2D slice, so every other coordinate is 0.
+fn lift<const N: usize>((x, y): Point) -> [isize; N] {
+    let mut cube = [0; N];
+    cube[0] = x;
+    cube[1] = y;
+    cube
+}
+
+fn pocket_dimension<const N: usize>(
+    active: impl IntoIterator<Item = [isize; N]>,
+) -> Automaton<Cartesian<N>, BirthSurvival> {
+    Automaton::new(Cartesian::new(), BirthSurvival::new(&[3], &[2, 3]), active)
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Point>> {
+    let mut active = Vec::<Point>::new();
+
+    for (y, line) in input.lines().enumerate() {
+        for (x, (i, c)) in line.trim_end().char_indices().enumerate() {
+            match c {
+                '#' => {
+                    active.push((x as isize, y as isize));
+                }
+                '.' => {}
+                _ => {
+                    return Err(Error::parse_at(
+                        input,
+                        &line[i..],
+                        format!("unknown cube state '{}'", c),
+                    ))
+                }
+            }
+        }
+    }
+
+    Ok(active)
+}
+
+//  Draws each 2D slice of the active cubes side by side, ordered by their
+//  other coordinates.
+fn cycle_frame<const N: usize>(automaton: &Automaton<Cartesian<N>, BirthSurvival>) -> Frame {
+    let mut cubes: Vec<[isize; N]> = automaton.live().collect();
+    cubes.sort_unstable();
+
+    let mut slices = BTreeMap::<&[isize], Vec<Point>>::new();
+    for cube in &cubes {
+        slices
+            .entry(&cube[2..])
+            .or_default()
+            .push((cube[0], cube[1]));
+    }
+
+    let mut picture = vec![];
+    if let Some(bounds) = bounds(cubes.iter().map(|cube| (cube[0], cube[1]))) {
+        for (i, slice) in slices.into_values().enumerate() {
+            for (y, row) in draw_points(slice, bounds).into_iter().enumerate() {
+                if i == 0 {
+                    picture.push(row);
+                } else {
+                    picture[y] += " ";
+                    picture[y] += &row;
+                }
+            }
+        }
+    }
+
+    Frame::new(
+        automaton.generation(),
+        format!(
+            "cycle {}: {} active cubes",
+            automaton.generation(),
+            automaton.population()
+        ),
+    )
+    .picture(picture)
+    .state("active", automaton.population())
+    .state("cubes", cubes)
+}
+
+fn run_program<const N: usize, T: Tracer + ?Sized>(
+    initial_state: &[Point],
+    tracer: &mut T,
+) -> usize {
+    let mut automaton = pocket_dimension(initial_state.iter().map(|&point| lift::<N>(point)));
+    trace(tracer, || cycle_frame(&automaton));
+    automaton.run_watching(6, |automaton| trace(tracer, || cycle_frame(automaton)));
+    automaton.population()
+}
+
+pub fn day17a(initial_state: &[Point]) -> Result<usize> {
+    Ok(run_program::<3, _>(initial_state, &mut NoTrace))
+}
+
+pub fn day17b(initial_state: &[Point]) -> Result<usize> {
+    Ok(run_program::<4, _>(initial_state, &mut NoTrace))
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Vec<Point>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<Point>> {
+        parse_input(input)
+    }
+
+    fn part1(initial_state: &Vec<Point>) -> Result<usize> {
+        day17a(initial_state)
+    }
+
+    fn part2(initial_state: &Vec<Point>) -> Result<usize> {
+        day17b(initial_state)
+    }
+}
+
+impl Traced for Day17 {
+    fn trace_part1(initial_state: &Vec<Point>, tracer: &mut dyn Tracer) -> Result<usize> {
+        Ok(run_program::<3, _>(initial_state, tracer))
+    }
+
+    fn trace_part2(initial_state: &Vec<Point>, tracer: &mut dyn Tracer) -> Result<usize> {
+        Ok(run_program::<4, _>(initial_state, tracer))
+    }
+}
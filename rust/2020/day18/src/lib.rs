@@ -1,6 +1,6 @@
+use aoc_common::input::lines;
+use aoc_common::{Error, Result, Solution};
 use std::collections::HashMap;
-use std::fs::File;
-use std::io::{BufReader, Read, Result};
 
 #[cfg(test)]
 mod day18_tests {
@@ -44,7 +44,7 @@ mod day18_tests {
             Some(71),
             calculate(
                 &get_part1_precedence(),
-                &vec!["1", "+", "2", "*", "3", "+", "4", "*", "5", "+", "6"]
+                &["1", "+", "2", "*", "3", "+", "4", "*", "5", "+", "6"]
                     .iter()
                     .map(|s| s.to_string())
                     .collect()
@@ -54,7 +54,7 @@ mod day18_tests {
             Some(26),
             calculate(
                 &get_part1_precedence(),
-                &vec!["2", "*", "3", "+", "(", "4", "*", "5", ")"]
+                &["2", "*", "3", "+", "(", "4", "*", "5", ")"]
                     .iter()
                     .map(|s| s.to_string())
                     .collect()
@@ -100,20 +100,32 @@ mod day18_tests {
 
     #[test]
     fn day18a_test() {
-        let input = TEST_INPUT
+        let input: Vec<Vec<String>> = TEST_INPUT
             .lines()
             .map(|line| tokenise(line.to_string()))
             .collect();
-        assert_eq!(26386, day18a(&input));
+        assert_eq!(26386, day18a(&input).unwrap());
     }
 
     #[test]
     fn day18b_test() {
-        let input = TEST_INPUT
+        let input: Vec<Vec<String>> = TEST_INPUT
             .lines()
             .map(|line| tokenise(line.to_string()))
             .collect();
-        assert_eq!(693942, day18b(&input));
+        assert_eq!(693942, day18b(&input).unwrap());
+    }
+
+    #[test]
+    fn should_reject_invalid_expressions() {
+        assert_eq!(
+            "line 2, column 1: '(2 * 3' is not a valid expression",
+            parse_input("1 + 2\n(2 * 3").unwrap_err().to_string()
+        );
+        assert!(parse_input("1 + 2)").is_err());
+        assert!(parse_input("1 2").is_err());
+        assert!(parse_input("1 +").is_err());
+        assert!(parse_input("1 - 2").is_err());
     }
 }
 
@@ -129,11 +141,8 @@ fn get_part2_precedence() -> HashMap<String, usize> {
         .collect()
 }
 
-fn parse_number(number: &String) -> Option<usize> {
-    match number.parse::<usize>() {
-        Ok(x) => Some(x),
-        _ => None,
-    }
+fn parse_number(number: &str) -> Option<usize> {
+    number.parse::<usize>().ok()
 }
 
 fn apply_terms(
@@ -159,7 +168,7 @@ fn apply_terms(
         next_index = next_result.1;
     }
 
-    return Some((current, next_index));
+    Some((current, next_index))
 }
 
 fn apply_bracket(
@@ -167,9 +176,11 @@ fn apply_bracket(
     input: &Vec<String>,
     start_index: usize,
 ) -> Option<(usize, usize)> {
-    if input[start_index] == "(" {
+    if input.get(start_index)? == "(" {
         let (result, next_index) = apply_terms(terms, 0, input, start_index + 1)?;
-        assert_eq!(")", input[next_index]);
+        if input.get(next_index)? != ")" {
+            return None;
+        }
         Some((result, next_index + 1))
     } else {
         Some((parse_number(&input[start_index])?, start_index + 1))
@@ -192,71 +203,79 @@ fn apply_bracket(
 }
 
 fn calculate(terms: &HashMap<String, usize>, input: &Vec<String>) -> Option<usize> {
-    match apply_terms(terms, 0, input, 0) {
-        Some((result, _)) => Some(result),
-        None => None,
+    match apply_terms(terms, 0, input, 0)? {
+        (result, next_index) if next_index == input.len() => Some(result),
+        _ => None,
     }
 }
 
-fn tokenise(line: String) -> Vec<String> {
+fn calculate_all(terms: &HashMap<String, usize>, input: &[Vec<String>]) -> Result<usize> {
+    input
+        .iter()
+        .enumerate()
+        .map(|(i, line)| {
+            calculate(terms, line).ok_or_else(|| {
+                Error::parse(
+                    i + 1,
+                    1,
+                    format!("'{}' is not a valid expression", line.join(" ")),
+                )
+            })
+        })
+        .sum()
+}
+
+pub fn tokenise(line: String) -> Vec<String> {
     line.replace("(", "( ")
         .replace(")", " )")
         .split(" ")
-        .filter(|&token| token != "")
+        .filter(|&token| !token.is_empty())
         .map(|c| c.to_string())
         .collect()
 }
 
-fn read_file(path: &str) -> Result<String> {
-    let file = File::open(path)?;
-    let mut buf_reader = BufReader::new(file);
-    let mut input = String::new();
-    buf_reader.read_to_string(&mut input)?;
+pub fn parse_input(input: &str) -> Result<Vec<Vec<String>>> {
+    let precedence = get_part1_precedence();
 
-    Ok(input)
+    lines(input)
+        .map(|line| {
+            let tokens = tokenise(line.to_string());
+            match calculate(&precedence, &tokens) {
+                Some(_) => Ok(tokens),
+                None => Err(Error::parse_at(
+                    input,
+                    line,
+                    format!("'{}' is not a valid expression", line),
+                )),
+            }
+        })
+        .collect()
 }
 
-fn day18a(input: &Vec<Vec<String>>) -> usize {
-    let precedence = get_part1_precedence();
-    input
-        .iter()
-        .map(|line| calculate(&precedence, line).unwrap())
-        .sum()
+pub fn day18a(input: &[Vec<String>]) -> Result<usize> {
+    calculate_all(&get_part1_precedence(), input)
 }
 
-fn day18b(input: &Vec<Vec<String>>) -> usize {
-    let precedence = get_part2_precedence();
-    input
-        .iter()
-        .map(|line| calculate(&precedence, line).unwrap())
-        .sum()
+pub fn day18b(input: &[Vec<String>]) -> Result<usize> {
+    calculate_all(&get_part2_precedence(), input)
 }
 
-fn main() -> Result<()> {
-    let input = read_file("input")?;
-    let lines = input
-        .lines()
-        .map(|line| tokenise(line.to_string()))
-        .collect();
+pub struct Day18;
 
-    use std::time::Instant;
-    let total = Instant::now();
+impl Solution for Day18 {
+    type Input = Vec<Vec<String>>;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    let result = day18a(&lines);
-    println!(
-        "Day 18A - {} ({:.2}ms)",
-        result,
-        total.elapsed().as_millis()
-    );
+    fn parse(input: &str) -> Result<Vec<Vec<String>>> {
+        parse_input(input)
+    }
 
-    let part2 = Instant::now();
-    let result = day18b(&lines);
-    println!(
-        "Day 18B - {} ({:.2}ms)",
-        result,
-        part2.elapsed().as_millis()
-    );
-    println!("Total ({:.2}ms)", total.elapsed().as_millis());
+    fn part1(lines: &Vec<Vec<String>>) -> Result<usize> {
+        day18a(lines)
+    }
 
-    Ok(())
+    fn part2(lines: &Vec<Vec<String>>) -> Result<usize> {
+        day18b(lines)
+    }
 }
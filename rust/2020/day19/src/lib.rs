@@ -0,0 +1,370 @@
+use aoc_common::input::groups;
+use aoc_common::{Error, Result, Solution};
+use regex::Regex;
+use std::cmp::max;
+use std::collections::HashMap;
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum Rule {
+    Character(String),
+    List(Vec<usize>),
+    Opt(Vec<usize>, Vec<usize>),
+}
+
+macro_rules! character {
+    ($s: expr) => {
+        Rule::Character($s.to_string())
+    };
+}
+
+#[cfg(test)]
+mod day19_tests {
+    use super::*;
+
+    macro_rules! list {
+        ($($s: expr), *) => {
+            Rule::List(vec![$($s,)*])
+        };
+    }
+    macro_rules! opt {
+        ([$($a: expr),*], [$($b: expr),*]) => {
+            Rule::Opt(vec![$($a,)*], vec![$($b,)*])
+        };
+    }
+
+    static TEST_INPUTA: &str = "0: 1 2
+1: \"a\"
+2: 1 3 | 3 1
+3: \"b\"
+";
+
+    static TEST2_INPUTA: &str = "0: 4 1 5
+1: 2 3 | 3 2
+2: 4 4 | 5 5
+3: 4 5 | 5 4
+4: \"a\"
+5: \"b\"
+";
+
+    static TEST2_INPUTB: &str = "ababbb
+bababa
+abbbab
+aaabbb
+aaaabbb
+";
+
+    static TEST3_INPUT: &str = "42: 9 14 | 10 1
+9: 14 27 | 1 26
+10: 23 14 | 28 1
+1: \"a\"
+11: 42 31
+5: 1 14 | 15 1
+19: 14 1 | 14 14
+12: 24 14 | 19 1
+16: 15 1 | 14 14
+31: 14 17 | 1 13
+6: 14 14 | 1 14
+2: 1 24 | 14 4
+0: 8 11
+13: 14 3 | 1 12
+15: 1 | 14
+17: 14 2 | 1 7
+23: 25 1 | 22 14
+28: 16 1
+4: 1 1
+20: 14 14 | 1 15
+3: 5 14 | 16 1
+27: 1 6 | 14 18
+14: \"b\"
+21: 14 1 | 1 14
+25: 1 1 | 1 14
+22: 14 14
+8: 42
+26: 14 22 | 1 20
+18: 15 15
+7: 14 5 | 1 21
+24: 14 1
+
+abbbbbabbbaaaababbaabbbbabababbbabbbbbbabaaaa
+bbabbbbaabaabba
+babbbbaabbbbbabbbbbbaabaaabaaa
+aaabbbbbbaaaabaababaabababbabaaabbababababaaa
+bbbbbbbaaaabbbbaaabbabaaa
+bbbababbbbaaaaaaaabbababaaababaabab
+ababaaaaaabaaab
+ababaaaaabbbaba
+baabbaaaabbaaaababbaababb
+abbbbabbbbaaaababbbbbbaaaababb
+aaaaabbaabaaaaababaa
+aaaabbaaaabbaaa
+aaaabbaabbaaaaaaabbbabbbaaabbaabaaa
+babaaabbbaaabaababbaabababaaab
+aabbbbbaabbbaaaaaabbbbbababaaaaabbaaabba
+";
+
+    fn test1_rules() -> Vec<Option<Rule>> {
+        vec![
+            Some(list![1, 2]),
+            Some(character!("a")),
+            Some(opt![[1, 3], [3, 1]]),
+            Some(character!("b")),
+        ]
+    }
+
+    fn test2_rules() -> Vec<Option<Rule>> {
+        vec![
+            Some(list![4, 1, 5]),
+            Some(opt![[2, 3], [3, 2]]),
+            Some(opt![[4, 4], [5, 5]]),
+            Some(opt![[4, 5], [5, 4]]),
+            Some(character!("a")),
+            Some(character!("b")),
+        ]
+    }
+
+    #[test]
+    fn should_parse_rule_strings() {
+        assert_eq!(test1_rules(), parse_rules(TEST_INPUTA).unwrap());
+        assert_eq!(test2_rules(), parse_rules(TEST2_INPUTA).unwrap());
+        assert!(parse_rules("0: 1 2\n1: \"a\"\n2: 1 x").is_err());
+    }
+
+    #[test]
+    fn should_parse_a_rule_string() {
+        assert_eq!(Some((0, list![4, 1, 5])), parse_rule_string("0: 4 1 5"));
+        assert_eq!(
+            Some((1, opt!([2, 3], [3, 2]))),
+            parse_rule_string("1: 2 3 | 3 2")
+        );
+        assert_eq!(Some((4, character!("a"))), parse_rule_string("4: \"a\""));
+    }
+
+    #[test]
+    fn should_generate_a_regular_expression_string_for_a_rule() {
+        assert_eq!(
+            Some("a".to_string()),
+            get_rule_regex(&test1_rules(), 1).ok()
+        );
+        assert_eq!(
+            Some("b".to_string()),
+            get_rule_regex(&test1_rules(), 3).ok()
+        );
+        assert_eq!(
+            Some("(ab|ba)".to_string()),
+            get_rule_regex(&test1_rules(), 2).ok()
+        );
+        assert_eq!(
+            Some("a(ab|ba)".to_string()),
+            get_rule_regex(&test1_rules(), 0).ok()
+        );
+        assert_eq!(
+            Some("a((aa|bb)(ab|ba)|(ab|ba)(aa|bb))b".to_string()),
+            get_rule_regex(&test2_rules(), 0).ok()
+        );
+    }
+
+    #[test]
+    fn should_reject_rules_that_refer_back_to_themselves() {
+        let (rules, messages) = parse_input("0: 0\n\na").unwrap();
+        assert_eq!(
+            "invalid puzzle shape: rule 0 refers back to itself",
+            day19a(&rules, &messages).unwrap_err().to_string()
+        );
+
+        let (rules, messages) = parse_input("0: 1 2\n1: \"a\"\n2: 1 | 3\n3: 1 0\n\na").unwrap();
+        assert!(day19a(&rules, &messages).is_err());
+    }
+
+    #[test]
+    fn should_report_a_missing_rule() {
+        let (rules, messages) = parse_input("0: 1 7\n1: \"a\"\n\na").unwrap();
+        assert_eq!(
+            "no solution: rule 7 is missing",
+            day19a(&rules, &messages).unwrap_err().to_string()
+        );
+    }
+
+    #[test]
+    fn day19a_test() {
+        assert_eq!(
+            2,
+            day19a(
+                &test2_rules(),
+                &TEST2_INPUTB
+                    .lines()
+                    .map(String::from)
+                    .collect::<Vec<String>>()
+            )
+            .unwrap()
+        )
+    }
+
+    #[test]
+    fn day19b_test() {
+        let (rules, messages) = parse_input(TEST3_INPUT).unwrap();
+        assert_eq!(12, day19b(&rules, &messages).unwrap());
+    }
+}
+
+//  The rules become one regular expression, so a rule that refers back to
+//  itself, even through others, has no expression at all. The rules being
+//  expanded are kept to catch that before it recurses forever.
+struct Expansion<'a> {
+    rules: &'a [Option<Rule>],
+    seen_rules: HashMap<usize, String>,
+    expanding: Vec<usize>,
+}
+
+fn generate_rule_list_regex(expansion: &mut Expansion, list: &[usize]) -> Result<String> {
+    list.iter().map(|&i| generate_regex(expansion, i)).collect()
+}
+
+fn generate_regex(expansion: &mut Expansion, index: usize) -> Result<String> {
+    if let Some(rule) = expansion.seen_rules.get(&index) {
+        return Ok(rule.clone());
+    }
+    if expansion.expanding.contains(&index) {
+        return Err(Error::invalid_shape(format!(
+            "rule {} refers back to itself",
+            index
+        )));
+    }
+
+    let rules = expansion.rules;
+    let rule = rules
+        .get(index)
+        .and_then(Option::as_ref)
+        .ok_or_else(|| Error::no_solution(format!("rule {} is missing", index)))?;
+
+    expansion.expanding.push(index);
+    let regex = match rule {
+        Rule::Character(c) => c.clone(),
+        Rule::List(list) => generate_rule_list_regex(expansion, list)?,
+        Rule::Opt(a, b) => format!(
+            "({}|{})",
+            generate_rule_list_regex(expansion, a)?,
+            generate_rule_list_regex(expansion, b)?
+        ),
+    };
+    expansion.expanding.pop();
+
+    expansion.seen_rules.insert(index, regex.clone());
+    Ok(regex)
+}
+
+fn get_rule_regex(rules: &[Option<Rule>], index: usize) -> Result<String> {
+    let mut expansion = Expansion {
+        rules,
+        seen_rules: HashMap::new(),
+        expanding: vec![],
+    };
+    generate_regex(&mut expansion, index)
+}
+
+fn parse_rules(input: &str) -> Result<Vec<Option<Rule>>> {
+    let mut output = Vec::<Option<Rule>>::new();
+
+    for line in input.lines() {
+        let (index, rule) = parse_rule_string(line)
+            .ok_or_else(|| Error::parse_at(input, line, format!("invalid rule '{}'", line)))?;
+        output.resize(max(index + 1, output.len()), None);
+        output[index] = Some(rule);
+    }
+
+    Ok(output)
+}
+
+fn parse_number(number: &str) -> Option<usize> {
+    number.trim().parse::<usize>().ok()
+}
+
+fn parse_rule_numbers(input: &str) -> Option<Vec<usize>> {
+    let numbers = input.trim().split(" ").map(parse_number);
+    if numbers.clone().any(|n| n.is_none()) {
+        None
+    } else {
+        Some(numbers.flatten().collect())
+    }
+}
+
+fn parse_rule_content(input: &str) -> Option<Rule> {
+    let rule = if input.starts_with("\"") {
+        character!(input.trim().replace("\"", ""))
+    } else if input.contains("|") {
+        let options: Vec<Option<Vec<usize>>> = input.split("|").map(parse_rule_numbers).collect();
+        Rule::Opt(options[0].clone()?, options[1].clone()?)
+    } else {
+        Rule::List(parse_rule_numbers(input)?)
+    };
+
+    Some(rule)
+}
+
+fn parse_rule_string(input: &str) -> Option<(usize, Rule)> {
+    let mut parts = input.split(": ");
+    let rule_no = parse_number(parts.next()?)?;
+    let rule_content = parse_rule_content(parts.next()?)?;
+
+    Some((rule_no, rule_content))
+}
+
+pub fn parse_input(input: &str) -> Result<(Vec<Option<Rule>>, Vec<String>)> {
+    let parts = groups(input);
+    if parts.len() != 2 {
+        return Err(Error::invalid_shape(
+            "expected rules and messages separated by a blank line",
+        ));
+    }
+
+    let messages = parts[1].lines().map(String::from).collect();
+
+    Ok((parse_rules(parts[0])?, messages))
+}
+
+fn compile(regex: &str) -> Result<Regex> {
+    Regex::new(regex).map_err(|e| Error::invalid_shape(e.to_string()))
+}
+
+pub fn day19a(rules: &[Option<Rule>], messages: &[String]) -> Result<usize> {
+    let regex_str = get_rule_regex(rules, 0)?;
+    let regex = compile(format!("^{}$", regex_str).as_str())?;
+
+    Ok(messages.iter().filter(|m| regex.is_match(m)).count())
+}
+
+pub fn day19b(rules: &[Option<Rule>], messages: &[String]) -> Result<usize> {
+    //  This is using the knowledge that 0: 8 11
+    //  and 8: 42 | 42 8, 11: 42 31 | 42 11 31
+    //  means 8: (42)+ and 11: (42){x}(31){x}
+    let rule42 = get_rule_regex(rules, 42)?;
+    let rule31 = get_rule_regex(rules, 31)?;
+    let regex = compile(
+        format!(
+            "^({})+({1}{{1}}{2}{{1}}|{1}{{2}}{2}{{2}}|{1}{{3}}{2}{{3}}|{1}{{4}}{2}{{4}})$",
+            rule42, rule42, rule31
+        )
+        .as_str(),
+    )?;
+
+    Ok(messages.iter().filter(|m| regex.is_match(m)).count())
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    type Input = (Vec<Option<Rule>>, Vec<String>);
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<(Vec<Option<Rule>>, Vec<String>)> {
+        parse_input(input)
+    }
+
+    fn part1(input: &(Vec<Option<Rule>>, Vec<String>)) -> Result<usize> {
+        day19a(&input.0, &input.1)
+    }
+
+    fn part2(input: &(Vec<Option<Rule>>, Vec<String>)) -> Result<usize> {
+        day19b(&input.0, &input.1)
+    }
+}
@@ -0,0 +1,716 @@
+use aoc_common::input::lines;
+use aoc_common::{Error, Result, Solution};
+use regex::Regex;
+use std::collections::HashMap;
+
+pub struct PasswordPolicy {
+    pub first: usize,
+    pub second: usize,
+    pub character: char,
+}
+
+pub struct PasswordAndPolicy {
+    pub password: String,
+    pub policy: PasswordPolicy,
+}
+
+//  A rule a password can be checked against, which may or may not use the
+//  policy written next to it.
+pub trait Policy {
+    //  Describes the rule the password breaks, if it breaks one.
+    fn check(&self, entry: &PasswordAndPolicy) -> Option<String>;
+}
+
+//  The sled rental's policy, a count of the character within the range.
+pub struct OldPolicy;
+
+//  The toboggan company's policy, the character in exactly one of the two
+//  positions.
+pub struct NewPolicy;
+
+pub struct MinDistinct(pub usize);
+
+pub struct Forbidden(pub String);
+
+pub struct Matches(pub Regex);
+
+//  Met when every one of the policies is, or any of them.
+pub struct All(pub Vec<Box<dyn Policy>>);
+pub struct Any(pub Vec<Box<dyn Policy>>);
+
+//  Builds a policy from the argument after its name, which may be empty.
+//  Any error is reported relative to the spec, which the argument is a part
+//  of.
+pub type Constructor = fn(spec: &str, argument: &str) -> Result<Box<dyn Policy>>;
+
+//  Policies by name, so that they can be picked when running the day. A
+//  spec names one, with its argument after a ':', or combines several with
+//  'and' and 'or', where 'and' binds tighter. An argument can be put in
+//  double quotes to keep an 'and' or 'or' inside it.
+pub struct Registry {
+    constructors: HashMap<&'static str, Constructor>,
+}
+
+pub struct PolicyCount {
+    pub spec: String,
+    pub valid: usize,
+    pub invalid: usize,
+}
+
+pub struct Summary {
+    pub malformed: Vec<Error>,
+    pub counts: Vec<PolicyCount>,
+}
+
+#[cfg(test)]
+mod day2_tests {
+    use super::*;
+
+    static TEST_INPUT: &str = "\
+1-3 a: abcde
+1-3 b: cdefg
+2-9 c: ccccccccc
+";
+
+    #[test]
+    fn should_parse_policy_into_struct() {
+        let policy = parse_policy("1-3 a", "1-3 a").unwrap();
+        assert_eq!(policy.first, 1);
+        assert_eq!(policy.second, 3);
+        assert_eq!(policy.character, 'a');
+    }
+
+    #[test]
+    fn should_parse_a_line_into_password_and_policy() {
+        let parsed_line = parse_line("1-3 a: abcde", "1-3 a: abcde").unwrap();
+        assert_eq!(parsed_line.policy.first, 1);
+        assert_eq!(parsed_line.policy.second, 3);
+        assert_eq!(parsed_line.policy.character, 'a');
+        assert_eq!(parsed_line.password, "abcde");
+    }
+
+    #[test]
+    fn should_reject_lines_that_are_not_a_password_and_policy() {
+        let error = |line: &str| parse_line(line, line).err().unwrap().to_string();
+
+        assert_eq!("line 1, column 1: missing ':' after the policy", error("a"));
+        assert_eq!(
+            "line 1, column 1: expected '<first>-<second> <character>'",
+            error("1 a: abcde")
+        );
+        assert_eq!(
+            "line 1, column 1: expected '<first>-<second> <character>'",
+            error("1-3: abcde")
+        );
+        assert_eq!(
+            "line 1, column 3: 'x' is not a position",
+            error("1-x a: abcde")
+        );
+        assert_eq!(
+            "line 1, column 5: 'ab' is not a single character",
+            error("1-3 ab: abcde")
+        );
+    }
+
+    #[test]
+    fn should_report_the_line_of_a_malformed_entry() {
+        let error = parse_input("1-3 a: abcde\n0-3 b: cdefg\n").err().unwrap();
+        assert_eq!("line 2, column 1: positions start at 1", error.to_string());
+    }
+
+    #[test]
+    fn should_not_find_a_character_at_position_zero() {
+        assert_eq!(None, position("abc", 0));
+        assert_eq!(Some('a'), position("abc", 1));
+        assert_eq!(None, position("abc", 4));
+    }
+
+    #[test]
+    fn should_summarise_malformed_lines_and_counts_per_policy() {
+        let input = "1-3 a: abcde\n1-3 b cdefg\n2-9 c: ccccccccc\n1-3 b: cdefg\n4-x a: aaaa\n";
+        let summary = summarise(input, &["old", "new", "old or new"], &Registry::new()).unwrap();
+
+        assert_eq!(
+            vec![
+                "line 2, column 1: missing ':' after the policy",
+                "line 5, column 3: 'x' is not a position"
+            ],
+            summary
+                .malformed
+                .iter()
+                .map(|e| e.to_string())
+                .collect::<Vec<String>>()
+        );
+        assert_eq!(
+            vec![("old", 2, 1), ("new", 1, 2), ("old or new", 2, 1)],
+            summary
+                .counts
+                .iter()
+                .map(|count| (count.spec.as_str(), count.valid, count.invalid))
+                .collect::<Vec<_>>()
+        );
+        assert!(summarise(input, &["oldest"], &Registry::new()).is_err());
+    }
+
+    #[test]
+    fn should_return_true_if_password_matches_old_policy() {
+        let test_policy = PasswordAndPolicy {
+            policy: PasswordPolicy {
+                first: 1,
+                second: 3,
+                character: 'a',
+            },
+            password: String::from("abcde"),
+        };
+        assert_eq!(true, does_password_meet_old_policy(&test_policy))
+    }
+
+    #[test]
+    fn should_return_false_if_password_does_not_match_old_policy() {
+        let test_policy = PasswordAndPolicy {
+            policy: PasswordPolicy {
+                first: 1,
+                second: 3,
+                character: 'b',
+            },
+            password: String::from("cdefg"),
+        };
+        assert_eq!(false, does_password_meet_old_policy(&test_policy))
+    }
+
+    #[test]
+    fn should_return_true_if_password_matches_new_policy() {
+        let test_policy = PasswordAndPolicy {
+            policy: PasswordPolicy {
+                first: 1,
+                second: 3,
+                character: 'a',
+            },
+            password: String::from("abcde"),
+        };
+        assert_eq!(true, does_password_meet_new_policy(&test_policy))
+    }
+
+    #[test]
+    fn should_return_false_if_password_does_not_match_new_policy() {
+        let test_policy = PasswordAndPolicy {
+            policy: PasswordPolicy {
+                first: 1,
+                second: 3,
+                character: 'b',
+            },
+            password: String::from("cdefg"),
+        };
+        assert_eq!(false, does_password_meet_new_policy(&test_policy))
+    }
+
+    fn entry(first: usize, second: usize, character: char, password: &str) -> PasswordAndPolicy {
+        PasswordAndPolicy {
+            policy: PasswordPolicy {
+                first,
+                second,
+                character,
+            },
+            password: String::from(password),
+        }
+    }
+
+    #[test]
+    fn should_report_the_rule_a_password_breaks() {
+        let registry = Registry::new();
+        let check =
+            |spec: &str, entry: &PasswordAndPolicy| registry.parse(spec).unwrap().check(entry);
+        let entry = entry(1, 3, 'b', "cdefg");
+
+        assert_eq!(
+            Some(String::from("needs 1 to 3 of 'b'")),
+            check("old", &entry)
+        );
+        assert_eq!(
+            Some(String::from(
+                "needs 'b' at exactly one of positions 1 and 3"
+            )),
+            check("new", &entry)
+        );
+        assert_eq!(None, check("distinct:5", &entry));
+        assert_eq!(
+            Some(String::from("needs at least 6 different characters")),
+            check("distinct:6", &entry)
+        );
+        assert_eq!(
+            Some(String::from("must not contain 'ef'")),
+            check("forbid:ef", &entry)
+        );
+        assert_eq!(None, check("regex:^c.*g$", &entry));
+        assert_eq!(
+            Some(String::from("must match /^[a-c]+$/")),
+            check("regex:^[a-c]+$", &entry)
+        );
+    }
+
+    #[test]
+    fn should_combine_policies() {
+        let registry = Registry::new();
+        let check =
+            |spec: &str, entry: &PasswordAndPolicy| registry.parse(spec).unwrap().check(entry);
+        let entry = entry(1, 3, 'a', "abcde");
+
+        assert_eq!(None, check("old and new", &entry));
+        assert_eq!(
+            Some(String::from("must not contain 'cd'")),
+            check("old and forbid:cd and distinct:2", &entry)
+        );
+        assert_eq!(None, check("forbid:cd or distinct:2", &entry));
+        assert_eq!(
+            Some(String::from(
+                "must not contain 'cd' or needs at least 9 different characters"
+            )),
+            check("forbid:cd or distinct:9", &entry)
+        );
+        assert_eq!(None, check("forbid:cd and old or new", &entry));
+    }
+
+    #[test]
+    fn should_reject_unknown_or_malformed_policies() {
+        let registry = Registry::new();
+        let error = |spec: &str| registry.parse(spec).err().unwrap().to_string();
+
+        assert_eq!(
+            "line 1, column 9: unknown policy 'strict'",
+            error("old and strict")
+        );
+        assert_eq!(
+            "line 1, column 5: this policy takes no argument",
+            error("old:2")
+        );
+        assert_eq!(
+            "line 1, column 10: 'x' is not a number of characters",
+            error("distinct:x")
+        );
+        assert!(error("regex:(").starts_with("line 1, column 7: regex parse error"));
+        assert_eq!("line 1, column 8: nothing to forbid", error("forbid:"));
+        assert_eq!(
+            "line 1, column 15: missing the closing '\"'",
+            error("old or forbid:\"ab")
+        );
+        assert!(registry.parse("old and").is_err());
+    }
+
+    #[test]
+    fn should_keep_and_or_or_inside_quoted_arguments() {
+        let registry = Registry::new();
+        let check = |spec: &str, password: &str| {
+            registry
+                .parse(spec)
+                .unwrap()
+                .check(&entry(1, 3, 'a', password))
+        };
+        let spec = r#"regex:"^(cat and dog|cat or dog)$" and old"#;
+
+        assert_eq!(None, check(spec, "cat and dog"));
+        assert_eq!(None, check(spec, "cat or dog"));
+        assert_eq!(
+            Some(String::from("must match /^(cat and dog|cat or dog)$/")),
+            check(spec, "cat")
+        );
+        assert_eq!(
+            Some(String::from("must not contain ' or '")),
+            check(r#"forbid:" or ""#, "cat or dog")
+        );
+    }
+
+    #[test]
+    fn should_add_policies_to_a_registry() {
+        struct Short;
+        impl Policy for Short {
+            fn check(&self, entry: &PasswordAndPolicy) -> Option<String> {
+                match entry.password.len() {
+                    0..=5 => None,
+                    _ => Some(String::from("is too long")),
+                }
+            }
+        }
+
+        let mut registry = Registry::new();
+        registry.register("short", |_, _| Ok(Box::new(Short)));
+        let policy = registry.parse("short and old").unwrap();
+
+        assert_eq!(
+            1,
+            count_meeting(&parse_input(TEST_INPUT).unwrap(), policy.as_ref())
+        );
+        assert!(registry.names().contains(&"short"));
+    }
+
+    #[test]
+    fn day2a_tests() {
+        assert_eq!(2, day2a(&parse_input(TEST_INPUT).unwrap()).unwrap());
+    }
+
+    #[test]
+    fn day2b_tests() {
+        assert_eq!(1, day2b(&parse_input(TEST_INPUT).unwrap()).unwrap());
+    }
+}
+
+//  Any error is reported relative to `input`, which `text` is a part of.
+fn parse_position(input: &str, text: &str) -> Result<usize> {
+    match text.parse::<usize>() {
+        Ok(0) => Err(Error::parse_at(input, text, "positions start at 1")),
+        Ok(position) => Ok(position),
+        Err(_) => Err(Error::parse_at(
+            input,
+            text,
+            format!("'{}' is not a position", text),
+        )),
+    }
+}
+
+fn parse_policy(input: &str, text: &str) -> Result<PasswordPolicy> {
+    let expected = || Error::parse_at(input, text, "expected '<first>-<second> <character>'");
+    let (range, character) = text.split_once(' ').ok_or_else(expected)?;
+    let (first, second) = range.split_once('-').ok_or_else(expected)?;
+
+    let mut characters = character.chars();
+    let character = match (characters.next(), characters.next()) {
+        (Some(c), None) => c,
+        _ => {
+            return Err(Error::parse_at(
+                input,
+                character,
+                format!("'{}' is not a single character", character),
+            ))
+        }
+    };
+
+    Ok(PasswordPolicy {
+        first: parse_position(input, first)?,
+        second: parse_position(input, second)?,
+        character,
+    })
+}
+
+fn parse_line(input: &str, line: &str) -> Result<PasswordAndPolicy> {
+    let (policy, password) = line
+        .split_once(':')
+        .ok_or_else(|| Error::parse_at(input, line, "missing ':' after the policy"))?;
+
+    Ok(PasswordAndPolicy {
+        policy: parse_policy(input, policy.trim())?,
+        password: String::from(password.trim()),
+    })
+}
+
+fn does_password_meet_old_policy(input: &PasswordAndPolicy) -> bool {
+    let char_count = input
+        .password
+        .chars()
+        .filter(|&c| c == input.policy.character)
+        .count();
+    char_count >= input.policy.first && char_count <= input.policy.second
+}
+
+//  Positions count from 1, so there is nothing at 0.
+fn position(password: &str, position: usize) -> Option<char> {
+    password.chars().nth(position.checked_sub(1)?)
+}
+
+fn does_password_meet_new_policy(input: &PasswordAndPolicy) -> bool {
+    [
+        position(&input.password, input.policy.first),
+        position(&input.password, input.policy.second),
+    ]
+    .iter()
+    .filter_map(|&x| x)
+    .filter(|&c| c == input.policy.character)
+    .count()
+        == 1
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<PasswordAndPolicy>> {
+    lines(input).map(|line| parse_line(input, line)).collect()
+}
+
+//  Keeps every line that parses, and the error for each one that doesn't,
+//  so that one bad line doesn't hide the rest.
+pub fn parse_lenient(input: &str) -> (Vec<PasswordAndPolicy>, Vec<Error>) {
+    let mut passwords = vec![];
+    let mut malformed = vec![];
+
+    for line in lines(input) {
+        match parse_line(input, line) {
+            Ok(password) => passwords.push(password),
+            Err(e) => malformed.push(e),
+        }
+    }
+
+    (passwords, malformed)
+}
+
+//  The malformed lines of an input, and how many of the rest meet each of
+//  a list of policies.
+pub fn summarise(input: &str, specs: &[&str], registry: &Registry) -> Result<Summary> {
+    let (passwords, malformed) = parse_lenient(input);
+    let mut counts = vec![];
+
+    for &spec in specs {
+        let valid = count_meeting(&passwords, registry.parse(spec)?.as_ref());
+        counts.push(PolicyCount {
+            spec: String::from(spec),
+            valid,
+            invalid: passwords.len() - valid,
+        });
+    }
+
+    Ok(Summary { malformed, counts })
+}
+
+//  Which rule each password breaks, by its position in the list.
+pub fn failures(passwords: &[PasswordAndPolicy], policy: &dyn Policy) -> Vec<(usize, String)> {
+    passwords
+        .iter()
+        .enumerate()
+        .filter_map(|(i, password)| policy.check(password).map(|rule| (i, rule)))
+        .collect()
+}
+
+pub fn count_meeting(passwords: &[PasswordAndPolicy], policy: &dyn Policy) -> usize {
+    passwords
+        .iter()
+        .filter(|password| policy.check(password).is_none())
+        .count()
+}
+
+pub fn day2a(passwords: &[PasswordAndPolicy]) -> Result<usize> {
+    Ok(count_meeting(passwords, &OldPolicy))
+}
+
+pub fn day2b(passwords: &[PasswordAndPolicy]) -> Result<usize> {
+    Ok(count_meeting(passwords, &NewPolicy))
+}
+
+impl Policy for OldPolicy {
+    fn check(&self, entry: &PasswordAndPolicy) -> Option<String> {
+        if does_password_meet_old_policy(entry) {
+            return None;
+        }
+
+        let policy = &entry.policy;
+        Some(format!(
+            "needs {} to {} of '{}'",
+            policy.first, policy.second, policy.character
+        ))
+    }
+}
+
+impl Policy for NewPolicy {
+    fn check(&self, entry: &PasswordAndPolicy) -> Option<String> {
+        if does_password_meet_new_policy(entry) {
+            return None;
+        }
+
+        let policy = &entry.policy;
+        Some(format!(
+            "needs '{}' at exactly one of positions {} and {}",
+            policy.character, policy.first, policy.second
+        ))
+    }
+}
+
+impl Policy for MinDistinct {
+    fn check(&self, entry: &PasswordAndPolicy) -> Option<String> {
+        let mut characters: Vec<char> = entry.password.chars().collect();
+        characters.sort_unstable();
+        characters.dedup();
+
+        if characters.len() >= self.0 {
+            return None;
+        }
+        Some(format!("needs at least {} different characters", self.0))
+    }
+}
+
+impl Policy for Forbidden {
+    fn check(&self, entry: &PasswordAndPolicy) -> Option<String> {
+        if !entry.password.contains(&self.0) {
+            return None;
+        }
+        Some(format!("must not contain '{}'", self.0))
+    }
+}
+
+impl Policy for Matches {
+    fn check(&self, entry: &PasswordAndPolicy) -> Option<String> {
+        if self.0.is_match(&entry.password) {
+            return None;
+        }
+        Some(format!("must match /{}/", self.0.as_str()))
+    }
+}
+
+//  Names the first rule broken.
+impl Policy for All {
+    fn check(&self, entry: &PasswordAndPolicy) -> Option<String> {
+        self.0.iter().find_map(|policy| policy.check(entry))
+    }
+}
+
+//  Names every rule broken, since meeting any one of them would have done.
+impl Policy for Any {
+    fn check(&self, entry: &PasswordAndPolicy) -> Option<String> {
+        let mut broken = vec![];
+        for policy in &self.0 {
+            broken.push(policy.check(entry)?);
+        }
+        Some(broken.join(" or "))
+    }
+}
+
+fn no_argument(spec: &str, argument: &str) -> Result<()> {
+    if argument.is_empty() {
+        return Ok(());
+    }
+    Err(Error::parse_at(
+        spec,
+        argument,
+        "this policy takes no argument",
+    ))
+}
+
+//  Splits on the separator wherever it isn't inside double quotes.
+fn split_unquoted<'a>(text: &'a str, separator: &str) -> Vec<&'a str> {
+    let mut parts = vec![];
+    let mut start = 0;
+    let mut quoted = false;
+
+    for (i, c) in text.char_indices() {
+        if c == '"' {
+            quoted = !quoted;
+        } else if !quoted && i >= start && text[i..].starts_with(separator) {
+            parts.push(&text[start..i]);
+            start = i + separator.len();
+        }
+    }
+    parts.push(&text[start..]);
+
+    parts
+}
+
+//  The argument without the quotes round it, if it has them.
+fn unquote<'a>(spec: &str, argument: &'a str) -> Result<&'a str> {
+    if !argument.starts_with('"') {
+        return Ok(argument);
+    }
+
+    argument[1..]
+        .strip_suffix('"')
+        .ok_or_else(|| Error::parse_at(spec, argument, "missing the closing '\"'"))
+}
+
+impl Registry {
+    //  A registry of the built in policies.
+    pub fn new() -> Registry {
+        let mut registry = Registry {
+            constructors: HashMap::new(),
+        };
+        registry.register("old", |spec, argument| {
+            no_argument(spec, argument)?;
+            Ok(Box::new(OldPolicy))
+        });
+        registry.register("new", |spec, argument| {
+            no_argument(spec, argument)?;
+            Ok(Box::new(NewPolicy))
+        });
+        registry.register("distinct", |spec, argument| {
+            let count = argument.parse::<usize>().map_err(|_| {
+                Error::parse_at(
+                    spec,
+                    argument,
+                    format!("'{}' is not a number of characters", argument),
+                )
+            })?;
+            Ok(Box::new(MinDistinct(count)))
+        });
+        registry.register("forbid", |spec, argument| {
+            if argument.is_empty() {
+                return Err(Error::parse_at(spec, argument, "nothing to forbid"));
+            }
+            Ok(Box::new(Forbidden(String::from(argument))))
+        });
+        registry.register("regex", |spec, argument| {
+            let regex =
+                Regex::new(argument).map_err(|e| Error::parse_at(spec, argument, e.to_string()))?;
+            Ok(Box::new(Matches(regex)))
+        });
+
+        registry
+    }
+
+    pub fn register(&mut self, name: &'static str, constructor: Constructor) {
+        self.constructors.insert(name, constructor);
+    }
+
+    pub fn names(&self) -> Vec<&'static str> {
+        let mut names: Vec<&'static str> = self.constructors.keys().copied().collect();
+        names.sort_unstable();
+        names
+    }
+
+    fn single(&self, spec: &str, term: &str) -> Result<Box<dyn Policy>> {
+        //  A missing argument is the empty end of the term, so that errors
+        //  about it still point into the spec.
+        let (name, argument) = term.split_once(':').unwrap_or((term, &term[term.len()..]));
+        let constructor = self
+            .constructors
+            .get(name)
+            .ok_or_else(|| Error::parse_at(spec, term, format!("unknown policy '{}'", name)))?;
+
+        constructor(spec, unquote(spec, argument)?)
+    }
+
+    pub fn parse(&self, spec: &str) -> Result<Box<dyn Policy>> {
+        let mut any: Vec<Box<dyn Policy>> = vec![];
+
+        for alternative in split_unquoted(spec, " or ") {
+            let mut all: Vec<Box<dyn Policy>> = vec![];
+            for term in split_unquoted(alternative, " and ") {
+                all.push(self.single(spec, term.trim())?);
+            }
+            any.push(match all.len() {
+                1 => all.remove(0),
+                _ => Box::new(All(all)),
+            });
+        }
+
+        Ok(match any.len() {
+            1 => any.remove(0),
+            _ => Box::new(Any(any)),
+        })
+    }
+}
+
+impl Default for Registry {
+    fn default() -> Registry {
+        Registry::new()
+    }
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<PasswordAndPolicy>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<PasswordAndPolicy>> {
+        parse_input(input)
+    }
+
+    fn part1(passwords: &Vec<PasswordAndPolicy>) -> Result<usize> {
+        day2a(passwords)
+    }
+
+    fn part2(passwords: &Vec<PasswordAndPolicy>) -> Result<usize> {
+        day2b(passwords)
+    }
+}
@@ -0,0 +1,43 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use day20::{arrange_images, day20a, day20b, parse_input};
+
+static TEST_INPUT: &str = include_str!("../example.txt");
+
+//  Inputs of other sizes from the generator, seeded by their size.
+fn synthetic_input(size: usize) -> String {
+    aoc_generate::generate("2020", 20, size as u64, Some(size))
+        .unwrap()
+        .input
+}
+
+fn day20_benches(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day20");
+    group.sample_size(10);
+
+    let inputs = vec![
+        (String::from("example"), String::from(TEST_INPUT)),
+        (String::from("6x6"), synthetic_input(6)),
+        (String::from("12x12"), synthetic_input(12)),
+    ];
+
+    for (name, input) in &inputs {
+        let images = parse_input(input).unwrap();
+
+        group.bench_with_input(BenchmarkId::new("arrange", name), &images, |b, images| {
+            b.iter(|| arrange_images(images))
+        });
+
+        let image_map = arrange_images(&images).unwrap();
+        group.bench_with_input(BenchmarkId::new("part1", name), &images, |b, images| {
+            b.iter(|| day20a(images, &image_map))
+        });
+        group.bench_with_input(BenchmarkId::new("part2", name), &images, |b, images| {
+            b.iter(|| day20b(images, &image_map))
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, day20_benches);
+criterion_main!(benches);
@@ -0,0 +1,680 @@
+use aoc_common::grid::Grid;
+use aoc_common::input::groups;
+use aoc_common::{Error, Result, Solution};
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+type Location = (usize, usize);
+type Boundary = usize;
+type Boundaries = [Boundary; 4];
+type BoundaryCache = Vec<[Boundaries; 8]>;
+pub type ImageMap = HashMap<Location, (usize, usize)>;
+
+#[derive(Debug, PartialEq)]
+pub struct Image {
+    tile_no: usize,
+    picture: Grid<bool>,
+    boundaries: Boundaries,
+    boundary_size: usize,
+}
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+enum Position {
+    Top,
+    Right,
+    Bottom,
+    Left,
+}
+
+#[cfg(test)]
+mod day20_tests {
+    use super::*;
+
+    static TEST_INPUT: &str = include_str!("../example.txt");
+
+    fn get_test_input() -> Vec<Image> {
+        parse_input(TEST_INPUT).unwrap()
+    }
+
+    fn picture(input: &str) -> Grid<bool> {
+        Grid::parse(input, pixel).unwrap()
+    }
+
+    #[test]
+    fn should_parse_an_image() {
+        let image = groups(TEST_INPUT)[0];
+        assert_eq!(
+            Image {
+                tile_no: 2311,
+                picture: picture(
+                    "
+#..#....
+...##..#
+###.#...
+#.##.###
+#...#.##
+#.#.#..#
+.#....#.
+##...#.#"
+                ),
+                //  Max: 1023
+                boundaries: [0b0011010010, 0b0001011001, 0b0011100111, 0b0111110010],
+                boundary_size: 10,
+            },
+            parse_image(TEST_INPUT, image).unwrap()
+        );
+    }
+
+    #[test]
+    fn should_reject_malformed_tiles() {
+        assert_eq!(
+            "line 1, column 1: expected a 'Tile <number>:' title, found 'Tile x:'",
+            parse_input("Tile x:\n#.#\n...\n#.#")
+                .unwrap_err()
+                .to_string()
+        );
+        assert_eq!(
+            "line 3, column 1: expected a row of 3 cells, found 1",
+            parse_input("Tile 1:\n#.#\n.\n#.#").unwrap_err().to_string()
+        );
+        assert_eq!(
+            "line 1, column 1: tile 1 is 3 pixels wide but 2 high",
+            parse_input("Tile 1:\n#.#\n...").unwrap_err().to_string()
+        );
+        assert!(parse_input("Tile 1:\n#.#\n...\n#.#\n\nTile 2:\n#.#\n...\n#.#").is_err());
+    }
+
+    #[test]
+    fn should_find_image_boundaries() {
+        let picture = picture(
+            "
+..##.#..#.
+##..#.....
+#...##..#.
+####.#...#
+##.##.###.
+##...#.###
+.#.#.#..##
+..#....#..
+###...#.#.
+..###..###",
+        );
+        assert_eq!(
+            [
+                0b0011010010, //vec![2, 3, 5, 8],
+                0b0001011001, //vec![3, 5, 6, 9],
+                0b0011100111, //vec![2, 3, 4, 7, 8, 9],
+                0b0111110010, //vec![1, 2, 3, 4, 5, 8],
+            ],
+            find_boundaries(&picture)
+        );
+    }
+
+    #[test]
+    fn should_flip_a_boundary() {
+        assert_eq!(0b0100101100, flip_bits(0b0011010010, 10)); //  vec![1, 4, 6, 7]
+        assert_eq!(0b1001101000, flip_bits(0b0001011001, 10)); //  vec![0, 3, 4, 6]
+        assert_eq!(0b1110011100, flip_bits(0b0011100111, 10)); //  vec![0, 1, 2, 5, 6, 7]
+        assert_eq!(0b0100111110, flip_bits(0b0111110010, 10)); //  vec![1, 4, 5, 6, 7, 8]
+    }
+
+    #[test]
+    fn should_flip_a_set_of_boundaries() {
+        assert_eq!(
+            [0b0011100111, 0b1001101000, 0b0011010010, 0b0100111110,],
+            flip_boundaries(
+                &[
+                    0b0011010010, //vec![2, 3, 5, 8],
+                    0b0001011001, //vec![3, 5, 6, 9],
+                    0b0011100111, //vec![2, 3, 4, 7, 8, 9],
+                    0b0111110010, //vec![1, 2, 3, 4, 5, 8],
+                ],
+                10
+            )
+        );
+    }
+
+    #[test]
+    fn should_rotate_a_set_of_boundaries() {
+        assert_eq!(
+            [0b0100111110, 0b0011010010, 0b1001101000, 0b0011100111,],
+            rotate_boundaries(
+                &[
+                    0b0011010010, //vec![2, 3, 5, 8],
+                    0b0001011001, //vec![3, 5, 6, 9],
+                    0b0011100111, //vec![2, 3, 4, 7, 8, 9],
+                    0b0111110010, //vec![1, 2, 3, 4, 5, 8],
+                ],
+                10
+            )
+        );
+    }
+
+    #[test]
+    fn should_generate_eight_distinct_orientations() {
+        let image = &get_test_input()[0];
+        let orientations = generate_orientations(&image.boundaries, image.boundary_size);
+
+        assert_eq!(
+            8,
+            orientations.iter().collect::<HashSet<&Boundaries>>().len()
+        );
+        assert_eq!(
+            image.boundaries,
+            rotate_boundaries(&orientations[3], image.boundary_size)
+        );
+    }
+
+    #[test]
+    fn should_solve_the_input() {
+        let images = get_test_input();
+        let boundaries = generate_all_orientations(&images);
+        let map = HashMap::<Location, (usize, usize)>::new();
+
+        let result = solve(&images, &boundaries, &map, (0, 0), 2);
+
+        assert!(result.is_some());
+
+        let result_map = result.unwrap();
+
+        assert_eq!(1951, images[result_map[&(0, 0)].0].tile_no);
+        assert_eq!(2971, images[result_map[&(2, 0)].0].tile_no);
+        assert_eq!(3079, images[result_map[&(0, 2)].0].tile_no);
+        assert_eq!(1171, images[result_map[&(2, 2)].0].tile_no);
+    }
+
+    #[test]
+    fn should_flip_an_image() {
+        assert_eq!(
+            picture(
+                "
+##...#.#
+.#....#.
+#.#.#..#
+#...#.##
+#.##.###
+###.#...
+...##..#
+#..#...."
+            ),
+            orient_image(
+                &picture(
+                    "
+#..#....
+...##..#
+###.#...
+#.##.###
+#...#.##
+#.#.#..#
+.#....#.
+##...#.#"
+                ),
+                4
+            )
+        );
+    }
+
+    #[test]
+    fn should_rotate_an_image() {
+        assert_eq!(
+            picture(
+                "
+#.####.#
+##...#..
+..#.##..
+....#.##
+..##.##.
+#...#...
+.#.##...
+#.###.#."
+            ),
+            orient_image(
+                &picture(
+                    "
+#..#....
+...##..#
+###.#...
+#.##.###
+#...#.##
+#.#.#..#
+.#....#.
+##...#.#"
+                ),
+                1
+            )
+        );
+    }
+
+    #[test]
+    fn should_find_a_monster() {
+        assert!(check_for_monster_at(
+            &picture(
+                "
+..................#.
+#....##....##....###
+.#..#..#..#..#..#..."
+            ),
+            (0, 0)
+        ));
+    }
+
+    #[test]
+    fn day20a_test() {
+        let images = get_test_input();
+        let image_map = arrange_images(&images).unwrap();
+        let result = day20a(&images, &image_map).unwrap();
+        assert_eq!(20899048083289, result);
+    }
+
+    #[test]
+    fn day20b_test() {
+        let images = get_test_input();
+        let image_map = arrange_images(&images).unwrap();
+        let result = day20b(&images, &image_map).unwrap();
+        assert_eq!(273, result);
+    }
+}
+
+static MONSTER_LEN: usize = 20;
+static MONSTER_ROW_ONE: usize = 2;
+static MONSTER_ROW_TWO: usize = 549255;
+static MONSTER_ROW_THREE: usize = 299592;
+static MONSTER_SQUARES: usize = 15;
+
+fn pixel(c: char) -> Option<bool> {
+    match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    }
+}
+
+fn to_value(vector: &[bool]) -> usize {
+    vector
+        .iter()
+        .fold(0, |acc, &b| acc * 2 + (if b { 1 } else { 0 }))
+}
+
+fn flip_bits(number: usize, boundary_size: usize) -> usize {
+    (0..boundary_size)
+        .map(|i| 1 << i)
+        .map(|n| number & n == n)
+        .fold(0, |acc, b| acc * 2 + (if b { 1 } else { 0 }))
+}
+
+fn check_for_monster_at(image: &Grid<bool>, location: Location) -> bool {
+    let (col, row) = location;
+
+    (to_value(&image.row(row)[col..col + MONSTER_LEN]) & MONSTER_ROW_ONE == MONSTER_ROW_ONE)
+        && (to_value(&image.row(row + 1)[col..col + MONSTER_LEN]) & MONSTER_ROW_TWO
+            == MONSTER_ROW_TWO)
+        && (to_value(&image.row(row + 2)[col..col + MONSTER_LEN]) & MONSTER_ROW_THREE
+            == MONSTER_ROW_THREE)
+}
+
+fn find_monsters(image: &Grid<bool>) -> usize {
+    let max_col_row = image.height();
+    let mut monster_count = 0;
+    let mut row = 0;
+    let mut col = 0;
+
+    while row + 2 < max_col_row {
+        while col + MONSTER_LEN <= max_col_row {
+            if check_for_monster_at(image, (col, row)) {
+                // col += MONSTER_LEN;
+                monster_count += 1;
+            }
+
+            col += 1;
+        }
+
+        col = 0;
+        row += 1;
+    }
+
+    monster_count
+}
+
+fn orient_image(image: &Grid<bool>, orientation: usize) -> Grid<bool> {
+    match orientation {
+        0 => image.clone(),
+        1 => image.rotate(),
+        2 => image.rotate().rotate(),
+        3 => image.rotate().rotate().rotate(),
+        4 => image.flip(),
+        5 => image.flip().rotate(),
+        6 => image.flip().rotate().rotate(),
+        7 => image.flip().rotate().rotate().rotate(),
+        _ => unreachable!(),
+    }
+}
+
+fn get_boundary(boundaries: &Boundaries, position: Position) -> Boundary {
+    match position {
+        Position::Top => boundaries[0],
+        Position::Right => boundaries[1],
+        Position::Bottom => boundaries[2],
+        Position::Left => boundaries[3],
+    }
+}
+
+fn solve(
+    images: &[Image],
+    boundaries: &BoundaryCache,
+    current_map: &ImageMap,
+    location: Location,
+    max_col_row: usize,
+) -> Option<ImageMap> {
+    let (col, row) = location;
+
+    let seen_images: HashSet<usize> = current_map.values().map(|&(index, _)| index).collect();
+    let mut current_map = current_map.clone();
+
+    for (index, _) in images.iter().enumerate() {
+        if seen_images.contains(&index) {
+            continue;
+        }
+
+        for (orientation, bounds) in boundaries[index].iter().enumerate() {
+            if row > 0 {
+                //  Doesn't match the row above, so can't use it
+                let (above_index, above_orientation) = current_map[&(col, row - 1)];
+                if get_boundary(bounds, Position::Top)
+                    != get_boundary(
+                        &boundaries[above_index][above_orientation],
+                        Position::Bottom,
+                    )
+                {
+                    continue;
+                }
+            }
+
+            if col > 0 {
+                //  Doesn't match the col to the left, so can't use it
+                let (left_index, left_orientation) = current_map[&(col - 1, row)];
+                let left_bound = get_boundary(bounds, Position::Left);
+                let right_bound =
+                    get_boundary(&boundaries[left_index][left_orientation], Position::Right);
+                if left_bound != right_bound {
+                    continue;
+                }
+            }
+
+            if col < max_col_row {
+                let right_matches = find_matching_edges(
+                    get_boundary(bounds, Position::Right),
+                    Position::Left,
+                    images,
+                    boundaries,
+                    &seen_images,
+                    index,
+                );
+                if right_matches.is_empty() {
+                    continue;
+                }
+            }
+
+            if row < max_col_row {
+                let down_matches = find_matching_edges(
+                    get_boundary(bounds, Position::Bottom),
+                    Position::Top,
+                    images,
+                    boundaries,
+                    &seen_images,
+                    index,
+                );
+                if down_matches.is_empty() {
+                    continue;
+                }
+            }
+
+            //  Let's try this one here, see if it works
+            current_map.insert((col, row), (index, orientation));
+
+            let next_loc = if col == max_col_row && row == max_col_row {
+                return Some(current_map.clone());
+            } else if col == max_col_row {
+                (0, row + 1)
+            } else {
+                (col + 1, row)
+            };
+
+            if let Some(m) = solve(images, boundaries, &current_map, next_loc, max_col_row) {
+                return Some(m);
+            };
+        }
+    }
+
+    None
+}
+
+fn find_matching_edges(
+    boundary: Boundary,
+    position: Position,
+    images: &[Image],
+    boundaries: &BoundaryCache,
+    seen_images: &HashSet<usize>,
+    current_index: usize,
+) -> Vec<(usize, usize)> {
+    images
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| !seen_images.contains(i) && current_index != *i)
+        .flat_map(|(i, _)| {
+            boundaries[i].iter().enumerate().map(move |(or, bound)| {
+                if get_boundary(bound, position) == boundary {
+                    Some((i, or))
+                } else {
+                    None
+                }
+            })
+        })
+        .flatten()
+        .collect()
+}
+
+fn find_boundaries(picture: &Grid<bool>) -> Boundaries {
+    let column = |x| {
+        (0..picture.height())
+            .map(|y| picture[(x, y)])
+            .collect::<Vec<bool>>()
+    };
+    let top = to_value(picture.row(0));
+    let bottom = to_value(picture.row(picture.height() - 1));
+    let right = to_value(&column(picture.width() - 1));
+    let left = to_value(&column(0));
+
+    [top, right, bottom, left]
+}
+
+fn generate_all_orientations(images: &[Image]) -> BoundaryCache {
+    images
+        .iter()
+        .map(|image| generate_orientations(&image.boundaries, image.boundary_size))
+        .collect()
+}
+
+fn generate_orientations(boundaries: &Boundaries, boundary_size: usize) -> [Boundaries; 8] {
+    let r90 = rotate_boundaries(boundaries, boundary_size);
+    let r180 = rotate_boundaries(&r90, boundary_size);
+    let r270 = rotate_boundaries(&r180, boundary_size);
+    let flip = flip_boundaries(boundaries, boundary_size);
+    let flip90 = rotate_boundaries(&flip, boundary_size);
+    let flip180 = rotate_boundaries(&flip90, boundary_size);
+    let flip270 = rotate_boundaries(&flip180, boundary_size);
+
+    [*boundaries, r90, r180, r270, flip, flip90, flip180, flip270]
+}
+
+fn flip_boundaries(boundaries: &Boundaries, boundary_size: usize) -> Boundaries {
+    [
+        boundaries[2],
+        flip_bits(boundaries[1], boundary_size),
+        boundaries[0],
+        flip_bits(boundaries[3], boundary_size),
+    ]
+}
+
+fn rotate_boundaries(boundaries: &Boundaries, boundary_size: usize) -> Boundaries {
+    [
+        flip_bits(boundaries[3], boundary_size),
+        boundaries[0],
+        flip_bits(boundaries[1], boundary_size),
+        boundaries[2],
+    ]
+}
+
+//  Any error is reported relative to `input`, which `image` is a part of.
+fn parse_image(input: &str, image: &str) -> Result<Image> {
+    let mut lines = image.lines();
+    let title_line = lines.next().unwrap_or(image);
+    let tile_no = title_line
+        .strip_prefix("Tile ")
+        .and_then(|title| title.strip_suffix(':'))
+        .and_then(|number| number.parse::<usize>().ok())
+        .ok_or_else(|| {
+            Error::parse_at(
+                input,
+                title_line,
+                format!("expected a 'Tile <number>:' title, found '{}'", title_line),
+            )
+        })?;
+
+    if lines.next().is_none() {
+        return Err(Error::parse_at(
+            input,
+            title_line,
+            format!("tile {} has no pixels", tile_no),
+        ));
+    }
+
+    let full_picture = Grid::parse_within(input, &image[title_line.len()..], pixel)?;
+    let height = full_picture.height();
+    if full_picture.width() != height {
+        return Err(Error::parse_at(
+            input,
+            title_line,
+            format!(
+                "tile {} is {} pixels wide but {} high",
+                tile_no,
+                full_picture.width(),
+                height
+            ),
+        ));
+    }
+
+    if height < 3 {
+        return Err(Error::parse_at(
+            input,
+            title_line,
+            format!("tile {} has fewer than 3 rows", tile_no),
+        ));
+    }
+
+    let boundaries = find_boundaries(&full_picture);
+    let picture = full_picture.subgrid((1, 1), height - 2, height - 2);
+
+    Ok(Image {
+        tile_no,
+        picture,
+        boundaries,
+        boundary_size: height,
+    })
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Image>> {
+    let images = groups(input)
+        .into_iter()
+        .map(|image| parse_image(input, image))
+        .collect::<Result<Vec<Image>>>()?;
+
+    let side = (images.len() as f64).sqrt() as usize;
+    if images.is_empty() || side * side != images.len() {
+        return Err(Error::invalid_shape(format!(
+            "{} tiles cannot make a square image",
+            images.len()
+        )));
+    }
+
+    if images
+        .iter()
+        .any(|image| image.boundary_size != images[0].boundary_size)
+    {
+        return Err(Error::invalid_shape("the tiles are not all the same size"));
+    }
+
+    Ok(images)
+}
+
+pub fn arrange_images(images: &[Image]) -> Result<ImageMap> {
+    let boundaries = generate_all_orientations(images);
+    let max_col_row = (images.len() as f64).sqrt() as usize - 1;
+    solve(
+        images,
+        &boundaries,
+        &HashMap::<Location, (usize, usize)>::new(),
+        (0, 0),
+        max_col_row,
+    )
+    .ok_or_else(|| Error::no_solution("the tiles cannot be arranged so that every edge matches"))
+}
+
+pub fn day20a(images: &[Image], image_map: &ImageMap) -> Result<usize> {
+    let max_col_row = (images.len() as f64).sqrt() as usize - 1;
+
+    Ok(images[image_map[&(0, 0)].0].tile_no
+        * images[image_map[&(0, max_col_row)].0].tile_no
+        * images[image_map[&(max_col_row, 0)].0].tile_no
+        * images[image_map[&(max_col_row, max_col_row)].0].tile_no)
+}
+
+pub fn day20b(images: &[Image], image_map: &ImageMap) -> Result<usize> {
+    let max_col_row = (images.len() as f64).sqrt() as usize;
+    let height = images[0].picture.height();
+    let tiles: HashMap<Location, Grid<bool>> = image_map
+        .iter()
+        .map(|(&location, &(index, orientation))| {
+            (location, orient_image(&images[index].picture, orientation))
+        })
+        .collect();
+    let full_image = Grid::from_fn(max_col_row * height, max_col_row * height, |(x, y)| {
+        tiles[&(x / height, y / height)][(x % height, y % height)]
+    });
+
+    let total_count = full_image.iter().filter(|&&b| b).count();
+
+    for orientation in 0..8 {
+        let test_image = orient_image(&full_image, orientation);
+
+        match find_monsters(&test_image) {
+            0 => {}
+            monster_count => {
+                return Ok(total_count - (monster_count * MONSTER_SQUARES));
+            }
+        }
+    }
+
+    Err(Error::no_solution(
+        "there are no sea monsters in any orientation of the image",
+    ))
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input = Vec<Image>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<Image>> {
+        parse_input(input)
+    }
+
+    fn part1(images: &Vec<Image>) -> Result<usize> {
+        day20a(images, &arrange_images(images)?)
+    }
+
+    fn part2(images: &Vec<Image>) -> Result<usize> {
+        day20b(images, &arrange_images(images)?)
+    }
+}
@@ -1,10 +1,10 @@
+use aoc_common::input::lines;
+use aoc_common::{Error, Result, Solution};
 use std::collections::HashMap;
 use std::collections::HashSet;
-use std::fs::File;
-use std::io::{BufReader, Read, Result};
 
 #[derive(Debug, PartialEq)]
-struct Recipe {
+pub struct Recipe {
     ingredients: Vec<String>,
     allergens: Vec<String>,
 }
@@ -20,7 +20,7 @@ sqjhc mxmxvkd sbzzf (contains fish)
 ";
 
     fn get_test_input() -> Vec<Recipe> {
-        parse_input(TEST_INPUT)
+        parse_input(TEST_INPUT).unwrap()
     }
 
     #[test]
@@ -35,7 +35,7 @@ sqjhc mxmxvkd sbzzf (contains fish)
                 ],
                 allergens: vec!["dairy".to_string(), "fish".to_string()]
             },
-            parse_recipe("mxmxvkd kfcds sqjhc nhms (contains dairy, fish)")
+            parse_recipe("mxmxvkd kfcds sqjhc nhms (contains dairy, fish)").unwrap()
         );
         assert_eq!(
             Recipe {
@@ -46,10 +46,26 @@ sqjhc mxmxvkd sbzzf (contains fish)
                 ],
                 allergens: vec!["fish".to_string()]
             },
-            parse_recipe("sqjhc mxmxvkd sbzzf (contains fish)")
+            parse_recipe("sqjhc mxmxvkd sbzzf (contains fish)").unwrap()
         );
     }
 
+    #[test]
+    fn should_reject_malformed_recipes() {
+        assert_eq!(
+            "line 2, column 1: invalid recipe 'sqjhc fvjkl (contains soy'",
+            parse_input("mxmxvkd (contains dairy)\nsqjhc fvjkl (contains soy")
+                .unwrap_err()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn should_fail_when_allergens_cannot_be_told_apart() {
+        let recipes = parse_input("a b (contains dairy, fish)").unwrap();
+        assert!(day21b(&recipes).is_err());
+    }
+
     #[test]
     fn should_find_ingredients_that_are_allergen_candidates() {
         assert_eq!(
@@ -90,16 +106,16 @@ sqjhc mxmxvkd sbzzf (contains fish)
 
     #[test]
     fn day21a_test() {
-        assert_eq!(5, day21a(&get_test_input()))
+        assert_eq!(5, day21a(&get_test_input()).unwrap())
     }
 
     #[test]
     fn day21b_test() {
-        assert_eq!("mxmxvkd,sqjhc,fvjkl", day21b(&get_test_input()))
+        assert_eq!("mxmxvkd,sqjhc,fvjkl", day21b(&get_test_input()).unwrap())
     }
 }
 
-fn count_ingredient_usage(recipes: &Vec<Recipe>) -> HashMap<String, usize> {
+fn count_ingredient_usage(recipes: &[Recipe]) -> HashMap<String, usize> {
     let mut map = HashMap::<String, usize>::new();
 
     for recipe in recipes {
@@ -112,7 +128,7 @@ fn count_ingredient_usage(recipes: &Vec<Recipe>) -> HashMap<String, usize> {
     map
 }
 
-fn find_allergen_candidates(recipes: &Vec<Recipe>) -> HashMap<String, Vec<String>> {
+fn find_allergen_candidates(recipes: &[Recipe]) -> HashMap<String, Vec<String>> {
     let mut candidates = HashMap::<String, Vec<String>>::new();
 
     for recipe in recipes {
@@ -122,8 +138,8 @@ fn find_allergen_candidates(recipes: &Vec<Recipe>) -> HashMap<String, Vec<String
             } else {
                 candidates[allergen]
                     .iter()
-                    .map(|ingredient| ingredient.clone())
-                    .filter(|ingredient| recipe.ingredients.contains(ingredient))
+                    .filter(|&ingredient| recipe.ingredients.contains(ingredient))
+                    .cloned()
                     .collect()
             };
 
@@ -134,43 +150,41 @@ fn find_allergen_candidates(recipes: &Vec<Recipe>) -> HashMap<String, Vec<String
     candidates
 }
 
-fn parse_recipe(line: &str) -> Recipe {
+fn parse_recipe(line: &str) -> Option<Recipe> {
     let mut parts = line.split("(contains");
-    let ingredients = parts
-        .next()
-        .unwrap()
-        .trim()
-        .split(" ")
-        .map(|s| s.trim().to_string())
+    let ingredients: Vec<String> = parts
+        .next()?
+        .split_whitespace()
+        .map(|s| s.to_string())
         .collect();
-    let allergens = parts
-        .next()
-        .unwrap()
-        .replace(")", "")
+    let allergens: Vec<String> = parts
+        .next()?
+        .trim()
+        .strip_suffix(")")?
         .split(",")
         .map(|s| s.trim().to_string())
         .collect();
 
-    Recipe {
-        ingredients: ingredients,
-        allergens: allergens,
+    if ingredients.is_empty() || allergens.iter().any(|a| a.is_empty()) {
+        return None;
     }
-}
 
-fn parse_input(input: &str) -> Vec<Recipe> {
-    input.lines().map(|line| parse_recipe(line)).collect()
+    Some(Recipe {
+        ingredients,
+        allergens,
+    })
 }
 
-fn read_file(path: &str) -> Result<String> {
-    let file = File::open(path)?;
-    let mut buf_reader = BufReader::new(file);
-    let mut input = String::new();
-    buf_reader.read_to_string(&mut input)?;
-
-    Ok(input)
+pub fn parse_input(input: &str) -> Result<Vec<Recipe>> {
+    lines(input)
+        .map(|line| {
+            parse_recipe(line)
+                .ok_or_else(|| Error::parse_at(input, line, format!("invalid recipe '{}'", line)))
+        })
+        .collect()
 }
 
-fn day21a(recipes: &Vec<Recipe>) -> usize {
+pub fn day21a(recipes: &[Recipe]) -> Result<usize> {
     let usage_counts = count_ingredient_usage(recipes);
 
     let allergen_opts: HashSet<String> = find_allergen_candidates(recipes)
@@ -178,32 +192,40 @@ fn day21a(recipes: &Vec<Recipe>) -> usize {
         .flat_map(|v| v.clone())
         .collect();
 
-    usage_counts
+    Ok(usage_counts
         .iter()
         .filter(|&(ingredient, _)| !allergen_opts.contains(ingredient))
         .map(|(_, count)| count)
-        .sum()
+        .sum())
 }
 
-fn day21b(recipes: &Vec<Recipe>) -> String {
+pub fn day21b(recipes: &[Recipe]) -> Result<String> {
     let allergen_opts = find_allergen_candidates(recipes);
     let mut dangerous_ingredients = HashMap::<String, String>::new();
 
     while dangerous_ingredients.len() < allergen_opts.len() {
+        let found = dangerous_ingredients.len();
+
         for (allergen, opts) in allergen_opts.iter() {
             let filtered_opts: Vec<String> = opts
                 .iter()
                 .filter(|&ingredient| !dangerous_ingredients.contains_key(ingredient))
-                .map(|ingredient| ingredient.clone())
+                .cloned()
                 .collect();
 
             if filtered_opts.len() == 1 {
                 dangerous_ingredients.insert(filtered_opts[0].clone(), allergen.clone());
             }
         }
+
+        if dangerous_ingredients.len() == found {
+            return Err(Error::no_solution(
+                "the allergens cannot be matched to a single ingredient each",
+            ));
+        }
     }
 
-    let mut allergens: Vec<String> = allergen_opts.keys().map(|s| s.clone()).collect();
+    let mut allergens: Vec<String> = allergen_opts.keys().cloned().collect();
     allergens.sort();
 
     let sorted_ingredients: Vec<String> = allergens
@@ -218,31 +240,25 @@ fn day21b(recipes: &Vec<Recipe>) -> String {
         })
         .collect();
 
-    sorted_ingredients.join(",")
+    Ok(sorted_ingredients.join(","))
 }
 
-fn main() -> Result<()> {
-    let input = read_file("input")?;
-    let recipes = parse_input(&input);
-
-    use std::time::Instant;
-    let total = Instant::now();
-
-    let result = day21a(&recipes);
-    println!(
-        "Day 21A - {} ({:.2}ms)",
-        result,
-        total.elapsed().as_millis()
-    );
-
-    let part2 = Instant::now();
-    let result = day21b(&recipes);
-    println!(
-        "Day 21B - {} ({:.2}ms)",
-        result,
-        part2.elapsed().as_millis()
-    );
-    println!("Total ({:.2}ms)", total.elapsed().as_millis());
-
-    Ok(())
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input = Vec<Recipe>;
+    type Part1 = usize;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<Vec<Recipe>> {
+        parse_input(input)
+    }
+
+    fn part1(recipes: &Vec<Recipe>) -> Result<usize> {
+        day21a(recipes)
+    }
+
+    fn part2(recipes: &Vec<Recipe>) -> Result<String> {
+        day21b(recipes)
+    }
 }
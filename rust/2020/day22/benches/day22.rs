@@ -0,0 +1,38 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use day22::{day22a, day22b, parse_input};
+
+static TEST_INPUT: &str = include_str!("../example.txt");
+
+//  Inputs of other sizes from the generator, seeded by their size.
+fn synthetic_input(size: usize) -> String {
+    aoc_generate::generate("2020", 22, size as u64, Some(size))
+        .unwrap()
+        .input
+}
+
+fn day22_benches(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day22");
+    group.sample_size(10);
+
+    let inputs = vec![
+        (String::from("example"), String::from(TEST_INPUT)),
+        (String::from("15 cards"), synthetic_input(15)),
+        (String::from("25 cards"), synthetic_input(25)),
+    ];
+
+    for (name, input) in &inputs {
+        let hands = parse_input(input).unwrap();
+
+        group.bench_with_input(BenchmarkId::new("part1", name), &hands, |b, hands| {
+            b.iter(|| day22a(hands))
+        });
+        group.bench_with_input(BenchmarkId::new("part2", name), &hands, |b, hands| {
+            b.iter(|| day22b(hands))
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, day22_benches);
+criterion_main!(benches);
@@ -0,0 +1,337 @@
+use aoc_common::input::{groups, lines};
+use aoc_common::trace::{trace, Frame, NoTrace, Traced, Tracer};
+use aoc_common::{Error, Result, Solution};
+use std::collections::HashSet;
+
+type Hands = (Vec<usize>, Vec<usize>);
+
+//  Plays recursive combat, numbering the games and counting the rounds across
+//  all of them so that each round can be traced.
+struct RecursiveCombat<'a, T: Tracer + ?Sized> {
+    tracer: &'a mut T,
+    games: usize,
+    rounds: usize,
+}
+
+#[cfg(test)]
+mod day22_tests {
+    use super::*;
+    use aoc_common::trace::Value;
+
+    static TEST_INPUT: &str = include_str!("../example.txt");
+
+    fn get_test_input() -> Hands {
+        (vec![9, 2, 6, 3, 1], vec![5, 8, 4, 7, 10])
+    }
+
+    #[test]
+    fn should_parse_input_to_hands() {
+        assert_eq!(get_test_input(), parse_input(TEST_INPUT).unwrap());
+    }
+
+    #[test]
+    fn should_reject_malformed_hands() {
+        assert_eq!(
+            "line 3, column 1: 'x' is not a card",
+            parse_input("Player 1:\n9\nx\n\nPlayer 2:\n5")
+                .unwrap_err()
+                .to_string()
+        );
+        assert!(parse_input("Player 1:\n9\n2").is_err());
+        assert!(parse_input("Player 1:\n9\n\nPlayer 3:\n5").is_err());
+    }
+
+    #[test]
+    fn should_play_a_round_of_combat() {
+        let result = play_round(&get_test_input());
+        assert_eq!((vec![2, 6, 3, 1, 9, 5], vec![8, 4, 7, 10]), result);
+        let result = play_round(&result);
+        assert_eq!((vec![6, 3, 1, 9, 5], vec![4, 7, 10, 8, 2]), result);
+    }
+
+    #[test]
+    fn should_play_a_recursive_round() {
+        let result =
+            RecursiveCombat::new(&mut NoTrace).round(&(vec![4, 9, 8, 5, 2], vec![3, 10, 1, 7, 6]));
+        assert_eq!((vec![9, 8, 5, 2], vec![10, 1, 7, 6, 3, 4]), result)
+    }
+
+    #[test]
+    fn should_handle_an_infinite_recursive_game() {
+        let result = play_recursive_game(&(vec![43, 19], vec![2, 29, 14]));
+        assert_eq!((vec![43, 19], vec![]), result)
+    }
+
+    #[test]
+    fn day22a_test() {
+        assert_eq!(306, day22a(&get_test_input()).unwrap());
+    }
+
+    #[test]
+    fn day22b_test() {
+        assert_eq!(291, day22b(&get_test_input()).unwrap());
+    }
+
+    #[test]
+    fn should_trace_each_round() {
+        let mut frames = vec![];
+        Day22::trace_part1(&get_test_input(), &mut frames).unwrap();
+
+        assert_eq!(30, frames.len());
+        assert_eq!("round 1: player 1 wins with 9 against 5", frames[1].caption);
+        assert_eq!(
+            ("player1", Value::from(vec![2, 6, 3, 1, 9, 5])),
+            frames[1].state[0]
+        );
+        assert_eq!(
+            ("player2", Value::from(vec![8, 4, 7, 10])),
+            frames[1].state[1]
+        );
+
+        let mut frames = vec![];
+        Day22::trace_part2(&get_test_input(), &mut frames).unwrap();
+
+        assert_eq!(30, frames.len());
+        assert_eq!(
+            "game 2, round 1: player 2 wins with 10 against 9",
+            frames[9].caption
+        );
+        assert_eq!(
+            "game 1, round 9: player 2 wins with 3 against 4",
+            frames[15].caption
+        );
+    }
+}
+
+fn play_recursive_game(hands: &Hands) -> Hands {
+    RecursiveCombat::new(&mut NoTrace).game(hands)
+}
+
+fn deal_frame(hands: &Hands) -> Frame {
+    Frame::new(0, "deal")
+        .state("player1", hands.0.clone())
+        .state("player2", hands.1.clone())
+}
+
+//  The winner of a round is whoever's hand grew.
+fn round_frame(step: usize, round: String, before: &Hands, after: &Hands) -> Frame {
+    let (winner, won, lost) = if after.0.len() > before.0.len() {
+        (1, before.0[0], before.1[0])
+    } else {
+        (2, before.1[0], before.0[0])
+    };
+
+    Frame::new(
+        step,
+        format!(
+            "{}: player {} wins with {} against {}",
+            round, winner, won, lost
+        ),
+    )
+    .state("player1", after.0.clone())
+    .state("player2", after.1.clone())
+}
+
+impl<'a, T: Tracer + ?Sized> RecursiveCombat<'a, T> {
+    fn new(tracer: &'a mut T) -> RecursiveCombat<'a, T> {
+        RecursiveCombat {
+            tracer,
+            games: 0,
+            rounds: 0,
+        }
+    }
+
+    fn round(&mut self, hands: &Hands) -> Hands {
+        let player1_card = hands.0[0];
+        let player2_card = hands.1[0];
+
+        let mut player1_hand: Vec<usize> = hands.0[1..].to_vec();
+        let mut player2_hand: Vec<usize> = hands.1[1..].to_vec();
+
+        let player1_win =
+            if player1_card <= player1_hand.len() && player2_card <= player2_hand.len() {
+                let subgame_player1 = player1_hand[0..player1_card].to_vec();
+                let subgame_player2 = player2_hand[0..player2_card].to_vec();
+                let subgame_result = self.game(&(subgame_player1, subgame_player2));
+
+                !subgame_result.0.is_empty()
+            } else {
+                player1_card > player2_card
+            };
+
+        if player1_win {
+            player1_hand.push(player1_card);
+            player1_hand.push(player2_card);
+        } else {
+            player2_hand.push(player2_card);
+            player2_hand.push(player1_card);
+        }
+
+        (player1_hand, player2_hand)
+    }
+
+    fn game(&mut self, hands: &Hands) -> Hands {
+        self.games += 1;
+        let game = self.games;
+        let mut round = 0;
+
+        let mut current_hands = hands.clone();
+        let mut seen_hands = HashSet::<Hands>::new();
+
+        while !current_hands.0.is_empty() && !current_hands.1.is_empty() {
+            if seen_hands.contains(&current_hands) {
+                return (current_hands.0, vec![]);
+            }
+
+            seen_hands.insert(current_hands.clone());
+            let next_hands = self.round(&current_hands);
+
+            round += 1;
+            self.rounds += 1;
+            let step = self.rounds;
+            trace(self.tracer, || {
+                let round = format!("game {}, round {}", game, round);
+                round_frame(step, round, &current_hands, &next_hands)
+            });
+            current_hands = next_hands;
+        }
+
+        current_hands
+    }
+}
+
+fn play_round(hands: &Hands) -> Hands {
+    let player1_card = hands.0[0];
+    let player2_card = hands.1[0];
+
+    let mut player1_hand: Vec<usize> = hands.0[1..].to_vec();
+    let mut player2_hand: Vec<usize> = hands.1[1..].to_vec();
+
+    if player1_card > player2_card {
+        player1_hand.push(player1_card);
+        player1_hand.push(player2_card);
+    } else {
+        player2_hand.push(player2_card);
+        player2_hand.push(player1_card);
+    }
+
+    (player1_hand, player2_hand)
+}
+
+fn parse_hand(input: &str, hand: &str, player: usize) -> Result<Vec<usize>> {
+    let mut lines = lines(hand);
+    let heading = format!("Player {}:", player);
+
+    match lines.next() {
+        Some(line) if line.trim() == heading => {}
+        Some(line) => {
+            return Err(Error::parse_at(
+                input,
+                line,
+                format!("expected '{}', found '{}'", heading, line),
+            ))
+        }
+        None => {
+            return Err(Error::invalid_shape(format!(
+                "missing hand for player {}",
+                player
+            )))
+        }
+    }
+
+    lines
+        .map(|line| {
+            line.trim()
+                .parse::<usize>()
+                .map_err(|_| Error::parse_at(input, line, format!("'{}' is not a card", line)))
+        })
+        .collect()
+}
+
+pub fn parse_input(input: &str) -> Result<Hands> {
+    let hands = groups(input);
+
+    if hands.len() != 2 {
+        return Err(Error::invalid_shape(format!(
+            "expected 2 hands, found {}",
+            hands.len()
+        )));
+    }
+
+    Ok((
+        parse_hand(input, hands[0], 1)?,
+        parse_hand(input, hands[1], 2)?,
+    ))
+}
+
+fn score(hands: Hands) -> usize {
+    let winning_hand = if hands.0.is_empty() { hands.1 } else { hands.0 };
+
+    winning_hand
+        .into_iter()
+        .rev()
+        .enumerate()
+        .map(|(i, n)| n * (i + 1))
+        .sum()
+}
+
+fn play_game<T: Tracer + ?Sized>(hands: &Hands, tracer: &mut T) -> usize {
+    let mut current_hands = hands.clone();
+    let mut round = 0;
+    trace(tracer, || deal_frame(hands));
+
+    while !current_hands.0.is_empty() && !current_hands.1.is_empty() {
+        let next_hands = play_round(&current_hands);
+        round += 1;
+        trace(tracer, || {
+            round_frame(
+                round,
+                format!("round {}", round),
+                &current_hands,
+                &next_hands,
+            )
+        });
+        current_hands = next_hands;
+    }
+
+    score(current_hands)
+}
+
+pub fn day22a(hands: &Hands) -> Result<usize> {
+    Ok(play_game(hands, &mut NoTrace))
+}
+
+pub fn day22b(hands: &Hands) -> Result<usize> {
+    Ok(score(play_recursive_game(hands)))
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input = Hands;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Hands> {
+        parse_input(input)
+    }
+
+    fn part1(hands: &Hands) -> Result<usize> {
+        day22a(hands)
+    }
+
+    fn part2(hands: &Hands) -> Result<usize> {
+        day22b(hands)
+    }
+}
+
+impl Traced for Day22 {
+    fn trace_part1(hands: &Hands, tracer: &mut dyn Tracer) -> Result<usize> {
+        Ok(play_game(hands, tracer))
+    }
+
+    fn trace_part2(hands: &Hands, tracer: &mut dyn Tracer) -> Result<usize> {
+        trace(tracer, || deal_frame(hands));
+        Ok(score(RecursiveCombat::new(tracer).game(hands)))
+    }
+}
@@ -0,0 +1,28 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use day23::{parse_input, play_game};
+
+static TEST_INPUT: &str = "389125467";
+
+//  Part two is a million cups for ten million moves; keep the same ratio of
+//  moves to cups and scale both down.
+fn day23_benches(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day23");
+    group.sample_size(10);
+
+    let cups = parse_input(TEST_INPUT).unwrap();
+
+    group.bench_function("part1/example", |b| b.iter(|| play_game(&cups, 9, 100)));
+
+    for &cup_count in &[10000, 100000, 1000000] {
+        group.bench_with_input(
+            BenchmarkId::new("cups", cup_count),
+            &cup_count,
+            |b, &cup_count| b.iter(|| play_game(&cups, cup_count, cup_count * 10)),
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, day23_benches);
+criterion_main!(benches);
@@ -0,0 +1,307 @@
+use aoc_common::trace::{trace, Frame, NoTrace, Traced, Tracer};
+use aoc_common::{Error, Result, Solution};
+
+type Cups = Vec<usize>;
+type CupInput = [usize; 9];
+
+#[cfg(test)]
+mod day23_tests {
+    use super::*;
+    use aoc_common::trace::Value;
+
+    static TEST_INPUT: [usize; 9] = [3, 8, 9, 1, 2, 5, 4, 6, 7];
+
+    #[test]
+    fn should_parse_the_cup_labels() {
+        assert_eq!(TEST_INPUT, parse_input("389125467\n").unwrap());
+        assert_eq!(
+            "line 1, column 3: '0' is not a cup label",
+            parse_input("380125467").unwrap_err().to_string()
+        );
+        assert!(parse_input("38912546").is_err());
+        assert!(parse_input("389125466").is_err());
+    }
+
+    #[test]
+    fn should_convert_an_input_to_vec_linked_list() {
+        assert_eq!(
+            vec![0, 2, 5, 8, 6, 4, 7, 3, 9, 1],
+            to_linked_list(&TEST_INPUT)
+        );
+    }
+
+    #[test]
+    fn should_move_cups_in_a_turn() {
+        let mut state = to_linked_list(&TEST_INPUT);
+        let next_cup = play_turn(&mut state, 3);
+        assert_eq!(2, next_cup);
+        assert_eq!(to_linked_list(&[2, 8, 9, 1, 5, 4, 6, 7, 3,]), state);
+
+        let next_cup = play_turn(&mut state, next_cup);
+        assert_eq!(5, next_cup);
+        assert_eq!(to_linked_list(&[5, 4, 6, 7, 8, 9, 1, 3, 2,]), state);
+
+        let next_cup = play_turn(&mut state, next_cup);
+        assert_eq!(8, next_cup);
+        assert_eq!(to_linked_list(&[8, 9, 1, 3, 4, 6, 7, 2, 5,]), state);
+
+        let next_cup = play_turn(&mut state, next_cup);
+        assert_eq!(4, next_cup);
+        assert_eq!(to_linked_list(&[4, 6, 7, 9, 1, 3, 2, 5, 8,]), state);
+
+        let next_cup = play_turn(&mut state, next_cup);
+        assert_eq!(1, next_cup);
+        assert_eq!(to_linked_list(&[1, 3, 6, 7, 9, 2, 5, 8, 4,]), state);
+
+        let next_cup = play_turn(&mut state, next_cup);
+        assert_eq!(9, next_cup);
+        assert_eq!(to_linked_list(&[9, 3, 6, 7, 2, 5, 8, 4, 1,]), state);
+
+        let next_cup = play_turn(&mut state, next_cup);
+        assert_eq!(2, next_cup);
+        assert_eq!(to_linked_list(&[2, 5, 8, 3, 6, 7, 4, 1, 9,]), state);
+
+        let next_cup = play_turn(&mut state, next_cup);
+        assert_eq!(6, next_cup);
+        assert_eq!(to_linked_list(&[6, 7, 4, 1, 5, 8, 3, 9, 2,]), state);
+
+        let next_cup = play_turn(&mut state, next_cup);
+        assert_eq!(5, next_cup);
+        assert_eq!(to_linked_list(&[5, 7, 4, 1, 8, 3, 9, 2, 6,]), state);
+
+        let next_cup = play_turn(&mut state, next_cup);
+        assert_eq!(8, next_cup);
+        assert_eq!(to_linked_list(&[8, 3, 7, 4, 1, 9, 2, 6, 5,]), state);
+    }
+
+    #[test]
+    fn day23a_test() {
+        assert_eq!("67384529".to_string(), day23a(&TEST_INPUT).unwrap());
+    }
+
+    #[test]
+    fn day23b_test() {
+        assert_eq!(149245887792, day23b(&TEST_INPUT).unwrap());
+    }
+
+    #[test]
+    fn should_trace_each_move() {
+        let mut frames = vec![];
+        Day23::trace_part1(&TEST_INPUT, &mut frames).unwrap();
+
+        assert_eq!(101, frames.len());
+        assert_eq!(
+            ("cups", Value::from(vec![3, 8, 9, 1, 2, 5, 4, 6, 7])),
+            frames[0].state[1]
+        );
+        assert_eq!("move 1: picked up 8 9 1, placed after 2", frames[1].caption);
+        assert_eq!(
+            ("cups", Value::from(vec![2, 8, 9, 1, 5, 4, 6, 7, 3])),
+            frames[1].state[1]
+        );
+        assert_eq!("move 3: picked up 4 6 7, placed after 3", frames[3].caption);
+    }
+}
+
+fn to_linked_list(cups: &CupInput) -> Cups {
+    let mut state = vec![0; cups.len() + 1];
+
+    for i in 0..cups.len() {
+        state[cups[i]] = cups[(i + 1) % cups.len()];
+    }
+
+    state
+}
+
+//  The three cups after the current one, which are picked up in a turn.
+fn picked_up(state: &Cups, current_cup: usize) -> [usize; 3] {
+    let start_cup_to_move = state[current_cup];
+    let mid_cup_to_move = state[start_cup_to_move];
+    [start_cup_to_move, mid_cup_to_move, state[mid_cup_to_move]]
+}
+
+//  The highest labelled cup below the current one that wasn't picked up,
+//  wrapping around to the highest label.
+fn destination(state: &Cups, current_cup: usize, picked_up: [usize; 3]) -> usize {
+    let mut target_cup = current_cup;
+
+    loop {
+        if target_cup == 1 {
+            target_cup = state.len();
+        }
+
+        target_cup -= 1;
+
+        if !picked_up.contains(&target_cup) {
+            return target_cup;
+        }
+    }
+}
+
+fn play_turn(state: &mut Cups, current_cup: usize) -> usize {
+    let cups_to_move = picked_up(state, current_cup);
+    let [start_cup_to_move, _, end_cup_to_move] = cups_to_move;
+    let next_cup = state[end_cup_to_move];
+    let target_cup = destination(state, current_cup, cups_to_move);
+
+    state[current_cup] = next_cup;
+
+    let cup_to_link = state[target_cup];
+    state[target_cup] = start_cup_to_move;
+    state[end_cup_to_move] = cup_to_link;
+
+    next_cup
+}
+
+pub fn parse_input(input: &str) -> Result<CupInput> {
+    let labels = input.trim();
+    let mut cups = [0; 9];
+    let mut count = 0;
+
+    for (i, c) in labels.char_indices() {
+        let cup = match c.to_digit(10) {
+            Some(n) if n > 0 && count < cups.len() && !cups.contains(&(n as usize)) => n as usize,
+            _ => {
+                return Err(Error::parse_at(
+                    input,
+                    &labels[i..i + c.len_utf8()],
+                    format!("'{}' is not a cup label", c),
+                ))
+            }
+        };
+
+        cups[count] = cup;
+        count += 1;
+    }
+
+    //  Every label from 1 to 9 has to appear exactly once for the cups to
+    //  form a single circle.
+    if count != cups.len() {
+        return Err(Error::invalid_shape(format!(
+            "expected 9 cups, found {}",
+            count
+        )));
+    }
+
+    Ok(cups)
+}
+
+//  The circle read from the current cup, stopping after nine cups so that
+//  tracing a million cups stays cheap.
+fn move_frame(step: usize, caption: String, state: &Cups, current_cup: usize) -> Frame {
+    let mut cups = vec![current_cup];
+    while cups.len() < 9 && state[cups[cups.len() - 1]] != current_cup {
+        cups.push(state[cups[cups.len() - 1]]);
+    }
+
+    Frame::new(step, caption)
+        .state("current", current_cup)
+        .state("cups", cups)
+}
+
+//  Extends the labelled cups with the rest up to cup_count, in order, and
+//  returns the circle after the given number of moves as a linked list.
+pub fn play_game(cups: &CupInput, cup_count: usize, moves: usize) -> Cups {
+    play_traced_game(cups, cup_count, moves, &mut NoTrace)
+}
+
+fn play_traced_game<T: Tracer + ?Sized>(
+    cups: &CupInput,
+    cup_count: usize,
+    moves: usize,
+    tracer: &mut T,
+) -> Cups {
+    let mut game_state = to_linked_list(cups);
+    let mut next_cup = cups[0];
+
+    if cup_count > cups.len() {
+        for i in cups.len() + 1..=cup_count {
+            game_state.push(i + 1);
+        }
+
+        game_state[cups[cups.len() - 1]] = cups.len() + 1;
+        game_state[cup_count] = cups[0];
+    }
+
+    trace(tracer, || {
+        move_frame(0, String::from("start"), &game_state, next_cup)
+    });
+    for step in 1..=moves {
+        let current_cup = next_cup;
+        let picked_up = picked_up(&game_state, current_cup);
+        next_cup = play_turn(&mut game_state, current_cup);
+
+        trace(tracer, || {
+            let caption = format!(
+                "move {}: picked up {} {} {}, placed after {}",
+                step,
+                picked_up[0],
+                picked_up[1],
+                picked_up[2],
+                destination(&game_state, current_cup, picked_up)
+            );
+            move_frame(step, caption, &game_state, next_cup)
+        });
+    }
+
+    game_state
+}
+
+fn labels_after_1(game_state: &Cups) -> String {
+    let mut next_index = game_state[1];
+    let mut game_result = vec![];
+
+    while next_index != 1 {
+        game_result.push((next_index as u8 + b'0') as char);
+        next_index = game_state[next_index];
+    }
+
+    game_result.iter().collect()
+}
+
+fn star_cups(game_state: &Cups) -> usize {
+    let star1 = game_state[1];
+    let star2 = game_state[star1];
+
+    star1 * star2
+}
+
+pub fn day23a(cups: &CupInput) -> Result<String> {
+    Ok(labels_after_1(&play_game(cups, cups.len(), 100)))
+}
+
+pub fn day23b(cups: &CupInput) -> Result<usize> {
+    Ok(star_cups(&play_game(cups, 1000000, 10000000)))
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input = CupInput;
+    type Part1 = String;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<CupInput> {
+        parse_input(input)
+    }
+
+    fn part1(cups: &CupInput) -> Result<String> {
+        day23a(cups)
+    }
+
+    fn part2(cups: &CupInput) -> Result<usize> {
+        day23b(cups)
+    }
+}
+
+impl Traced for Day23 {
+    fn trace_part1(cups: &CupInput, tracer: &mut dyn Tracer) -> Result<String> {
+        let game_state = play_traced_game(cups, cups.len(), 100, tracer);
+        Ok(labels_after_1(&game_state))
+    }
+
+    fn trace_part2(cups: &CupInput, tracer: &mut dyn Tracer) -> Result<usize> {
+        let game_state = play_traced_game(cups, 1000000, 10000000, tracer);
+        Ok(star_cups(&game_state))
+    }
+}
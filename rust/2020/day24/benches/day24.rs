@@ -0,0 +1,42 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use day24::{day24a, day24b, parse_input};
+
+static TEST_INPUT: &str = include_str!("../example.txt");
+
+//  Inputs of other sizes from the generator, seeded by their size.
+fn synthetic_input(size: usize) -> String {
+    aoc_generate::generate("2020", 24, size as u64, Some(size))
+        .unwrap()
+        .input
+}
+
+fn day24_benches(c: &mut Criterion) {
+    let mut group = c.benchmark_group("day24");
+    group.sample_size(10);
+
+    let inputs = vec![
+        (String::from("example"), String::from(TEST_INPUT)),
+        (String::from("300 tiles"), synthetic_input(300)),
+        (String::from("1000 tiles"), synthetic_input(1000)),
+    ];
+
+    for (name, input) in &inputs {
+        let movements = parse_input(input).unwrap();
+
+        group.bench_with_input(
+            BenchmarkId::new("part1", name),
+            &movements,
+            |b, movements| b.iter(|| day24a(movements)),
+        );
+        group.bench_with_input(
+            BenchmarkId::new("part2", name),
+            &movements,
+            |b, movements| b.iter(|| day24b(movements)),
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, day24_benches);
+criterion_main!(benches);
@@ -0,0 +1,421 @@
+use aoc_common::automaton::{Automaton, BirthSurvival, Hex};
+use aoc_common::input::lines;
+use aoc_common::trace::{bounds, draw_points, trace, Frame, NoTrace, Traced, Tracer};
+use aoc_common::{Error, Result, Solution};
+use std::collections::HashSet;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Movement {
+    East,
+    NorthEast,
+    SouthEast,
+    West,
+    NorthWest,
+    SouthWest,
+}
+
+type Point = (isize, isize);
+
+#[cfg(test)]
+mod day24_test {
+    use super::*;
+    use aoc_common::automaton::HEX_DIRECTIONS;
+    use aoc_common::trace::Value;
+
+    static TEST_INPUT: &str = include_str!("../example.txt");
+
+    fn get_test_input() -> Vec<Vec<Movement>> {
+        parse_input(TEST_INPUT).unwrap()
+    }
+
+    fn update_tile(point: Point, flipped_tiles: &HashSet<Point>) -> bool {
+        let mut floor = lobby_floor(flipped_tiles.iter().copied());
+        floor.step();
+        floor.is_alive(point)
+    }
+
+    #[test]
+    fn should_parse_a_tile_movement_line() {
+        assert_eq!(
+            vec![
+                Movement::SouthEast,
+                Movement::SouthEast,
+                Movement::NorthWest,
+                Movement::NorthEast,
+                Movement::NorthEast,
+                Movement::NorthEast,
+                Movement::West,
+                Movement::SouthEast,
+                Movement::East,
+                Movement::SouthWest,
+                Movement::West,
+                Movement::SouthWest,
+                Movement::SouthWest,
+                Movement::West,
+                Movement::NorthEast,
+                Movement::NorthEast,
+                Movement::West,
+                Movement::SouthEast,
+                Movement::West,
+                Movement::SouthWest,
+            ],
+            parse_line(
+                "sesenwnenenewseeswwswswwnenewsewsw",
+                "sesenwnenenewseeswwswswwnenewsewsw"
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn should_report_where_a_movement_is_invalid() {
+        assert_eq!(
+            "line 2, column 3: expected e or w after 's', found 'n'",
+            parse_input("esew\nwsnw").unwrap_err().to_string()
+        );
+        assert_eq!(
+            "line 1, column 3: unknown direction 'x'",
+            parse_input("nex").unwrap_err().to_string()
+        );
+        assert!(parse_input("nwn").is_err());
+    }
+
+    #[test]
+    fn should_apply_a_movement_to_a_coordinate() {
+        assert_eq!((-1, 0), apply_movement(Movement::West, (0, 0)));
+        assert_eq!((0, -1), apply_movement(Movement::NorthWest, (0, 0)));
+        assert_eq!((-1, 1), apply_movement(Movement::SouthWest, (0, 0)));
+        assert_eq!((1, 0), apply_movement(Movement::East, (0, 0)));
+        assert_eq!((1, -1), apply_movement(Movement::NorthEast, (0, 0)));
+        assert_eq!((0, 1), apply_movement(Movement::SouthEast, (0, 0)));
+    }
+
+    #[test]
+    fn should_find_a_coordinate_from_movement_list() {
+        assert_eq!(
+            (0, 0),
+            apply_movements(
+                &[
+                    Movement::NorthWest,
+                    Movement::West,
+                    Movement::SouthWest,
+                    Movement::East,
+                    Movement::East
+                ],
+                (0, 0)
+            )
+        )
+    }
+
+    #[test]
+    fn should_find_a_tiles_neighbours() {
+        assert_eq!(
+            HEX_DIRECTIONS.to_vec(),
+            [
+                Movement::East,
+                Movement::NorthEast,
+                Movement::NorthWest,
+                Movement::West,
+                Movement::SouthWest,
+                Movement::SouthEast
+            ]
+            .iter()
+            .map(|&movement| apply_movement(movement, (0, 0)))
+            .collect::<Vec<Point>>()
+        );
+    }
+
+    #[test]
+    fn should_flip_a_tile_if_it_is_not_flipped_and_has_2_flipped_neighbours() {
+        assert!(update_tile(
+            (0, 0),
+            &vec![(1, 0), (0, 1)].into_iter().collect()
+        ));
+    }
+
+    #[test]
+    fn should_unflip_a_tile_if_it_is_flipped_and_has_no_flipped_neighbours() {
+        assert!(!update_tile((0, 0), &vec![(0, 0)].into_iter().collect()));
+    }
+
+    #[test]
+    fn should_unflip_a_tile_if_it_is_flipped_and_has_more_than_2_flipped_neighbours() {
+        assert!(!update_tile(
+            (0, 0),
+            &vec![(0, 0), (0, 1), (1, 0), (-1, 0), (0, -1)]
+                .into_iter()
+                .collect()
+        ));
+    }
+
+    #[test]
+    fn should_not_flip_a_tile_if_it_is_not_flipped_and_does_not_have_2_flipped_neighbours() {
+        assert!(!update_tile((0, 0), &vec![(1, 0)].into_iter().collect()));
+        assert!(!update_tile(
+            (0, 0),
+            &vec![(1, 0), (0, 1), (-1, 0)].into_iter().collect()
+        ));
+    }
+
+    #[test]
+    fn should_not_unflip_a_tile_if_it_is_flipped_and_has_2_flipped_neighbours() {
+        assert!(update_tile(
+            (0, 0),
+            &vec![(0, 0), (1, 0), (0, 1)].into_iter().collect()
+        ));
+    }
+
+    #[test]
+    fn should_not_unflip_a_tile_if_it_is_flipped_and_has_1_flipped_neighbour() {
+        assert!(update_tile(
+            (0, 0),
+            &vec![(0, 0), (1, 0)].into_iter().collect()
+        ));
+    }
+
+    #[test]
+    fn should_update_the_tiles_each_day() {
+        let mut floor = lobby_floor(get_flipped_tiles(&get_test_input()));
+
+        floor.step();
+        assert_eq!(15, floor.population());
+
+        floor.step();
+        assert_eq!(12, floor.population());
+
+        floor.step();
+        assert_eq!(25, floor.population());
+    }
+
+    #[test]
+    fn day24a_test() {
+        assert_eq!(10, day24a(&get_test_input()).unwrap());
+    }
+
+    #[test]
+    fn day24b_test() {
+        assert_eq!(2208, day24b(&get_test_input()).unwrap());
+    }
+
+    #[test]
+    fn should_draw_tiles_in_staggered_rows() {
+        assert_eq!(
+            vec![" # .", ". # "],
+            draw_tiles(vec![(0, -1), (0, 0)].into_iter())
+        );
+    }
+
+    #[test]
+    fn should_trace_each_day() {
+        let mut frames = vec![];
+        Day24::trace_part1(&get_test_input(), &mut frames).unwrap();
+
+        assert_eq!(21, frames.len());
+        assert_eq!("tile 1: flipped (-3, 2) to black", frames[1].caption);
+        assert_eq!("tile 20: flipped (-1, -1) to black", frames[20].caption);
+        assert_eq!(("black", Value::Number(10)), frames[20].state[0]);
+
+        let mut frames = vec![];
+        Day24::trace_part2(&get_test_input(), &mut frames).unwrap();
+
+        assert_eq!(101, frames.len());
+        assert_eq!("day 1: 15 black tiles", frames[1].caption);
+        assert_eq!("day 100: 2208 black tiles", frames[100].caption);
+    }
+}
+
+//  A flipped tile stays flipped with 1 or 2 flipped neighbours, and any other
+//  tile flips over with exactly 2.
+fn lobby_floor(flipped_tiles: impl IntoIterator<Item = Point>) -> Automaton<Hex, BirthSurvival> {
+    Automaton::new(Hex, BirthSurvival::new(&[2], &[1, 2]), flipped_tiles)
+}
+
+fn apply_movement(movement: Movement, point: Point) -> Point {
+    match movement {
+        Movement::East => (point.0 + 1, point.1),
+        Movement::NorthEast => (point.0 + 1, point.1 - 1),
+        Movement::SouthEast => (point.0, point.1 + 1),
+        Movement::West => (point.0 - 1, point.1),
+        Movement::NorthWest => (point.0, point.1 - 1),
+        Movement::SouthWest => (point.0 - 1, point.1 + 1),
+    }
+}
+
+fn apply_movements(movements: &[Movement], point: Point) -> Point {
+    movements
+        .iter()
+        .fold(point, |acc, &movement| apply_movement(movement, acc))
+}
+
+//  Each row is shifted half a tile right of the one above, so a tile is drawn
+//  every other column with gaps between.
+fn draw_tiles(tiles: impl Iterator<Item = Point> + Clone) -> Vec<String> {
+    let columns = tiles.map(|(x, y)| (2 * x + y, y));
+    let bounds = match bounds(columns.clone()) {
+        Some(((left, top), (right, bottom))) => ((left - 1, top), (right + 1, bottom)),
+        None => return vec![],
+    };
+    let ((left, top), _) = bounds;
+
+    draw_points(columns, bounds)
+        .into_iter()
+        .enumerate()
+        .map(|(y, row)| {
+            row.char_indices()
+                .map(|(x, c)| {
+                    if (left + x as isize - top - y as isize) % 2 == 0 {
+                        c
+                    } else {
+                        ' '
+                    }
+                })
+                .collect()
+        })
+        .collect()
+}
+
+fn tiles_frame(step: usize, caption: String, flipped_tiles: &HashSet<Point>) -> Frame {
+    let mut tiles: Vec<Point> = flipped_tiles.iter().copied().collect();
+    tiles.sort_unstable_by_key(|&(x, y)| (y, x));
+
+    Frame::new(step, caption)
+        .picture(draw_tiles(tiles.iter().copied()))
+        .state("black", tiles.len())
+        .state("tiles", tiles)
+}
+
+fn get_flipped_tiles(movement_lists: &[Vec<Movement>]) -> HashSet<Point> {
+    flip_tiles(movement_lists, &mut NoTrace)
+}
+
+fn flip_tiles<T: Tracer + ?Sized>(
+    movement_lists: &[Vec<Movement>],
+    tracer: &mut T,
+) -> HashSet<Point> {
+    let mut flipped_tiles = HashSet::<Point>::new();
+    trace(tracer, || {
+        tiles_frame(0, String::from("all white"), &flipped_tiles)
+    });
+
+    for (i, movements) in movement_lists.iter().enumerate() {
+        let target = apply_movements(movements, (0, 0));
+
+        let colour = if flipped_tiles.contains(&target) {
+            flipped_tiles.remove(&target);
+            "white"
+        } else {
+            flipped_tiles.insert(target);
+            "black"
+        };
+
+        trace(tracer, || {
+            let caption = format!("tile {}: flipped {:?} to {}", i + 1, target, colour);
+            tiles_frame(i + 1, caption, &flipped_tiles)
+        });
+    }
+
+    flipped_tiles
+}
+
+fn floor_frame(floor: &Automaton<Hex, BirthSurvival>) -> Frame {
+    let caption = format!(
+        "day {}: {} black tiles",
+        floor.generation(),
+        floor.population()
+    );
+    tiles_frame(floor.generation(), caption, &floor.live().collect())
+}
+
+fn run_exhibit<T: Tracer + ?Sized>(movements: &[Vec<Movement>], tracer: &mut T) -> usize {
+    let mut floor = lobby_floor(get_flipped_tiles(movements));
+    trace(tracer, || floor_frame(&floor));
+    floor.run_watching(100, |floor| trace(tracer, || floor_frame(floor)));
+
+    floor.population()
+}
+
+//  Any error is reported relative to `input`, which `line` is a part of.
+fn parse_line(input: &str, line: &str) -> Result<Vec<Movement>> {
+    let mut chars = line.char_indices();
+    let mut movements = Vec::<Movement>::new();
+
+    while let Some((index, c)) = chars.next() {
+        let movement = match c {
+            'w' => Movement::West,
+            'e' => Movement::East,
+            's' | 'n' => match (c, chars.next()) {
+                ('s', Some((_, 'e'))) => Movement::SouthEast,
+                ('s', Some((_, 'w'))) => Movement::SouthWest,
+                ('n', Some((_, 'e'))) => Movement::NorthEast,
+                ('n', Some((_, 'w'))) => Movement::NorthWest,
+                (_, Some((next_index, next))) => {
+                    return Err(Error::parse_at(
+                        input,
+                        &line[next_index..],
+                        format!("expected e or w after '{}', found '{}'", c, next),
+                    ))
+                }
+                (_, None) => {
+                    return Err(Error::parse_at(
+                        input,
+                        &line[index..],
+                        format!("expected e or w after '{}'", c),
+                    ))
+                }
+            },
+            _ => {
+                return Err(Error::parse_at(
+                    input,
+                    &line[index..],
+                    format!("unknown direction '{}'", c),
+                ))
+            }
+        };
+
+        movements.push(movement);
+    }
+
+    Ok(movements)
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<Vec<Movement>>> {
+    lines(input).map(|line| parse_line(input, line)).collect()
+}
+
+pub fn day24a(movement_lists: &[Vec<Movement>]) -> Result<usize> {
+    Ok(get_flipped_tiles(movement_lists).len())
+}
+
+pub fn day24b(movements: &[Vec<Movement>]) -> Result<usize> {
+    Ok(run_exhibit(movements, &mut NoTrace))
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input = Vec<Vec<Movement>>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<Vec<Movement>>> {
+        parse_input(input)
+    }
+
+    fn part1(movements: &Vec<Vec<Movement>>) -> Result<usize> {
+        day24a(movements)
+    }
+
+    fn part2(movements: &Vec<Vec<Movement>>) -> Result<usize> {
+        day24b(movements)
+    }
+}
+
+impl Traced for Day24 {
+    fn trace_part1(movements: &Vec<Vec<Movement>>, tracer: &mut dyn Tracer) -> Result<usize> {
+        Ok(flip_tiles(movements, tracer).len())
+    }
+
+    fn trace_part2(movements: &Vec<Vec<Movement>>, tracer: &mut dyn Tracer) -> Result<usize> {
+        Ok(run_exhibit(movements, tracer))
+    }
+}
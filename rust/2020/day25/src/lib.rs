@@ -0,0 +1,95 @@
+use aoc_common::input::numbers;
+use aoc_common::numtheory::{mod_pow, pohlig_hellman};
+use aoc_common::{Error, Result, Solution};
+use std::convert::Infallible;
+
+static SUBJECT_NUMBER: usize = 7;
+static MODULUS_NUMBER: usize = 20201227;
+
+#[cfg(test)]
+mod day25_tests {
+    use super::*;
+
+    #[test]
+    fn should_calculate_the_loop_size() {
+        assert_eq!(Some(8), calculate_loop_size(5764801));
+        assert_eq!(Some(11), calculate_loop_size(17807724));
+    }
+
+    #[test]
+    fn day25a_test() {
+        assert_eq!(14897079, day25a(&[5764801, 17807724]).unwrap())
+    }
+
+    #[test]
+    fn should_reject_keys_outside_the_modulus() {
+        assert!(parse_input("5764801\n20201227").is_err());
+        assert!(parse_input("5764801").is_err());
+    }
+}
+
+fn apply_loop(loop_size: usize, subject_number: usize) -> usize {
+    mod_pow(
+        subject_number as u64,
+        loop_size as u64,
+        MODULUS_NUMBER as u64,
+    ) as usize
+}
+
+//  The modulus is prime, so the handshake's discrete log can be split up.
+fn calculate_loop_size(input: usize) -> Option<usize> {
+    pohlig_hellman(SUBJECT_NUMBER as u64, input as u64, MODULUS_NUMBER as u64)
+        .map(|loop_size| loop_size as usize)
+}
+
+pub fn parse_input(input: &str) -> Result<[usize; 2]> {
+    let public_keys = numbers::<usize>(input)?;
+
+    if public_keys.len() != 2 {
+        return Err(Error::invalid_shape(format!(
+            "expected 2 public keys, found {}",
+            public_keys.len()
+        )));
+    }
+
+    //  Anything outside of this range can never be reached by the
+    //  handshake.
+    if let Some(key) = public_keys.iter().find(|&&k| k == 0 || k >= MODULUS_NUMBER) {
+        return Err(Error::no_solution(format!(
+            "{} is not a valid public key",
+            key
+        )));
+    }
+
+    Ok([public_keys[0], public_keys[1]])
+}
+
+pub fn day25a(input: &[usize; 2]) -> Result<usize> {
+    let loop_size = calculate_loop_size(input[0]).ok_or_else(|| {
+        Error::no_solution(format!("{} is not a power of {}", input[0], SUBJECT_NUMBER))
+    })?;
+
+    Ok(apply_loop(loop_size, input[1]))
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input = [usize; 2];
+    type Part1 = usize;
+    type Part2 = Infallible;
+
+    const HAS_PART2: bool = false;
+
+    fn parse(input: &str) -> Result<[usize; 2]> {
+        parse_input(input)
+    }
+
+    fn part1(public_keys: &[usize; 2]) -> Result<usize> {
+        day25a(public_keys)
+    }
+
+    fn part2(_: &[usize; 2]) -> Result<Infallible> {
+        Err(Error::no_solution("day 25 only has one part"))
+    }
+}
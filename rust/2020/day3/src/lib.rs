@@ -0,0 +1,257 @@
+use aoc_common::grid::Grid;
+use aoc_common::{Error, Result, Solution};
+use std::ops::RangeInclusive;
+
+type Map = Grid<bool>;
+
+//  How far the toboggan moves on each step, right (or left, when negative)
+//  and then down.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Slope {
+    pub right: isize,
+    pub down: usize,
+}
+
+//  The slopes checked in part two.
+pub const PUZZLE_SLOPES: [Slope; 5] = [
+    Slope { right: 1, down: 1 },
+    Slope { right: 3, down: 1 },
+    Slope { right: 5, down: 1 },
+    Slope { right: 7, down: 1 },
+    Slope { right: 1, down: 2 },
+];
+
+#[cfg(test)]
+mod day3_tests {
+    use super::*;
+
+    static TEST_INPUT: &str = "
+..##.......
+#...#...#..
+.#....#..#.
+..#.#...#.#
+.#...##..#.
+..#.##.....
+.#.#.#....#
+.#........#
+#.##...#...
+#...##....#
+.#..#...#.#";
+
+    #[test]
+    fn should_return_true_if_on_a_tree() {
+        assert_eq!(true, is_on_tree(&parse_input(TEST_INPUT).unwrap(), 2, 3, 1));
+    }
+
+    #[test]
+    fn should_return_false_if_not_on_a_tree() {
+        assert_eq!(
+            false,
+            is_on_tree(&parse_input(TEST_INPUT).unwrap(), 1, 3, 1)
+        );
+    }
+
+    #[test]
+    fn should_return_true_if_on_a_tree_past_the_end_of_the_line() {
+        assert_eq!(true, is_on_tree(&parse_input(TEST_INPUT).unwrap(), 7, 3, 1));
+    }
+
+    #[test]
+    fn should_return_false_if_not_on_a_tree_past_the_end_of_the_line() {
+        assert_eq!(
+            false,
+            is_on_tree(&parse_input(TEST_INPUT).unwrap(), 6, 3, 1)
+        );
+    }
+
+    #[test]
+    fn should_count_trees_on_with_move_1_1() {
+        let map = parse_input(TEST_INPUT).unwrap();
+        assert_eq!(2, count_trees(&map, 1, 1));
+    }
+
+    #[test]
+    fn should_count_trees_on_with_move_1_3() {
+        let map = parse_input(TEST_INPUT).unwrap();
+        assert_eq!(7, count_trees(&map, 1, 3));
+    }
+
+    #[test]
+    fn should_count_trees_on_with_move_1_5() {
+        let map = parse_input(TEST_INPUT).unwrap();
+        assert_eq!(3, count_trees(&map, 1, 5));
+    }
+
+    #[test]
+    fn should_count_trees_on_with_move_1_7() {
+        let map = parse_input(TEST_INPUT).unwrap();
+        assert_eq!(4, count_trees(&map, 1, 7));
+    }
+
+    #[test]
+    fn should_count_trees_on_with_move_2_1() {
+        let map = parse_input(TEST_INPUT).unwrap();
+        assert_eq!(2, count_trees(&map, 2, 1));
+    }
+
+    #[test]
+    fn should_count_trees_on_every_slope_at_once() {
+        let map = parse_input(TEST_INPUT).unwrap();
+        assert_eq!(
+            vec![2, 7, 3, 4, 2],
+            count_trees_on_slopes(&map, &PUZZLE_SLOPES).unwrap()
+        );
+    }
+
+    #[test]
+    fn should_count_trees_going_left() {
+        let map = parse_input(TEST_INPUT).unwrap();
+        let width = map.width() as isize;
+
+        for &slope in &PUZZLE_SLOPES {
+            let left = Slope {
+                right: slope.right - width,
+                ..slope
+            };
+            assert_eq!(
+                count_trees_on_slopes(&map, &[slope]).unwrap(),
+                count_trees_on_slopes(&map, &[left]).unwrap()
+            );
+        }
+        assert_eq!(
+            vec![5],
+            count_trees_on_slopes(&map, &[Slope { right: -1, down: 1 }]).unwrap()
+        );
+    }
+
+    #[test]
+    fn should_reject_a_slope_that_never_goes_down() {
+        let map = parse_input(TEST_INPUT).unwrap();
+        assert!(count_trees_on_slopes(&map, &[Slope { right: 3, down: 0 }]).is_err());
+    }
+
+    #[test]
+    fn should_find_the_slope_with_the_fewest_trees() {
+        let map = parse_input(TEST_INPUT).unwrap();
+        assert_eq!(
+            (Slope { right: 5, down: 2 }, 0),
+            fewest_trees(&map, 1..=7, 1..=2).unwrap()
+        );
+        assert_eq!(
+            (Slope { right: 2, down: 1 }, 1),
+            fewest_trees(&map, 1..=7, 1..=1).unwrap()
+        );
+        let (first, last) = (3, 1);
+        assert!(fewest_trees(&map, first..=last, 1..=2).is_err());
+        assert!(fewest_trees(&map, 1..=3, 0..=2).is_err());
+    }
+
+    #[test]
+    fn day3a_test() {
+        assert_eq!(7, day3a(&parse_input(TEST_INPUT).unwrap()).unwrap());
+    }
+
+    #[test]
+    fn day3b_test() {
+        assert_eq!(336, day3b(&parse_input(TEST_INPUT).unwrap()).unwrap());
+    }
+}
+
+//  Whether the toboggan hits a tree on its nth step down the slope.
+fn is_on_tree(map: &Map, step: usize, h_stride: isize, v_stride: usize) -> bool {
+    step > 0 && *map.get_wrapping(step as isize * h_stride, (step * v_stride) as isize)
+}
+
+//  Counts for every slope in one pass down the map, each slope looking at a
+//  row only when it lands on it.
+fn tree_counts(map: &Map, slopes: &[Slope]) -> Vec<usize> {
+    let mut counts = vec![0; slopes.len()];
+
+    for y in 1..map.height() {
+        for (count, slope) in counts.iter_mut().zip(slopes) {
+            if y % slope.down == 0 && is_on_tree(map, y / slope.down, slope.right, slope.down) {
+                *count += 1;
+            }
+        }
+    }
+
+    counts
+}
+
+fn count_trees(map: &Map, v_stride: usize, h_stride: isize) -> usize {
+    tree_counts(
+        map,
+        &[Slope {
+            right: h_stride,
+            down: v_stride,
+        }],
+    )[0]
+}
+
+pub fn count_trees_on_slopes(map: &Map, slopes: &[Slope]) -> Result<Vec<usize>> {
+    if slopes.iter().any(|slope| slope.down == 0) {
+        return Err(Error::invalid_shape(
+            "a slope has to go down to reach the bottom",
+        ));
+    }
+
+    Ok(tree_counts(map, slopes))
+}
+
+//  Tries every slope in the ranges, and picks the one that hits the fewest
+//  trees, preferring the earliest by how far down and then how far right it
+//  goes. Any slope that goes down the whole map in one step hits none.
+pub fn fewest_trees(
+    map: &Map,
+    rights: RangeInclusive<isize>,
+    downs: RangeInclusive<usize>,
+) -> Result<(Slope, usize)> {
+    let slopes: Vec<Slope> = downs
+        .flat_map(|down| rights.clone().map(move |right| Slope { right, down }))
+        .collect();
+
+    count_trees_on_slopes(map, &slopes)?
+        .into_iter()
+        .zip(slopes)
+        .min_by_key(|&(count, Slope { right, down })| (count, down, right))
+        .map(|(count, slope)| (slope, count))
+        .ok_or_else(|| Error::no_solution("there are no slopes to try"))
+}
+
+pub fn parse_input(input: &str) -> Result<Map> {
+    Grid::parse(input, |c| match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })
+}
+
+pub fn day3a(map: &Map) -> Result<usize> {
+    Ok(count_trees(map, 1, 3))
+}
+
+pub fn day3b(map: &Map) -> Result<usize> {
+    Ok(count_trees_on_slopes(map, &PUZZLE_SLOPES)?
+        .into_iter()
+        .product())
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Map;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Map> {
+        parse_input(input)
+    }
+
+    fn part1(map: &Map) -> Result<usize> {
+        day3a(map)
+    }
+
+    fn part2(map: &Map) -> Result<usize> {
+        day3b(map)
+    }
+}
@@ -1,8 +1,6 @@
+use aoc_common::input::records;
+use aoc_common::{Result, Solution};
 use std::collections::HashMap;
-use std::fs::File;
-use std::io::BufReader;
-use std::io::Read;
-use std::io::Result;
 
 #[allow(dead_code)]
 struct Passport {
@@ -15,7 +13,9 @@ struct Passport {
     passport_id: Option<String>,
     country_id: Option<u32>,
 }
-struct StringPassport {
+
+#[derive(Clone)]
+pub struct StringPassport {
     birth_year: Option<String>,
     issue_year: Option<String>,
     expiration_year: Option<String>,
@@ -29,6 +29,7 @@ struct StringPassport {
 #[cfg(test)]
 mod day4_tests {
     use super::*;
+    use aoc_common::input::key_values;
 
     static TEST_INPUT: &str = "
 ecl:gry pid:860033327 eyr:2020 hcl:#fffffd
@@ -183,7 +184,7 @@ iyr:2010 hgt:158cm hcl:#b6652a ecl:blu byr:1944 eyr:2021 pid:093154719";
 
     #[test]
     fn should_convert_a_line_input_into_a_property_map() {
-        let map = line_to_property_map(String::from(FIRST_LINE));
+        let map = key_values(FIRST_LINE).unwrap();
         assert_eq!(true, map.contains_key("ecl"));
         assert_eq!(true, map.contains_key("pid"));
         assert_eq!(true, map.contains_key("eyr"));
@@ -195,28 +196,28 @@ iyr:2010 hgt:158cm hcl:#b6652a ecl:blu byr:1944 eyr:2021 pid:093154719";
     }
 
     #[test]
-    fn should_convert_a_file_to_lines() {
-        let lines = file_to_lines(TEST_INPUT);
-        assert_eq!(4, lines.len());
-        assert_eq!(FIRST_LINE, lines[0]);
+    fn should_convert_a_file_to_property_maps() {
+        let maps = records(TEST_INPUT).unwrap();
+        assert_eq!(4, maps.len());
+        assert_eq!(key_values(FIRST_LINE).unwrap(), maps[0]);
     }
 
     #[test]
     fn should_convert_a_property_map_to_a_passport() {
-        let map: HashMap<String, String> = [
-            (String::from("ecl"), String::from("gry")),
-            (String::from("pid"), String::from("860033327")),
-            (String::from("eyr"), String::from("2020")),
-            (String::from("hcl"), String::from("#fffffd")),
-            (String::from("byr"), String::from("1937")),
-            (String::from("iyr"), String::from("2017")),
-            (String::from("cid"), String::from("147")),
-            (String::from("hgt"), String::from("183cm")),
+        let map: HashMap<&str, &str> = [
+            ("ecl", "gry"),
+            ("pid", "860033327"),
+            ("eyr", "2020"),
+            ("hcl", "#fffffd"),
+            ("byr", "1937"),
+            ("iyr", "2017"),
+            ("cid", "147"),
+            ("hgt", "183cm"),
         ]
         .iter()
         .cloned()
         .collect();
-        let passport = map_to_passport(map);
+        let passport = map_to_passport(&map);
 
         assert_eq!(Some(1937), passport.birth_year);
         assert_eq!(Some(2017), passport.issue_year);
@@ -357,12 +358,18 @@ iyr:2010 hgt:158cm hcl:#b6652a ecl:blu byr:1944 eyr:2021 pid:093154719";
 
     #[test]
     fn day4a_tests() {
-        assert_eq!(2, day4a(TEST_INPUT))
+        assert_eq!(
+            2,
+            day4a(&file_to_string_passport(TEST_INPUT).unwrap()).unwrap()
+        )
     }
 
     #[test]
     fn day4b_tests() {
-        assert_eq!(4, day4b(TEST2_INPUT))
+        assert_eq!(
+            4,
+            day4b(&file_to_string_passport(TEST2_INPUT).unwrap()).unwrap()
+        )
     }
 }
 
@@ -410,7 +417,7 @@ fn hair_colour_is_valid(hair_colour: &Option<String>) -> bool {
                 .chars()
                 .enumerate()
                 .filter(|&(i, c)| i > 0 || c == '#')
-                .filter(|&(i, c)| i == 0 || ((c >= '0' && c <= '9') || (c >= 'a' && c <= 'f')))
+                .filter(|&(i, c)| i == 0 || (c.is_ascii_digit() || ('a'..='f').contains(&c)))
                 .map(|(_, c)| c)
                 .collect();
             filtered.len() == 7
@@ -420,90 +427,51 @@ fn hair_colour_is_valid(hair_colour: &Option<String>) -> bool {
 }
 
 fn eye_colour_is_valid(eye_colour: &Option<String>) -> bool {
-    let valid = vec!["amb", "blu", "brn", "gry", "grn", "hzl", "oth"];
+    let valid = ["amb", "blu", "brn", "gry", "grn", "hzl", "oth"];
     match eye_colour {
         Some(s) => valid.contains(&s.as_str()),
         None => false,
     }
 }
 
-fn file_to_string_passport(file_input: &str) -> Vec<StringPassport> {
-    file_to_lines(file_input)
-        .iter()
-        .map(|line| line_to_property_map(String::from(line)))
-        .map(|map| map_to_string_passport(map))
-        .collect()
-}
-
-fn file_to_passport(file_input: &str) -> Vec<Passport> {
-    file_to_lines(file_input)
+pub fn file_to_string_passport(file_input: &str) -> Result<Vec<StringPassport>> {
+    Ok(records(file_input)?
         .iter()
-        .map(|line| line_to_property_map(String::from(line)))
-        .map(|map| map_to_passport(map))
-        .collect()
+        .map(map_to_string_passport)
+        .collect())
 }
 
-fn map_to_string_passport(map: HashMap<String, String>) -> StringPassport {
+fn map_to_string_passport(map: &HashMap<&str, &str>) -> StringPassport {
     StringPassport {
-        birth_year: map.get("byr").and_then(|s| Some(String::from(s))),
-        issue_year: map.get("iyr").and_then(|s| Some(String::from(s))),
-        expiration_year: map.get("eyr").and_then(|s| Some(String::from(s))),
-        height: map.get("hgt").and_then(|s| Some(String::from(s))),
-        hair_colour: map.get("hcl").and_then(|s| Some(String::from(s))),
-        eye_colour: map.get("ecl").and_then(|s| Some(String::from(s))),
-        passport_id: map.get("pid").and_then(|s| Some(String::from(s))),
-        country_id: map.get("cid").and_then(|s| Some(String::from(s))),
+        birth_year: map.get("byr").map(|s| s.to_string()),
+        issue_year: map.get("iyr").map(|s| s.to_string()),
+        expiration_year: map.get("eyr").map(|s| s.to_string()),
+        height: map.get("hgt").map(|s| s.to_string()),
+        hair_colour: map.get("hcl").map(|s| s.to_string()),
+        eye_colour: map.get("ecl").map(|s| s.to_string()),
+        passport_id: map.get("pid").map(|s| s.to_string()),
+        country_id: map.get("cid").map(|s| s.to_string()),
     }
 }
 
 fn string_passport_to_passport(input: StringPassport) -> Passport {
     Passport {
-        birth_year: input.birth_year.and_then(|s| match s.parse::<u16>() {
-            Ok(n) => Some(n),
-            Err(_) => None,
-        }),
-        issue_year: input.issue_year.and_then(|s| match s.parse::<u16>() {
-            Ok(n) => Some(n),
-            Err(_) => None,
-        }),
-        expiration_year: input.expiration_year.and_then(|s| match s.parse::<u16>() {
-            Ok(n) => Some(n),
-            Err(_) => None,
-        }),
+        birth_year: input.birth_year.and_then(|s| s.parse::<u16>().ok()),
+        issue_year: input.issue_year.and_then(|s| s.parse::<u16>().ok()),
+        expiration_year: input.expiration_year.and_then(|s| s.parse::<u16>().ok()),
         height: input.height,
         hair_colour: input.hair_colour,
         eye_colour: input.eye_colour,
         passport_id: input.passport_id,
-        country_id: input.country_id.and_then(|s| match s.parse::<u32>() {
-            Ok(n) => Some(n),
-            Err(_) => None,
-        }),
+        country_id: input.country_id.and_then(|s| s.parse::<u32>().ok()),
     }
 }
 
-fn map_to_passport(map: HashMap<String, String>) -> Passport {
+#[allow(dead_code)]
+fn map_to_passport(map: &HashMap<&str, &str>) -> Passport {
     string_passport_to_passport(map_to_string_passport(map))
 }
 
-fn file_to_lines(file_input: &str) -> Vec<String> {
-    let lines: Vec<String> = file_input
-        .trim()
-        .split("\n\n")
-        .map(|line| line.replace("\n", " "))
-        .collect();
-    println!("{}", lines.iter().count());
-    lines
-}
-
-fn line_to_property_map(line: String) -> HashMap<String, String> {
-    line.split(" ")
-        .map(|pair| {
-            let parts: Vec<&str> = pair.split(":").collect();
-            (String::from(parts[0]), String::from(parts[1]))
-        })
-        .collect()
-}
-
 fn is_string_passport_valid(passport: &StringPassport) -> bool {
     passport.birth_year.is_some()
         && passport.issue_year.is_some()
@@ -524,37 +492,38 @@ fn is_passport_valid(passport: &Passport) -> bool {
         && passport_id_is_valid(&passport.passport_id)
 }
 
-fn read_file(path: &str) -> Result<String> {
-    let file = File::open(path)?;
-    let mut buf_reader = BufReader::new(file);
-    let mut input = String::new();
-    buf_reader.read_to_string(&mut input)?;
-
-    Ok(input)
-}
-
-fn day4a(input: &str) -> usize {
-    file_to_string_passport(input)
+pub fn day4a(passports: &[StringPassport]) -> Result<usize> {
+    Ok(passports
         .iter()
         .filter(|&passport| is_string_passport_valid(passport))
-        .count()
+        .count())
 }
 
-fn day4b(input: &str) -> usize {
-    file_to_passport(input)
+pub fn day4b(passports: &[StringPassport]) -> Result<usize> {
+    Ok(passports
         .iter()
-        .filter(|&passport| is_passport_valid(passport))
-        .count()
+        .cloned()
+        .map(string_passport_to_passport)
+        .filter(is_passport_valid)
+        .count())
 }
 
-fn main() -> Result<()> {
-    let input = read_file("input")?;
+pub struct Day4;
 
-    let result = day4a(input.as_str());
-    println!("Day 4A - {}", result);
+impl Solution for Day4 {
+    type Input = Vec<StringPassport>;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    let result = day4b(input.as_str());
-    println!("Day 4B - {}", result);
+    fn parse(input: &str) -> Result<Vec<StringPassport>> {
+        file_to_string_passport(input)
+    }
 
-    Ok(())
+    fn part1(passports: &Vec<StringPassport>) -> Result<usize> {
+        day4a(passports)
+    }
+
+    fn part2(passports: &Vec<StringPassport>) -> Result<usize> {
+        day4b(passports)
+    }
 }
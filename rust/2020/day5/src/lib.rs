@@ -0,0 +1,160 @@
+use aoc_common::input::lines;
+use aoc_common::{Error, Result, Solution};
+
+#[cfg(test)]
+mod day5_tests {
+    use super::*;
+
+    static TEST_INPUT: &str = "
+BFFFBBFRRR
+FFFBBBFRRR
+BBFFBBFRLL";
+    static TEST2_INPUT: &str = "
+FFFFFFFLLL
+FFFFFFFLLR
+FFFFFFFLRL
+FFFFFFFLRR
+FFFFFFFRLR
+FFFFFFFRRL
+FFFFFFFRRR
+FFFFFFBLLL";
+
+    #[test]
+    fn should_parse_a_row_number() {
+        assert_eq!(70, parse_row_number("BFFFBBF"));
+        assert_eq!(14, parse_row_number("FFFBBBF"));
+        assert_eq!(102, parse_row_number("BBFFBBF"));
+    }
+
+    #[test]
+    fn should_parse_a_seat_number() {
+        assert_eq!(7, parse_seat_number("RRR"));
+        assert_eq!(5, parse_seat_number("RLR"));
+        assert_eq!(4, parse_seat_number("RLL"));
+        assert_eq!(0, parse_seat_number("LLL"));
+    }
+
+    #[test]
+    fn should_parse_a_ticket_to_a_seat_id() {
+        assert_eq!(567, parse_ticket("BFFFBBFRRR"));
+        assert_eq!(119, parse_ticket("FFFBBBFRRR"));
+        assert_eq!(820, parse_ticket("BBFFBBFRLL"));
+    }
+
+    #[test]
+    fn day5a_tests() {
+        assert_eq!(820, day5a(&parse_input(TEST_INPUT).unwrap()).unwrap());
+    }
+
+    #[test]
+    fn day5b_tests() {
+        assert_eq!(4, day5b(&parse_input(TEST2_INPUT).unwrap()).unwrap());
+    }
+
+    #[test]
+    fn should_reject_malformed_boarding_passes() {
+        assert_eq!(
+            "line 2, column 1: 'BFFFBBFRR' is not a boarding pass",
+            parse_input("FFFBBBFRRR\nBFFFBBFRR")
+                .unwrap_err()
+                .to_string()
+        );
+        assert!(parse_input("BFFFBBFRRX").is_err());
+        assert!(parse_input("BFFFB\u{1f300}R").is_err());
+    }
+
+    #[test]
+    fn should_fail_when_there_is_no_empty_seat() {
+        assert!(day5b(&parse_input("FFFFFFFLLL\nFFFFFFFLLR").unwrap()).is_err());
+    }
+}
+
+fn parse_row_number(input: &str) -> usize {
+    input
+        .chars()
+        .map(|c| match c {
+            'B' => 1,
+            _ => 0,
+        })
+        .fold(0, |acc, b| acc * 2 + b)
+}
+
+fn parse_seat_number(input: &str) -> usize {
+    input
+        .chars()
+        .map(|c| match c {
+            'R' => 1,
+            _ => 0,
+        })
+        .fold(0, |acc, b| acc * 2 + b)
+}
+
+fn is_valid_ticket(input: &str) -> bool {
+    let chars: Vec<char> = input.chars().collect();
+
+    chars.len() == 10
+        && chars[..7].iter().all(|&c| c == 'F' || c == 'B')
+        && chars[7..].iter().all(|&c| c == 'L' || c == 'R')
+}
+
+fn parse_ticket(input: &str) -> usize {
+    let (row, seat) = input.split_at(7);
+
+    (parse_row_number(row) * 8) + parse_seat_number(seat)
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<usize>> {
+    lines(input)
+        .map(|line| {
+            if is_valid_ticket(line) {
+                Ok(parse_ticket(line))
+            } else {
+                Err(Error::parse_at(
+                    input,
+                    line,
+                    format!("'{}' is not a boarding pass", line),
+                ))
+            }
+        })
+        .collect()
+}
+
+pub fn day5a(seat_ids: &[usize]) -> Result<usize> {
+    seat_ids
+        .iter()
+        .copied()
+        .max()
+        .ok_or_else(|| Error::no_solution("there are no boarding passes"))
+}
+
+pub fn day5b(seat_ids: &[usize]) -> Result<usize> {
+    let mut seat_ids = seat_ids.to_vec();
+    seat_ids.sort();
+
+    //  The only gap between two occupied seats is ours
+    seat_ids
+        .windows(2)
+        .find(|pair| pair[1] != pair[0] + 1)
+        .map(|pair| pair[0] + 1)
+        .ok_or_else(|| Error::no_solution("there is no empty seat between two taken seats"))
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = Vec<usize>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<usize>> {
+        parse_input(input)
+    }
+
+    fn part1(seat_ids: &Vec<usize>) -> Result<usize> {
+        day5a(seat_ids)
+    }
+
+    fn part2(seat_ids: &Vec<usize>) -> Result<usize> {
+        day5b(seat_ids)
+    }
+}
@@ -1,8 +1,6 @@
+use aoc_common::input::{groups, lines};
+use aoc_common::{Result, Solution};
 use std::collections::HashSet;
-use std::fs::File;
-use std::io::BufReader;
-use std::io::Read;
-use std::io::Result;
 
 #[cfg(test)]
 mod day6_tests {
@@ -61,32 +59,27 @@ b";
 
     #[test]
     fn day6a_tests() {
-        assert_eq!(11, day6a(TEST_INPUT));
+        assert_eq!(11, day6a(&parse_input(TEST_INPUT)).unwrap());
     }
 
     #[test]
     fn day6b_tests() {
-        assert_eq!(6, day6b(TEST_INPUT));
+        assert_eq!(6, day6b(&parse_input(TEST_INPUT)).unwrap());
     }
 }
 
 fn count_yes_answers(input: &str) -> usize {
-    let answers: HashSet<char> = input
-        .split("\n")
-        .flat_map(|line| line.trim().chars())
-        .collect();
+    let answers: HashSet<char> = lines(input).flat_map(|line| line.trim().chars()).collect();
 
     answers.len()
 }
 
 fn count_agreed_yes_answers(input: &str) -> usize {
-    let grouped_answers: Vec<HashSet<char>> = input
-        .split("\n")
-        .filter(|&line| line != "")
+    let grouped_answers: Vec<HashSet<char>> = lines(input)
         .map(|line| line.trim().chars().collect())
         .collect();
 
-    if grouped_answers.len() == 0 {
+    if grouped_answers.is_empty() {
         return 0;
     } else if grouped_answers.len() == 1 {
         return grouped_answers[0].len();
@@ -100,37 +93,37 @@ fn count_agreed_yes_answers(input: &str) -> usize {
         .count()
 }
 
-fn read_file(path: &str) -> Result<String> {
-    let file = File::open(path)?;
-    let mut buf_reader = BufReader::new(file);
-    let mut input = String::new();
-    buf_reader.read_to_string(&mut input)?;
-
-    Ok(input)
+pub fn parse_input(input: &str) -> Vec<String> {
+    groups(input).into_iter().map(String::from).collect()
 }
 
-fn day6a(input: &str) -> usize {
-    input
-        .split("\n\n")
-        .map(|line| count_yes_answers(line))
-        .fold(0, |total, yes_answers| total + yes_answers)
+pub fn day6a(groups: &[String]) -> Result<usize> {
+    Ok(groups.iter().map(|group| count_yes_answers(group)).sum())
 }
 
-fn day6b(input: &str) -> usize {
-    input
-        .split("\n\n")
-        .map(|line| count_agreed_yes_answers(line))
-        .fold(0, |total, yes_answers| total + yes_answers)
+pub fn day6b(groups: &[String]) -> Result<usize> {
+    Ok(groups
+        .iter()
+        .map(|group| count_agreed_yes_answers(group))
+        .sum())
 }
 
-fn main() -> Result<()> {
-    let input = read_file("input")?;
+pub struct Day6;
 
-    let result = day6a(input.as_str());
-    println!("Day 6A - {}", result);
+impl Solution for Day6 {
+    type Input = Vec<String>;
+    type Part1 = usize;
+    type Part2 = usize;
 
-    let result = day6b(input.as_str());
-    println!("Day 6B - {}", result);
+    fn parse(input: &str) -> Result<Vec<String>> {
+        Ok(parse_input(input))
+    }
 
-    Ok(())
+    fn part1(groups: &Vec<String>) -> Result<usize> {
+        day6a(groups)
+    }
+
+    fn part2(groups: &Vec<String>) -> Result<usize> {
+        day6b(groups)
+    }
 }
@@ -1,8 +1,9 @@
+use aoc_common::input::lines;
+use aoc_common::{Error, Result, Solution};
 use std::collections::HashMap;
-use std::fs::File;
-use std::io::{BufReader, Read, Result};
 
-struct BagCount {
+#[derive(Debug)]
+pub struct BagCount {
     count: u32,
     colour: String,
 }
@@ -75,22 +76,38 @@ dark violet bags contain no other bags.";
 
     #[test]
     fn day7a_tests() {
-        assert_eq!(4, day7a(TEST_INPUT));
+        assert_eq!(4, day7a(&create_bag_map(TEST_INPUT).unwrap()).unwrap());
     }
 
     #[test]
     fn day7b_tests() {
-        assert_eq!(32, day7b(TEST_INPUT));
-        assert_eq!(126, day7b(TEST2_INPUT));
+        assert_eq!(32, day7b(&create_bag_map(TEST_INPUT).unwrap()).unwrap());
+        assert_eq!(126, day7b(&create_bag_map(TEST2_INPUT).unwrap()).unwrap());
+    }
+
+    #[test]
+    fn should_reject_malformed_rules() {
+        assert_eq!(
+            "line 2, column 1: invalid bag rule 'bright white bags hold 1 shiny gold bag.'",
+            create_bag_map(
+                "faded blue bags contain no other bags.\nbright white bags hold 1 shiny gold bag."
+            )
+            .unwrap_err()
+            .to_string()
+        );
+        assert!(create_bag_map("bright white bags contain 1 shiny gold bag.").is_err());
     }
 }
 
 fn parse_bag_definition(input: &str) -> Option<Bag> {
     let parts: Vec<&str> = input.trim_end_matches(".").split("contain").collect();
+    if parts.len() != 2 {
+        return None;
+    }
+
     let colour = parts[0].trim();
 
     if !colour.ends_with("bags") {
-        println!("Not a bag");
         return None;
     }
 
@@ -100,13 +117,12 @@ fn parse_bag_definition(input: &str) -> Option<Bag> {
         _ => bags
             .split(",")
             .map(|part| parse_bag_count(part.trim()))
-            .filter_map(|x| x)
-            .collect(),
+            .collect::<Option<Vec<BagCount>>>()?,
     };
 
     Some(Bag {
         colour: String::from(colour.trim_end_matches("bags").trim()),
-        holds: holds,
+        holds,
     })
 }
 
@@ -116,6 +132,10 @@ fn parse_bag_count(input: &str) -> Option<BagCount> {
     }
 
     let parts = input.split(" ").collect::<Vec<&str>>();
+    if parts.len() < 3 {
+        return None;
+    }
+
     let (count_parts, colour_parts) = parts.split_at(1);
     let bag_index = colour_parts.len() - 1;
 
@@ -128,15 +148,6 @@ fn parse_bag_count(input: &str) -> Option<BagCount> {
     }
 }
 
-fn read_file(path: &str) -> Result<String> {
-    let file = File::open(path)?;
-    let mut buf_reader = BufReader::new(file);
-    let mut input = String::new();
-    buf_reader.read_to_string(&mut input)?;
-
-    Ok(input)
-}
-
 fn can_hold_shiny_gold(
     colour: &String,
     map: &HashMap<String, Vec<BagCount>>,
@@ -148,22 +159,35 @@ fn can_hold_shiny_gold(
 
     seen.insert(colour.clone(), false);
 
-    let can_hold = map[colour].iter().any(|held| {
-        held.colour == String::from("shiny gold") || can_hold_shiny_gold(&held.colour, map, seen)
-    });
+    let can_hold = map[colour]
+        .iter()
+        .any(|held| held.colour == "shiny gold" || can_hold_shiny_gold(&held.colour, map, seen));
 
     seen.insert(colour.clone(), can_hold);
     can_hold
 }
 
-fn create_bag_map(input: &str) -> HashMap<String, Vec<BagCount>> {
-    input
-        .split("\n")
-        .filter(|&line| line != "")
-        .map(|line| parse_bag_definition(line))
-        .filter_map(|x| x)
-        .map(|bag| (bag.colour, bag.holds))
-        .collect()
+pub fn create_bag_map(input: &str) -> Result<HashMap<String, Vec<BagCount>>> {
+    let bag_map = lines(input)
+        .map(|line| {
+            parse_bag_definition(line)
+                .map(|bag| (bag.colour, bag.holds))
+                .ok_or_else(|| Error::parse_at(input, line, format!("invalid bag rule '{}'", line)))
+        })
+        .collect::<Result<HashMap<String, Vec<BagCount>>>>()?;
+
+    if let Some(held) = bag_map
+        .values()
+        .flatten()
+        .find(|held| !bag_map.contains_key(&held.colour))
+    {
+        return Err(Error::invalid_shape(format!(
+            "there is no rule for {} bags",
+            held.colour
+        )));
+    }
+
+    Ok(bag_map)
 }
 
 fn count_required_bags_to_fill(
@@ -184,31 +208,43 @@ fn count_required_bags_to_fill(
     required_count
 }
 
-fn day7a(input: &str) -> usize {
-    let bag_map = create_bag_map(input);
-
+pub fn day7a(bag_map: &HashMap<String, Vec<BagCount>>) -> Result<usize> {
     let mut seen = HashMap::<String, bool>::new();
-    bag_map
+    Ok(bag_map
         .iter()
-        .filter(|(colour, _)| can_hold_shiny_gold(colour.clone(), &bag_map, &mut seen))
-        .count()
+        .filter(|(colour, _)| can_hold_shiny_gold(colour, bag_map, &mut seen))
+        .count())
 }
 
-fn day7b(input: &str) -> u32 {
-    let bag_map = create_bag_map(input);
+pub fn day7b(bag_map: &HashMap<String, Vec<BagCount>>) -> Result<u32> {
+    if !bag_map.contains_key("shiny gold") {
+        return Err(Error::no_solution("there is no rule for shiny gold bags"));
+    }
 
     let mut seen = HashMap::<String, u32>::new();
-    count_required_bags_to_fill(&String::from("shiny gold"), &bag_map, &mut seen)
+    Ok(count_required_bags_to_fill(
+        &String::from("shiny gold"),
+        bag_map,
+        &mut seen,
+    ))
 }
 
-fn main() -> Result<()> {
-    let input = read_file("input")?;
+pub struct Day7;
 
-    let result = day7a(input.as_str());
-    println!("Day 7A - {}", result);
+impl Solution for Day7 {
+    type Input = HashMap<String, Vec<BagCount>>;
+    type Part1 = usize;
+    type Part2 = u32;
 
-    let result = day7b(input.as_str());
-    println!("Day 7B - {}", result);
+    fn parse(input: &str) -> Result<HashMap<String, Vec<BagCount>>> {
+        create_bag_map(input)
+    }
 
-    Ok(())
+    fn part1(bag_map: &HashMap<String, Vec<BagCount>>) -> Result<usize> {
+        day7a(bag_map)
+    }
+
+    fn part2(bag_map: &HashMap<String, Vec<BagCount>>) -> Result<u32> {
+        day7b(bag_map)
+    }
 }
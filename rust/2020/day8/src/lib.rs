@@ -1,6 +1,6 @@
+use aoc_common::input::lines;
+use aoc_common::{Error, Result, Solution};
 use std::collections::HashSet;
-use std::fs::File;
-use std::io::{BufReader, Read, Result};
 
 #[derive(Debug, PartialEq)]
 enum CommandType {
@@ -15,7 +15,8 @@ enum ExecutionResult {
     Term(i32),
 }
 
-struct Command {
+#[derive(Debug)]
+pub struct Command {
     command: CommandType,
     input: i32,
 }
@@ -52,7 +53,7 @@ acc +6";
 
     #[test]
     fn should_parse_a_command_list() {
-        let command_list = parse_command_list(TEST_INPUT);
+        let command_list = parse_command_list(TEST_INPUT).unwrap();
         assert_eq!(9, command_list.len());
         assert_eq!(CommandType::NoOp, command_list[0].command);
         assert_eq!(CommandType::Accumulate, command_list[1].command);
@@ -61,6 +62,16 @@ acc +6";
         assert_eq!(4, command_list[2].input);
     }
 
+    #[test]
+    fn should_report_the_line_of_an_invalid_command() {
+        assert_eq!(
+            "line 3, column 1: invalid instruction 'jump +4'",
+            parse_command_list("nop +0\nacc +1\njump +4")
+                .unwrap_err()
+                .to_string()
+        );
+    }
+
     #[test]
     fn should_calculate_the_next_command_index() {
         assert_eq!(
@@ -70,8 +81,10 @@ acc +6";
                 &Command {
                     command: CommandType::Accumulate,
                     input: 0
-                }
+                },
+                10
             )
+            .unwrap()
         );
         assert_eq!(
             3,
@@ -80,8 +93,10 @@ acc +6";
                 &Command {
                     command: CommandType::NoOp,
                     input: 0
-                }
+                },
+                10
             )
+            .unwrap()
         );
         assert_eq!(
             7,
@@ -90,8 +105,10 @@ acc +6";
                 &Command {
                     command: CommandType::Jump,
                     input: 5
-                }
+                },
+                10
             )
+            .unwrap()
         );
         assert_eq!(
             1,
@@ -100,9 +117,29 @@ acc +6";
                 &Command {
                     command: CommandType::Jump,
                     input: -1
-                }
+                },
+                10
             )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn should_reject_a_jump_outside_the_program() {
+        let jump = |input| Command {
+            command: CommandType::Jump,
+            input,
+        };
+        assert_eq!(10, get_next_index(2, &jump(8), 10).unwrap());
+        assert!(get_next_index(2, &jump(9), 10).is_err());
+        assert!(get_next_index(2, &jump(-3), 10).is_err());
+
+        let command_list = parse_command_list("jmp -5\nacc +1").unwrap();
+        assert_eq!(
+            "invalid puzzle shape: jmp -5 on line 1 jumps outside the program",
+            day8a(&command_list).unwrap_err().to_string()
         );
+        assert!(day8b(&parse_command_list("nop +0\nacc +1\njmp +5\njmp -3").unwrap()).is_err());
     }
 
     #[test]
@@ -110,59 +147,84 @@ acc +6";
         assert_eq!(
             ExecutionResult::Loop(0),
             run_command_list(
-                &vec!(Command {
+                &[Command {
                     command: CommandType::Jump,
                     input: 0,
-                }),
+                }],
                 0,
                 0,
                 &mut HashSet::<usize>::new()
             )
+            .unwrap()
         );
         assert_eq!(
             ExecutionResult::Term(0),
             run_command_list(
-                &vec!(Command {
+                &[Command {
                     command: CommandType::NoOp,
                     input: 0,
-                }),
+                }],
                 0,
                 0,
                 &mut HashSet::<usize>::new()
             )
+            .unwrap()
         );
         assert_eq!(
             ExecutionResult::Term(0),
             run_command_list(
-                &vec!(Command {
+                &[Command {
                     command: CommandType::Accumulate,
                     input: 0,
-                }),
+                }],
                 0,
                 0,
                 &mut HashSet::<usize>::new()
             )
+            .unwrap()
         );
     }
 
     #[test]
     fn day8a_test() {
-        assert_eq!(5, day8a(TEST_INPUT));
+        assert_eq!(5, day8a(&parse_command_list(TEST_INPUT).unwrap()).unwrap());
+    }
+
+    #[test]
+    fn should_fail_when_the_program_does_not_loop() {
+        let command_list = parse_command_list("nop +0\nacc +1").unwrap();
+        assert!(day8a(&command_list).is_err());
     }
 
     #[test]
     fn day8b_test() {
-        assert_eq!(8, day8b(TEST_INPUT));
+        assert_eq!(8, day8b(&parse_command_list(TEST_INPUT).unwrap()).unwrap());
     }
-}
 
-fn get_next_index(current_index: usize, command: &Command) -> usize {
-    match command.command {
-        CommandType::Jump => (current_index as i32 + command.input) as usize,
-        _ => current_index + 1,
+    #[test]
+    fn should_fail_when_no_single_change_fixes_the_program() {
+        let command_list = parse_command_list("jmp +0\njmp -1").unwrap();
+        assert!(day8b(&command_list).is_err());
     }
 }
 
+//  A jump may land just past the last command, which ends the program, but
+//  nowhere else outside it.
+fn get_next_index(current_index: usize, command: &Command, len: usize) -> Result<usize> {
+    let next_index = match command.command {
+        CommandType::Jump => current_index.checked_add_signed(command.input as isize),
+        _ => current_index.checked_add(1),
+    };
+
+    next_index.filter(|&i| i <= len).ok_or_else(|| {
+        Error::invalid_shape(format!(
+            "jmp {:+} on line {} jumps outside the program",
+            command.input,
+            current_index + 1
+        ))
+    })
+}
+
 fn parse_command(command: &str) -> Option<Command> {
     let mut split = command.split(" ");
 
@@ -183,36 +245,28 @@ fn parse_command(command: &str) -> Option<Command> {
     }
 }
 
-fn parse_command_list(input: &str) -> Vec<Command> {
-    input
-        .split("\n")
-        .filter(|&line| line != "")
-        .map(|line| parse_command(line))
-        .filter_map(|x| x)
+pub fn parse_command_list(input: &str) -> Result<Vec<Command>> {
+    lines(input)
+        .map(|line| {
+            parse_command(line).ok_or_else(|| {
+                Error::parse_at(input, line, format!("invalid instruction '{}'", line))
+            })
+        })
         .collect()
 }
 
-fn read_file(path: &str) -> Result<String> {
-    let file = File::open(path)?;
-    let mut buf_reader = BufReader::new(file);
-    let mut input = String::new();
-    buf_reader.read_to_string(&mut input)?;
-
-    Ok(input)
-}
-
 fn run_command_list(
-    command_list: &Vec<Command>,
+    command_list: &[Command],
     start_accumulator: i32,
     start_index: usize,
     seen_indexes: &mut HashSet<usize>,
-) -> ExecutionResult {
+) -> Result<ExecutionResult> {
     let mut accumulator = start_accumulator;
     let mut current_index = start_index;
 
     while current_index < command_list.len() {
         if seen_indexes.contains(&current_index) {
-            return ExecutionResult::Loop(accumulator);
+            return Ok(ExecutionResult::Loop(accumulator));
         }
 
         let current_command = match command_list.get(current_index) {
@@ -222,14 +276,10 @@ fn run_command_list(
 
         accumulator = process_command(accumulator, current_command);
         seen_indexes.insert(current_index);
-        current_index = get_next_index(current_index, current_command);
+        current_index = get_next_index(current_index, current_command, command_list.len())?;
     }
 
-    if current_index == command_list.len() {
-        ExecutionResult::Term(accumulator)
-    } else {
-        ExecutionResult::Loop(accumulator)
-    }
+    Ok(ExecutionResult::Term(accumulator))
 }
 
 fn process_command(accumulator: i32, command: &Command) -> i32 {
@@ -239,41 +289,46 @@ fn process_command(accumulator: i32, command: &Command) -> i32 {
     }
 }
 
-fn day8a(input: &str) -> i32 {
-    let command_list = parse_command_list(input);
-    let exec_result = run_command_list(&command_list, 0, 0, &mut HashSet::<usize>::new());
+pub fn day8a(command_list: &[Command]) -> Result<i32> {
+    let exec_result = run_command_list(command_list, 0, 0, &mut HashSet::<usize>::new())?;
 
     match exec_result {
-        ExecutionResult::Loop(n) => n,
-        ExecutionResult::Term(_) => -1,
+        ExecutionResult::Loop(n) => Ok(n),
+        ExecutionResult::Term(_) => {
+            Err(Error::no_solution("the program terminates without looping"))
+        }
     }
 }
 
-fn day8b(input: &str) -> i32 {
-    let command_list = parse_command_list(input);
+fn no_fix_found() -> Error {
+    Error::no_solution("no single jmp or nop change lets the program terminate")
+}
+
+pub fn day8b(command_list: &[Command]) -> Result<i32> {
     let mut accumulator = 0_i32;
     let mut current_index = 0;
     let mut seen_indexes = HashSet::<usize>::new();
 
     while current_index < command_list.len() {
-        println!("{}", current_index);
         if seen_indexes.contains(&current_index) {
-            return -2;
+            return Err(no_fix_found());
         }
 
         let current_command = match command_list.get(current_index) {
-            None => return -1,
+            None => return Err(no_fix_found()),
             Some(command) => command,
         };
 
         if current_command.command == CommandType::Accumulate {
             accumulator = process_command(accumulator, current_command);
             seen_indexes.insert(current_index);
-            current_index = get_next_index(current_index, current_command);
+            current_index = get_next_index(current_index, current_command, command_list.len())?;
             continue;
         }
 
         seen_indexes.insert(current_index);
+        //  A changed command that jumps outside the program is no fix, but
+        //  the original program must stay inside it.
         let test_next_index = get_next_index(
             current_index,
             &Command {
@@ -284,36 +339,46 @@ fn day8b(input: &str) -> i32 {
                 },
                 input: current_command.input,
             },
+            command_list.len(),
         );
 
-        if test_next_index == command_list.len() {
-            return accumulator;
-        }
+        if let Ok(test_next_index) = test_next_index {
+            if test_next_index == command_list.len() {
+                return Ok(accumulator);
+            }
 
-        match run_command_list(
-            &command_list,
-            accumulator,
-            test_next_index,
-            &mut seen_indexes.iter().cloned().collect(),
-        ) {
-            ExecutionResult::Term(n) => return n,
-            ExecutionResult::Loop(_) => {
-                current_index = get_next_index(current_index, current_command);
+            if let Ok(ExecutionResult::Term(n)) = run_command_list(
+                command_list,
+                accumulator,
+                test_next_index,
+                &mut seen_indexes.iter().cloned().collect(),
+            ) {
+                return Ok(n);
             }
         }
+
+        current_index = get_next_index(current_index, current_command, command_list.len())?;
     }
 
-    accumulator
+    Ok(accumulator)
 }
 
-fn main() -> Result<()> {
-    let input = read_file("input")?;
+pub struct Day8;
 
-    let result = day8a(input.as_str());
-    println!("Day 8A - {}", result);
+impl Solution for Day8 {
+    type Input = Vec<Command>;
+    type Part1 = i32;
+    type Part2 = i32;
 
-    let result = day8b(input.as_str());
-    println!("Day 8B - {}", result);
+    fn parse(input: &str) -> Result<Vec<Command>> {
+        parse_command_list(input)
+    }
 
-    Ok(())
+    fn part1(command_list: &Vec<Command>) -> Result<i32> {
+        day8a(command_list)
+    }
+
+    fn part2(command_list: &Vec<Command>) -> Result<i32> {
+        day8b(command_list)
+    }
 }
@@ -1,6 +1,6 @@
+use aoc_common::input::numbers;
+use aoc_common::{Error, Result, Solution};
 use std::collections::HashMap;
-use std::fs::File;
-use std::io::{BufReader, Read, Result};
 
 #[cfg(test)]
 mod day9_tests {
@@ -28,13 +28,13 @@ mod day9_tests {
 309
 576";
 
-    static TEST_PARSED: [u32; 20] = [
+    static TEST_PARSED: [u64; 20] = [
         35, 20, 15, 25, 47, 40, 62, 55, 65, 95, 102, 117, 150, 182, 127, 219, 299, 277, 309, 576,
     ];
 
     #[test]
     fn should_parse_input_to_numbers() {
-        assert_eq!(Vec::from(TEST_PARSED), file_to_numbers(TEST_INPUT));
+        assert_eq!(Vec::from(TEST_PARSED), numbers::<u64>(TEST_INPUT).unwrap());
     }
 
     #[test]
@@ -56,7 +56,7 @@ mod day9_tests {
 
     #[test]
     fn should_increment_the_seen_count_when_the_same_number_appears_multiple_times() {
-        let result = calculate_preamble(&vec![1, 1, 1, 1, 1, 1, 1], 5);
+        let result = calculate_preamble(&[1, 1, 1, 1, 1, 1, 1], 5);
         assert_eq!(1, result.len());
         assert!(result.contains_key(&1));
         assert_eq!(5, *result.get(&1).unwrap());
@@ -64,7 +64,7 @@ mod day9_tests {
 
     #[test]
     fn should_replace_a_number_in_the_preamble_map() {
-        let mut map: HashMap<u32, u32> = vec![1, 2, 3, 4, 5].iter().map(|&n| (n, 1)).collect();
+        let mut map: HashMap<u64, u32> = [1, 2, 3, 4, 5].iter().map(|&n| (n, 1)).collect();
 
         replace_preamble(&mut map, 1, 6);
 
@@ -76,7 +76,7 @@ mod day9_tests {
 
     #[test]
     fn should_decrement_the_seen_count_when_replacing_a_number_that_appears_multiple_times() {
-        let mut map: HashMap<u32, u32> = vec![(1, 5)].into_iter().collect();
+        let mut map: HashMap<u64, u32> = vec![(1, 5)].into_iter().collect();
         replace_preamble(&mut map, 1, 2);
         assert_eq!(2, map.len());
         assert!(map.contains_key(&1));
@@ -87,7 +87,7 @@ mod day9_tests {
 
     #[test]
     fn should_check_if_a_number_is_created_from_the_preamble() {
-        let map: HashMap<u32, u32> = vec![1, 2, 3, 4, 5].iter().map(|&n| (n, 1)).collect();
+        let map: HashMap<u64, u32> = [1, 2, 3, 4, 5].iter().map(|&n| (n, 1)).collect();
 
         assert!(is_created_from_preamble(&map, 3));
         assert!(is_created_from_preamble(&map, 8));
@@ -108,9 +108,16 @@ mod day9_tests {
         assert_eq!(2, start);
         assert_eq!(5, end);
     }
+
+    #[test]
+    fn should_fail_when_every_number_follows_the_rule() {
+        let numbers: Vec<u64> = (1..=30).collect();
+        assert!(day9a(&numbers).is_err());
+        assert!(day9a(&numbers[..10]).is_err());
+    }
 }
 
-fn find_indexes_that_add_to(input: &Vec<u32>, target: u32) -> Option<(usize, usize)> {
+fn find_indexes_that_add_to(input: &[u64], target: u64) -> Option<(usize, usize)> {
     let input_length = input.len();
     for i in 0..input_length {
         let current_input = input[i];
@@ -119,8 +126,7 @@ fn find_indexes_that_add_to(input: &Vec<u32>, target: u32) -> Option<(usize, usi
         }
 
         let mut remain = target - current_input;
-        for j in i + 1..input_length {
-            let next_input = input[j];
+        for (j, &next_input) in input.iter().enumerate().skip(i + 1) {
             if next_input > remain {
                 break;
             } else if next_input == remain {
@@ -133,7 +139,7 @@ fn find_indexes_that_add_to(input: &Vec<u32>, target: u32) -> Option<(usize, usi
     None
 }
 
-fn find_number_not_created_from_preamble(input: &Vec<u32>, preamble_length: usize) -> Option<u32> {
+fn find_number_not_created_from_preamble(input: &[u64], preamble_length: usize) -> Option<u64> {
     let mut preamble = calculate_preamble(input, preamble_length);
 
     for n in preamble_length..input.len() {
@@ -147,7 +153,7 @@ fn find_number_not_created_from_preamble(input: &Vec<u32>, preamble_length: usiz
     None
 }
 
-fn is_created_from_preamble(map: &HashMap<u32, u32>, to_test: u32) -> bool {
+fn is_created_from_preamble(map: &HashMap<u64, u32>, to_test: u64) -> bool {
     map.keys().any(|&n| {
         if n > to_test {
             return false;
@@ -160,7 +166,7 @@ fn is_created_from_preamble(map: &HashMap<u32, u32>, to_test: u32) -> bool {
     })
 }
 
-fn replace_preamble(map: &mut HashMap<u32, u32>, to_remove: u32, to_add: u32) {
+fn replace_preamble(map: &mut HashMap<u64, u32>, to_remove: u64, to_add: u64) {
     let removed_count = map.entry(to_remove).or_insert(1_u32);
     if *removed_count > 1_u32 {
         *removed_count -= 1_u32;
@@ -172,10 +178,10 @@ fn replace_preamble(map: &mut HashMap<u32, u32>, to_remove: u32, to_add: u32) {
     *added_count += 1;
 }
 
-fn calculate_preamble(input: &Vec<u32>, preamble_length: usize) -> HashMap<u32, u32> {
+fn calculate_preamble(input: &[u64], preamble_length: usize) -> HashMap<u64, u32> {
     input[..preamble_length]
         .iter()
-        .fold(HashMap::<u32, u32>::new(), |mut map, &n| {
+        .fold(HashMap::<u64, u32>::new(), |mut map, &n| {
             {
                 let count = map.entry(n).or_insert(0);
                 *count += 1;
@@ -184,51 +190,47 @@ fn calculate_preamble(input: &Vec<u32>, preamble_length: usize) -> HashMap<u32,
         })
 }
 
-fn file_to_numbers(input: &str) -> Vec<u32> {
-    return input
-        .split('\n')
-        .filter(|&s| s != "")
-        .map(|s| s.parse::<u32>().unwrap_or(0))
-        .collect();
-}
-
-fn read_file(path: &str) -> Result<String> {
-    let file = File::open(path)?;
-    let mut buf_reader = BufReader::new(file);
-    let mut input = String::new();
-    buf_reader.read_to_string(&mut input)?;
+fn find_invalid_number(numbers: &[u64]) -> Result<u64> {
+    if numbers.len() <= 25 {
+        return Err(Error::invalid_shape(format!(
+            "expected more than a 25 number preamble, found {} numbers",
+            numbers.len()
+        )));
+    }
 
-    Ok(input)
+    find_number_not_created_from_preamble(numbers, 25)
+        .ok_or_else(|| Error::no_solution("every number is a sum of two in its preamble"))
 }
 
-fn day9a(input: &str) -> u32 {
-    let numbers = file_to_numbers(input);
-    find_number_not_created_from_preamble(&numbers, 25).unwrap_or(0)
+pub fn day9a(numbers: &[u64]) -> Result<u64> {
+    find_invalid_number(numbers)
 }
 
-fn day9b(input: &str) -> u32 {
-    let numbers = file_to_numbers(input);
-    let target = match find_number_not_created_from_preamble(&numbers, 25) {
-        Some(t) => t,
-        None => return 0,
-    };
-    let (start, end) = match find_indexes_that_add_to(&numbers, target) {
-        Some(result) => result,
-        None => return 0,
-    };
-
-    numbers[start..=end].iter().min().unwrap_or(&0_u32)
-        + numbers[start..=end].iter().max().unwrap_or(&0_u32)
+pub fn day9b(numbers: &[u64]) -> Result<u64> {
+    let target = find_invalid_number(numbers)?;
+    let (start, end) = find_indexes_that_add_to(numbers, target)
+        .ok_or_else(|| Error::no_solution(format!("no contiguous range sums to {}", target)))?;
+
+    let range = &numbers[start..=end];
+    Ok(range.iter().min().unwrap() + range.iter().max().unwrap())
 }
 
-fn main() -> Result<()> {
-    let input = read_file("input")?;
+pub struct Day9;
 
-    let result = day9a(input.as_str());
-    println!("Day 9A - {}", result);
+impl Solution for Day9 {
+    type Input = Vec<u64>;
+    type Part1 = u64;
+    type Part2 = u64;
 
-    let result = day9b(input.as_str());
-    println!("Day 9B - {}", result);
+    fn parse(input: &str) -> Result<Vec<u64>> {
+        numbers(input)
+    }
 
-    Ok(())
+    fn part1(numbers: &Vec<u64>) -> Result<u64> {
+        day9a(numbers)
+    }
+
+    fn part2(numbers: &Vec<u64>) -> Result<u64> {
+        day9b(numbers)
+    }
 }
@@ -0,0 +1,175 @@
+use crate::Part;
+use aoc_common::input::lines;
+use aoc_common::{Error, Result};
+use std::collections::HashMap;
+use std::fmt;
+
+#[derive(Debug, PartialEq)]
+pub enum Verdict {
+    Pass,
+    Fail(String),
+    Unknown,
+}
+
+impl Verdict {
+    pub fn status(&self) -> &'static str {
+        match self {
+            Verdict::Pass => "PASS",
+            Verdict::Fail(_) => "FAIL",
+            Verdict::Unknown => "UNKNOWN",
+        }
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Verdict::Fail(expected) => write!(f, "FAIL (expected {})", expected),
+            verdict => write!(f, "{}", verdict.status()),
+        }
+    }
+}
+
+//  The recorded answers for each year, day and part, so that a change which
+//  alters a real-input answer is caught by the runner.
+#[derive(Debug, Default)]
+pub struct Answers {
+    expected: HashMap<(String, usize, Part), String>,
+}
+
+#[cfg(test)]
+mod answers_tests {
+    use super::*;
+
+    static TEST_INPUT: &str = "
+# year day part answer
+2020 1 a 100419
+2020 1 b 265253940
+2020 21 b fdsfpg,jmvxx,lkv
+";
+
+    #[test]
+    fn should_parse_a_manifest() {
+        let answers = Answers::parse(TEST_INPUT).unwrap();
+        assert_eq!(3, answers.expected.len());
+    }
+
+    #[test]
+    fn should_check_answers_against_the_manifest() {
+        let answers = Answers::parse(TEST_INPUT).unwrap();
+
+        assert_eq!(Verdict::Pass, answers.check("2020", 1, Part::A, "100419"));
+        assert_eq!(
+            Verdict::Fail(String::from("265253940")),
+            answers.check("2020", 1, Part::B, "12")
+        );
+        assert_eq!(
+            Verdict::Pass,
+            answers.check("2020", 21, Part::B, "fdsfpg,jmvxx,lkv")
+        );
+        assert_eq!(Verdict::Unknown, answers.check("2020", 2, Part::A, "603"));
+        assert_eq!(
+            Verdict::Unknown,
+            answers.check("2019", 1, Part::A, "100419")
+        );
+    }
+
+    #[test]
+    fn should_fail_a_missing_answer_only_if_one_is_recorded() {
+        let answers = Answers::parse(TEST_INPUT).unwrap();
+
+        assert_eq!(
+            Verdict::Fail(String::from("100419")),
+            answers.check_missing("2020", 1, Part::A)
+        );
+        assert_eq!(Verdict::Unknown, answers.check_missing("2020", 2, Part::A));
+    }
+
+    #[test]
+    fn should_reject_malformed_entries() {
+        assert_eq!(
+            "line 2, column 1: expected '<year> <day> <part> <answer>', found '2020 1 a'",
+            Answers::parse("2020 1 a 5\n2020 1 a")
+                .unwrap_err()
+                .to_string()
+        );
+        assert_eq!(
+            "line 1, column 6: 'x' is not a day",
+            Answers::parse("2020 x a 5").unwrap_err().to_string()
+        );
+        assert_eq!(
+            "line 1, column 8: 'c' is not a part (a or b)",
+            Answers::parse("2020 1 c 5").unwrap_err().to_string()
+        );
+        assert!(Answers::parse("2020 1 a 5\n2020 1 a 6").is_err());
+    }
+}
+
+fn parse_entry<'a>(input: &'a str, line: &'a str) -> Result<(String, usize, Part, &'a str)> {
+    let fields: Vec<&str> = line.split_whitespace().collect();
+
+    if fields.len() != 4 {
+        return Err(Error::parse_at(
+            input,
+            line,
+            format!("expected '<year> <day> <part> <answer>', found '{}'", line),
+        ));
+    }
+
+    let day = fields[1]
+        .parse::<usize>()
+        .map_err(|_| Error::parse_at(input, fields[1], format!("'{}' is not a day", fields[1])))?;
+
+    let part = match fields[2] {
+        "a" | "A" => Part::A,
+        "b" | "B" => Part::B,
+        p => {
+            return Err(Error::parse_at(
+                input,
+                fields[2],
+                format!("'{}' is not a part (a or b)", p),
+            ))
+        }
+    };
+
+    Ok((String::from(fields[0]), day, part, fields[3]))
+}
+
+impl Answers {
+    pub fn parse(input: &str) -> Result<Answers> {
+        let mut expected = HashMap::new();
+
+        for line in lines(input).filter(|line| !line.trim_start().starts_with('#')) {
+            let (year, day, part, answer) = parse_entry(input, line)?;
+
+            if expected
+                .insert((year, day, part), String::from(answer))
+                .is_some()
+            {
+                return Err(Error::parse_at(
+                    input,
+                    line,
+                    format!("day {}{} already has an answer", day, part.label()),
+                ));
+            }
+        }
+
+        Ok(Answers { expected })
+    }
+
+    pub fn check(&self, year: &str, day: usize, part: Part, answer: &str) -> Verdict {
+        match self.expected.get(&(String::from(year), day, part)) {
+            None => Verdict::Unknown,
+            Some(expected) if expected == answer => Verdict::Pass,
+            Some(expected) => Verdict::Fail(expected.clone()),
+        }
+    }
+
+    //  A part that gave no answer at all fails only if it has one recorded.
+    pub fn check_missing(&self, year: &str, day: usize, part: Part) -> Verdict {
+        match self.expected.get(&(String::from(year), day, part)) {
+            None => Verdict::Unknown,
+            Some(expected) => Verdict::Fail(expected.clone()),
+        }
+    }
+}
@@ -0,0 +1,127 @@
+use std::time::Duration;
+
+//  Every sample taken for one step, sorted so the order statistics are cheap.
+#[derive(Debug, Clone)]
+pub struct Timings {
+    samples: Vec<Duration>,
+}
+
+#[cfg(test)]
+mod bench_tests {
+    use super::*;
+
+    fn micros(samples: &[u64]) -> Timings {
+        Timings::new(samples.iter().map(|&s| Duration::from_micros(s)).collect())
+    }
+
+    #[test]
+    fn should_calculate_order_statistics() {
+        let timings = micros(&[30, 10, 20, 50, 40]);
+        assert_eq!(10, timings.min().as_micros());
+        assert_eq!(30, timings.median().as_micros());
+        assert_eq!(50, timings.max().as_micros());
+    }
+
+    #[test]
+    fn should_take_the_lower_median_of_an_even_sample() {
+        assert_eq!(20, micros(&[40, 10, 20, 30]).median().as_micros());
+    }
+
+    #[test]
+    fn should_format_a_summary_table() {
+        let rows = vec![
+            Row {
+                day: 1,
+                step: String::from("parse"),
+                timings: micros(&[10, 20, 30]),
+            },
+            Row {
+                day: 1,
+                step: String::from("A"),
+                timings: micros(&[60]),
+            },
+        ];
+
+        assert_eq!(
+            vec![
+                "Day  Step    Min (µs)  Median (µs)   Max (µs)  Share",
+                "  1  parse         10           20         30  25.0%",
+                "  1  A             60           60         60  75.0%",
+                "     Total         70           80         90",
+            ],
+            summary(&rows)
+        );
+    }
+}
+
+impl Timings {
+    pub fn new(mut samples: Vec<Duration>) -> Timings {
+        samples.sort();
+        Timings { samples }
+    }
+
+    pub fn min(&self) -> Duration {
+        self.samples.first().copied().unwrap_or_default()
+    }
+
+    pub fn median(&self) -> Duration {
+        match self.samples.len() {
+            0 => Duration::default(),
+            n => self.samples[(n - 1) / 2],
+        }
+    }
+
+    pub fn max(&self) -> Duration {
+        self.samples.last().copied().unwrap_or_default()
+    }
+}
+
+pub struct Row {
+    pub day: usize,
+    pub step: String,
+    pub timings: Timings,
+}
+
+//  Lays out one row per day and step, with the share of the summed medians
+//  so the slowest days stand out.
+pub fn summary(rows: &[Row]) -> Vec<String> {
+    let total = |f: fn(&Timings) -> Duration| -> u128 {
+        rows.iter().map(|row| f(&row.timings).as_micros()).sum()
+    };
+    let total_median = total(Timings::median);
+
+    let mut table = vec![format!(
+        "{:>3}  {:<5}  {:>9}  {:>11}  {:>9}  {:>5}",
+        "Day", "Step", "Min (µs)", "Median (µs)", "Max (µs)", "Share"
+    )];
+
+    for row in rows {
+        let median = row.timings.median().as_micros();
+        let share = if total_median == 0 {
+            0.0
+        } else {
+            median as f64 * 100.0 / total_median as f64
+        };
+
+        table.push(format!(
+            "{:>3}  {:<5}  {:>9}  {:>11}  {:>9}  {:>4.1}%",
+            row.day,
+            row.step,
+            row.timings.min().as_micros(),
+            median,
+            row.timings.max().as_micros(),
+            share
+        ));
+    }
+
+    table.push(format!(
+        "{:>3}  {:<5}  {:>9}  {:>11}  {:>9}",
+        "",
+        "Total",
+        total(Timings::min),
+        total_median,
+        total(Timings::max)
+    ));
+
+    table
+}
@@ -0,0 +1,62 @@
+use aoc_common::read_file;
+use std::fs;
+use std::io::{Error, Result};
+use std::path::Path;
+
+#[cfg(test)]
+mod cache_tests {
+    use super::*;
+    use std::env;
+    use std::process;
+
+    #[test]
+    fn should_fetch_an_input_only_once() {
+        let directory = env::temp_dir().join(format!("aoc-cache-{}", process::id()));
+        let path = directory.join("2020").join("day01.txt");
+
+        assert_eq!(
+            "1721\n",
+            load(&path, || Ok(String::from("1721\n"))).unwrap()
+        );
+        assert_eq!("1721\n", load(&path, || panic!("fetched twice")).unwrap());
+
+        fs::write(&path, "").unwrap();
+        assert_eq!("979\n", load(&path, || Ok(String::from("979\n"))).unwrap());
+        fs::remove_dir_all(&directory).unwrap();
+
+        let error = load(&path, || Err(Error::other("offline"))).unwrap_err();
+        assert!(error
+            .to_string()
+            .ends_with("is not cached and could not be fetched: offline"));
+        assert!(!path.exists());
+    }
+}
+
+//  Reads a cached input, or fetches it and keeps it for next time. An empty
+//  file is a placeholder for an input that hasn't been fetched yet.
+pub fn load(path: &Path, fetch: impl FnOnce() -> Result<String>) -> Result<String> {
+    if path.exists() {
+        let input = read_file(path)?;
+        if !input.is_empty() {
+            return Ok(input);
+        }
+    }
+
+    let input = fetch().map_err(|e| {
+        Error::new(
+            e.kind(),
+            format!(
+                "{} is not cached and could not be fetched: {}",
+                path.display(),
+                e
+            ),
+        )
+    })?;
+
+    if let Some(directory) = path.parent() {
+        fs::create_dir_all(directory)?;
+    }
+    fs::write(path, &input)?;
+
+    Ok(input)
+}
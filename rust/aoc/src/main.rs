@@ -0,0 +1,806 @@
+mod answers;
+mod bench;
+mod cache;
+mod parity;
+mod pool;
+mod report;
+mod scaffold;
+mod site;
+mod trace;
+mod year2020;
+
+use answers::{Answers, Verdict};
+use aoc_common::trace::{Traced, Tracer};
+use aoc_common::{read_file, read_input, Solution};
+use bench::Timings;
+use parity::DayParity;
+use report::{DayReport, Format};
+use site::{Outcome, Site};
+use std::env;
+use std::fs::OpenOptions;
+use std::io::{Error, ErrorKind, Result, Write};
+use std::path::{Path, PathBuf};
+use std::process;
+use std::thread;
+use std::time::Instant;
+
+#[derive(Debug, PartialEq, Eq, Hash, Copy, Clone)]
+pub enum Part {
+    A,
+    B,
+}
+
+impl Part {
+    fn label(self) -> char {
+        match self {
+            Part::A => 'A',
+            Part::B => 'B',
+        }
+    }
+}
+
+pub struct Answer {
+    pub part: Part,
+    pub result: aoc_common::Result<String>,
+    pub timings: Timings,
+}
+
+pub struct Solved {
+    pub parse: Timings,
+    pub answers: Vec<Answer>,
+}
+
+#[derive(Debug, PartialEq)]
+struct Options {
+    bench: Option<usize>,
+    format: Format,
+    input: Option<PathBuf>,
+    fsharp: Option<PathBuf>,
+    output: Option<PathBuf>,
+    steps: Option<usize>,
+    jobs: Option<usize>,
+}
+
+//  Solves the given parts of a day, timing each over a number of runs, with
+//  the parts sharing the parsed input on up to a number of jobs.
+type Solver = fn(usize, &str, &[Part], usize, usize) -> aoc_common::Result<Solved>;
+type TraceSolver = fn(usize, &str, Part, &mut dyn Tracer) -> aoc_common::Result<String>;
+
+#[cfg(test)]
+mod aoc_tests {
+    use super::*;
+
+    #[test]
+    fn should_parse_a_single_day() {
+        assert_eq!(vec![5], parse_days("5", 25).unwrap());
+    }
+
+    #[test]
+    fn should_parse_a_range_of_days() {
+        assert_eq!(vec![3, 4, 5, 6], parse_days("3-6", 25).unwrap());
+    }
+
+    #[test]
+    fn should_parse_all_days() {
+        assert_eq!(
+            (1..=25).collect::<Vec<usize>>(),
+            parse_days("all", 25).unwrap()
+        );
+    }
+
+    #[test]
+    fn should_reject_days_out_of_range() {
+        assert!(parse_days("0", 25).is_err());
+        assert!(parse_days("26", 25).is_err());
+        assert!(parse_days("20-30", 25).is_err());
+        assert!(parse_days("6-3", 25).is_err());
+        assert!(parse_days("x", 25).is_err());
+    }
+
+    #[test]
+    fn should_skip_part_two_when_a_day_has_none() {
+        let answers = solve_parts::<day25::Day25>("5764801\n17807724", &[Part::A, Part::B], 1, 1)
+            .unwrap()
+            .answers;
+
+        assert_eq!(1, answers.len());
+        assert_eq!(
+            14897079,
+            answers[0]
+                .result
+                .as_ref()
+                .unwrap()
+                .parse::<usize>()
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn should_time_every_run() {
+        let solved =
+            solve_parts::<day25::Day25>("5764801\n17807724", &[Part::A, Part::B], 3, 2).unwrap();
+
+        assert!(solved.parse.min() <= solved.parse.max());
+        assert_eq!(
+            "14897079",
+            solved.answers[0].result.as_ref().unwrap().as_str()
+        );
+    }
+
+    #[test]
+    fn should_separate_options_from_arguments() {
+        let args: Vec<String> = vec!["2020", "--bench", "5", "3-6"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+
+        let (options, positional) = parse_options(&args).unwrap();
+        assert_eq!(Some(5), options.bench);
+        assert_eq!(Format::Text, options.format);
+        assert_eq!(vec!["2020", "3-6"], positional);
+
+        let (options, _) =
+            parse_options(&[String::from("--format"), String::from("json")]).unwrap();
+        assert_eq!(Format::Json, options.format);
+        assert!(parse_options(&[String::from("--format"), String::from("xml")]).is_err());
+
+        let args: Vec<String> = vec!["trace", "--format", "gif", "--output", "seats.gif"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+        let (options, positional) = parse_options(&args).unwrap();
+        assert_eq!(Format::Gif, options.format);
+        assert_eq!(Some(PathBuf::from("seats.gif")), options.output);
+        assert_eq!(vec!["trace"], positional);
+
+        let (options, _) = parse_options(&[String::from("--steps"), String::from("10")]).unwrap();
+        assert_eq!(Some(10), options.steps);
+        assert!(parse_options(&[String::from("--steps"), String::from("0")]).is_err());
+
+        let (options, _) = parse_options(&[String::from("--input"), String::from("-")]).unwrap();
+        assert_eq!(Some(PathBuf::from("-")), options.input);
+        assert!(parse_options(&[String::from("--input")]).is_err());
+
+        let args: Vec<String> = vec!["parity", "--fsharp", "fsharp", "2015"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+        let (options, positional) = parse_options(&args).unwrap();
+        assert_eq!(Some(PathBuf::from("fsharp")), options.fsharp);
+        assert_eq!(vec!["parity", "2015"], positional);
+    }
+
+    #[test]
+    fn should_default_to_the_inputs_directory() {
+        assert_eq!(
+            Path::new("inputs").join("2020").join("day07.txt"),
+            input_path("2020", 7)
+        );
+
+        assert!(parse_options(&[String::from("--bench")]).is_err());
+        assert!(parse_options(&[String::from("--bench"), String::from("0")]).is_err());
+        assert!(parse_options(&[String::from("--fast")]).is_err());
+    }
+
+    #[test]
+    fn should_parse_parts() {
+        assert_eq!(vec![Part::A], parse_parts(Some("a")).unwrap());
+        assert_eq!(vec![Part::B], parse_parts(Some("2")).unwrap());
+        assert_eq!(vec![Part::A, Part::B], parse_parts(None).unwrap());
+        assert!(parse_parts(Some("c")).is_err());
+    }
+
+    #[test]
+    fn should_run_one_job_at_a_time_when_benchmarking() {
+        let args: Vec<String> = vec!["--jobs", "3", "2020"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+        let (options, _) = parse_options(&args).unwrap();
+        assert_eq!(3, jobs(&options));
+        assert!(parse_options(&[String::from("--jobs"), String::from("0")]).is_err());
+
+        let (options, _) = parse_options(&[String::from("--bench"), String::from("5")]).unwrap();
+        assert_eq!(1, jobs(&options));
+        let (options, _) = parse_options(&[]).unwrap();
+        assert!(jobs(&options) >= 1);
+    }
+
+    #[test]
+    fn should_share_the_jobs_between_the_days() {
+        assert_eq!(4, jobs_per_day(4, 1));
+        assert_eq!(2, jobs_per_day(4, 2));
+        assert_eq!(1, jobs_per_day(4, 3));
+        assert_eq!(1, jobs_per_day(4, 25));
+        assert_eq!(1, jobs_per_day(1, 25));
+    }
+
+    #[test]
+    fn should_trace_a_simulation() {
+        let mut frames = vec![];
+        let answer = year2020::trace(23, "389125467", Part::A, &mut frames).unwrap();
+
+        assert_eq!("67384529", answer);
+        assert_eq!(101, frames.len());
+        assert!(year2020::trace(1, "1721\n979", Part::A, &mut frames).is_err());
+    }
+
+    #[test]
+    fn should_check_passwords_against_a_policy() {
+        let input = "1-3 a: abcde\n1-3 b: cdefg\n2-9 c: ccccccccc";
+        assert_eq!(
+            vec![
+                "cdefg needs 1 to 3 of 'b'",
+                "ccccccccc needs 'c' at exactly one of positions 2 and 9",
+                "1 of 3 passwords meet 'old and new'",
+            ],
+            year2020::check_passwords(input, "old and new").unwrap()
+        );
+        assert_eq!(
+            "line 1, column 1: unknown policy 'newest'",
+            year2020::check_policies(&["old", "newest"])
+                .unwrap_err()
+                .to_string()
+        );
+        match year2020::check_policies(&["old and newest"]) {
+            Err(aoc_common::Error::Parse { line, column, .. }) => {
+                assert_eq!((1, 9), (line, column))
+            }
+            result => panic!("unexpected result {:?}", result),
+        }
+        assert_eq!(
+            "line 2, column 1: missing ':' after the policy",
+            year2020::check_passwords("1-3 a: abcde\n1-3 b", "old")
+                .unwrap_err()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn should_summarise_passwords_against_each_policy() {
+        let input = "1-3 a: abcde\n1-3 b cdefg\n2-9 c: ccccccccc";
+        assert_eq!(
+            vec![
+                "malformed line 2, column 1: missing ':' after the policy",
+                "old: 2 valid, 0 invalid",
+                "new: 1 valid, 1 invalid",
+            ],
+            year2020::summarise_passwords(input, &[]).unwrap()
+        );
+        assert_eq!(
+            vec![
+                "malformed line 2, column 1: missing ':' after the policy",
+                "distinct:3: 1 valid, 1 invalid",
+            ],
+            year2020::summarise_passwords(input, &["distinct:3"]).unwrap()
+        );
+    }
+}
+
+fn invalid_input(message: String) -> Error {
+    Error::new(ErrorKind::InvalidInput, message)
+}
+
+fn parse_day(day: &str, last_day: usize) -> Result<usize> {
+    match day.trim().parse::<usize>() {
+        Ok(n) if n >= 1 && n <= last_day => Ok(n),
+        _ => Err(invalid_input(format!(
+            "'{}' is not a day between 1 and {}",
+            day, last_day
+        ))),
+    }
+}
+
+fn parse_days(days: &str, last_day: usize) -> Result<Vec<usize>> {
+    if days == "all" {
+        return Ok((1..=last_day).collect());
+    }
+
+    let mut bounds = days.splitn(2, '-');
+    let first = parse_day(bounds.next().unwrap_or(""), last_day)?;
+    let last = match bounds.next() {
+        Some(day) => parse_day(day, last_day)?,
+        None => first,
+    };
+
+    if last < first {
+        return Err(invalid_input(format!(
+            "'{}' is an empty range of days",
+            days
+        )));
+    }
+
+    Ok((first..=last).collect())
+}
+
+fn parse_parts(part: Option<&str>) -> Result<Vec<Part>> {
+    match part {
+        None => Ok(vec![Part::A, Part::B]),
+        Some("a") | Some("A") | Some("1") => Ok(vec![Part::A]),
+        Some("b") | Some("B") | Some("2") => Ok(vec![Part::B]),
+        Some(p) => Err(invalid_input(format!("'{}' is not a part (a or b)", p))),
+    }
+}
+
+fn parse_options(args: &[String]) -> Result<(Options, Vec<&str>)> {
+    let mut options = Options {
+        bench: None,
+        format: Format::Text,
+        input: None,
+        fsharp: None,
+        output: None,
+        steps: None,
+        jobs: None,
+    };
+    let mut positional = vec![];
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--bench" => {
+                let runs = args.next().map_or("", |r| r.as_str());
+                options.bench = match runs.parse::<usize>() {
+                    Ok(n) if n > 0 => Some(n),
+                    _ => return Err(invalid_input(format!("'{}' is not a number of runs", runs))),
+                };
+            }
+            "--format" => {
+                options.format = match args.next().map(|f| f.as_str()) {
+                    Some("text") => Format::Text,
+                    Some("json") => Format::Json,
+                    Some("gif") => Format::Gif,
+                    Some("png") => Format::Png,
+                    f => {
+                        return Err(invalid_input(format!(
+                            "'{}' is not an output format (text, json, gif or png)",
+                            f.unwrap_or("")
+                        )))
+                    }
+                };
+            }
+            "--input" => {
+                options.input = match args.next() {
+                    Some(path) => Some(PathBuf::from(path)),
+                    None => return Err(invalid_input(String::from("--input needs a path or -"))),
+                };
+            }
+            "--fsharp" => {
+                options.fsharp = match args.next() {
+                    Some(path) => Some(PathBuf::from(path)),
+                    None => return Err(invalid_input(String::from("--fsharp needs a path"))),
+                };
+            }
+            "--output" => {
+                options.output = match args.next() {
+                    Some(path) => Some(PathBuf::from(path)),
+                    None => return Err(invalid_input(String::from("--output needs a path"))),
+                };
+            }
+            "--jobs" => {
+                let jobs = args.next().map_or("", |j| j.as_str());
+                options.jobs = match jobs.parse::<usize>() {
+                    Ok(n) if n > 0 => Some(n),
+                    _ => return Err(invalid_input(format!("'{}' is not a number of jobs", jobs))),
+                };
+            }
+            "--steps" => {
+                let steps = args.next().map_or("", |s| s.as_str());
+                options.steps = match steps.parse::<usize>() {
+                    Ok(n) if n > 0 => Some(n),
+                    _ => {
+                        return Err(invalid_input(format!(
+                            "'{}' is not a number of steps",
+                            steps
+                        )))
+                    }
+                };
+            }
+            a if a.starts_with("--") => {
+                return Err(invalid_input(format!("unknown option '{}'", a)))
+            }
+            a => positional.push(a),
+        }
+    }
+
+    Ok((options, positional))
+}
+
+//  Runs a step the given number of times, stopping early if it fails, and
+//  keeps the result of the last run.
+fn time_runs<T>(
+    runs: usize,
+    mut step: impl FnMut() -> aoc_common::Result<T>,
+) -> (aoc_common::Result<T>, Timings) {
+    let mut samples = vec![];
+
+    loop {
+        let start = Instant::now();
+        let result = step();
+        samples.push(start.elapsed());
+
+        if result.is_err() || samples.len() >= runs {
+            return (result, Timings::new(samples));
+        }
+    }
+}
+
+//  Parses the input and then runs each requested part against it, timing
+//  every step over the given number of runs. The parts only read the parsed
+//  input, so they can run side by side.
+pub fn solve_parts<S: Solution>(
+    input: &str,
+    parts: &[Part],
+    runs: usize,
+    jobs: usize,
+) -> aoc_common::Result<Solved>
+where
+    S::Input: Sync,
+{
+    let (parsed, parse) = time_runs(runs, || S::parse(input));
+    let parsed = parsed?;
+
+    let parts: Vec<Part> = parts
+        .iter()
+        .copied()
+        .filter(|&part| part == Part::A || S::HAS_PART2)
+        .collect();
+
+    let answers = pool::map(&parts, jobs, |&part| {
+        let (result, timings) = time_runs(runs, || match part {
+            Part::A => S::part1(&parsed).map(|answer| answer.to_string()),
+            Part::B => S::part2(&parsed).map(|answer| answer.to_string()),
+        });
+
+        Answer {
+            part,
+            result,
+            timings,
+        }
+    });
+
+    Ok(Solved { parse, answers })
+}
+
+//  Parses the input and runs one part with the tracer watching each step.
+pub fn trace_part<S: Traced>(
+    input: &str,
+    part: Part,
+    tracer: &mut dyn Tracer,
+) -> aoc_common::Result<String> {
+    let parsed = S::parse(input)?;
+    match part {
+        Part::A => S::trace_part1(&parsed, tracer).map(|answer| answer.to_string()),
+        Part::B => S::trace_part2(&parsed, tracer).map(|answer| answer.to_string()),
+    }
+}
+
+//  The manifest is optional; without it every answer is reported as unknown.
+fn load_answers(path: &Path) -> Result<Answers> {
+    if !path.exists() {
+        return Ok(Answers::default());
+    }
+
+    Answers::parse(&read_file(path)?)
+        .map_err(|e| invalid_input(format!("{}: {}", path.display(), e)))
+}
+
+fn input_path(year: &str, day: usize) -> PathBuf {
+    Path::new("inputs")
+        .join(year)
+        .join(format!("day{:02}.txt", day))
+}
+
+const ANSWERS_PATH: &str = "answers.txt";
+
+fn usage() -> Error {
+    invalid_input(String::from(
+        "usage: aoc [--bench <runs>] [--jobs <count>] [--format text | json] [--input <path> | -] <year> [<day> | <first>-<last> | all] [a | b]\n       aoc parity [--bench <runs>] [--fsharp <path>] [--input <path> | -] <year> [<day> | <first>-<last> | all]\n       aoc submit <year> <day> <a | b>\n       aoc new <year> <day>\n       aoc policy [--input <path> | -] <spec>\n       aoc policy [--input <path> | -] summary [<spec> ...]\n       aoc trace [--format text | json | gif | png] [--output <path>] [--steps <count>] [--input <path> | -] <year> <day> <a | b>",
+    ))
+}
+
+//  Inputs are cached under inputs/<year>/dayNN.txt, and downloaded from the
+//  site the first time a day is run, unless another input is given.
+fn load_input(options: &Options, site: &Site, year: &str, day: usize) -> Result<String> {
+    match &options.input {
+        Some(path) => read_input(path),
+        None => cache::load(&input_path(year, day), || site.input(year, day)),
+    }
+}
+
+fn solver(year: &str) -> Result<Solver> {
+    match year {
+        "2020" => Ok(year2020::solve),
+        _ => Err(invalid_input(format!("no solutions for year {}", year))),
+    }
+}
+
+fn trace_solver(year: &str) -> Result<TraceSolver> {
+    match year {
+        "2020" => Ok(year2020::trace),
+        _ => Err(invalid_input(format!("no solutions for year {}", year))),
+    }
+}
+
+//  Days run side by side on every core by default, but one at a time when
+//  benchmarking so that they don't slow each other down.
+fn jobs(options: &Options) -> usize {
+    match (options.jobs, options.bench) {
+        (Some(jobs), _) => jobs,
+        (None, Some(_)) => 1,
+        (None, None) => thread::available_parallelism().map_or(1, |n| n.get()),
+    }
+}
+
+//  Each day solves its parts on its share of the jobs, so that days run side
+//  by side never use more threads than were asked for between them.
+fn jobs_per_day(jobs: usize, days: usize) -> usize {
+    (jobs / days.max(1)).max(1)
+}
+
+//  An explicit input only makes sense for one day at a time.
+fn check_single_day(options: &Options, days: &[usize]) -> Result<()> {
+    if options.input.is_some() && days.len() != 1 {
+        return Err(invalid_input(String::from(
+            "--input can only be used with a single day",
+        )));
+    }
+
+    Ok(())
+}
+
+//  Runs the Rust and F# solutions for each day on the same input, and fails
+//  if any of their answers differ.
+fn parity(options: &Options, args: &[&str]) -> Result<()> {
+    let year = *args.first().ok_or_else(usage)?;
+    let days = parse_days(args.get(1).copied().unwrap_or("all"), 25)?;
+    let solve = solver(year)?;
+    let runs = options.bench.unwrap_or(1);
+    check_single_day(options, &days)?;
+
+    let root = options
+        .fsharp
+        .clone()
+        .unwrap_or_else(|| PathBuf::from(parity::FSHARP_PATH));
+    if !root.join(year).is_dir() {
+        return Err(invalid_input(format!(
+            "no F# solutions for year {} in {}",
+            year,
+            root.display()
+        )));
+    }
+
+    let site = Site::from_env();
+    let results: Vec<DayParity> = days
+        .into_iter()
+        .map(|day| {
+            let input = load_input(options, &site, year, day);
+            DayParity {
+                day,
+                rust: match &input {
+                    Ok(input) => solve(day, input, &[Part::A, Part::B], runs, 1),
+                    Err(e) => Err(aoc_common::Error::from(Error::new(e.kind(), e.to_string()))),
+                },
+                fsharp: input.and_then(|input| parity::run_fsharp(&root, year, day, &input)),
+            }
+        })
+        .collect();
+
+    for line in parity::text(&results) {
+        println!("{}", line);
+    }
+
+    let differences = parity::differences(&results);
+    if differences > 0 {
+        return Err(Error::other(format!(
+            "{} answers differed between Rust and F#",
+            differences
+        )));
+    }
+
+    Ok(())
+}
+
+//  Solves one part of a day, sends the answer to the site, and records it in
+//  the answers manifest if the site says it is right.
+fn submit(options: &Options, args: &[&str]) -> Result<()> {
+    if args.len() != 3 {
+        return Err(usage());
+    }
+    if options.input.is_some() {
+        return Err(invalid_input(String::from(
+            "only answers to the cached input can be submitted",
+        )));
+    }
+
+    let year = args[0];
+    let day = parse_day(args[1], 25)?;
+    let part = parse_parts(Some(args[2]))?[0];
+    let solve = solver(year)?;
+    let site = Site::from_env();
+
+    let input = load_input(options, &site, year, day)?;
+    let answer = solve(day, &input, &[part], 1, 1)
+        .map_err(|e| Error::other(format!("Day {}: {}", day, e)))?
+        .answers
+        .pop()
+        .ok_or_else(|| invalid_input(format!("day {} has no part {}", day, part.label())))?
+        .result
+        .map_err(|e| Error::other(format!("Day {}{}: {}", day, part.label(), e)))?;
+
+    let (outcome, message) = site.submit(year, day, part, &answer)?;
+    println!("Day {}{} - {} {}", day, part.label(), answer, message);
+
+    if outcome != Outcome::Correct {
+        return Err(Error::other(format!("{} was not accepted", answer)));
+    }
+
+    let expected = load_answers(Path::new(ANSWERS_PATH))?;
+    if expected.check(year, day, part, &answer) == Verdict::Unknown {
+        let mut manifest = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(ANSWERS_PATH)?;
+        writeln!(
+            manifest,
+            "{} {} {} {}",
+            year,
+            day,
+            part.label().to_ascii_lowercase(),
+            answer
+        )?;
+    }
+
+    Ok(())
+}
+
+//  Runs one part of a simulation day, writing out its state at each step.
+//  The answer goes to standard error to keep it out of the trace.
+fn trace(options: &Options, args: &[&str]) -> Result<()> {
+    if args.len() != 3 {
+        return Err(usage());
+    }
+
+    let year = args[0];
+    let day = parse_day(args[1], 25)?;
+    let part = parse_parts(Some(args[2]))?[0];
+    let trace_solve = trace_solver(year)?;
+    let input = load_input(options, &Site::from_env(), year, day)?;
+
+    let mut recorder =
+        trace::Recorder::new(options.format, options.output.as_deref(), options.steps)?;
+    let answer = trace_solve(day, &input, part, &mut recorder)
+        .map_err(|e| Error::other(format!("Day {}{}: {}", day, part.label(), e)))?;
+
+    let traced = recorder.finish()?;
+    eprintln!(
+        "Day {}{} - {} ({} steps traced)",
+        day,
+        part.label(),
+        answer,
+        traced
+    );
+
+    Ok(())
+}
+
+//  Adds a crate for a new day, run from the workspace root.
+fn new(args: &[&str]) -> Result<()> {
+    if args.len() != 2 {
+        return Err(usage());
+    }
+
+    let year = args[0];
+    let day = parse_day(args[1], 25)?;
+    scaffold::create(Path::new("."), year, day)?;
+    println!("Created {}/day{}", year, day);
+
+    Ok(())
+}
+
+//  Checks the 2020 day 2 passwords against any policy in the registry, such
+//  as "old and forbid:abc" or "regex:^[a-z]+$ or distinct:5", or summarises
+//  the input against several at once.
+fn policy(options: &Options, args: &[&str]) -> Result<()> {
+    let (summary, specs) = match args {
+        ["summary", specs @ ..] => (true, specs),
+        [_] => (false, args),
+        _ => return Err(usage()),
+    };
+    year2020::check_policies(specs).map_err(|e| invalid_input(format!("Day 2 policy: {}", e)))?;
+
+    let input = load_input(options, &Site::from_env(), "2020", 2)?;
+    let lines = if summary {
+        year2020::summarise_passwords(&input, specs)
+    } else {
+        year2020::check_passwords(&input, specs[0])
+    }
+    .map_err(|e| invalid_input(format!("Day 2: {}", e)))?;
+    for line in lines {
+        println!("{}", line);
+    }
+
+    Ok(())
+}
+
+fn run(args: &[String]) -> Result<()> {
+    let (options, args) = parse_options(args)?;
+    match args.first() {
+        Some(&"parity") => return parity(&options, &args[1..]),
+        Some(&"submit") => return submit(&options, &args[1..]),
+        Some(&"trace") => return trace(&options, &args[1..]),
+        Some(&"new") => return new(&args[1..]),
+        Some(&"policy") => return policy(&options, &args[1..]),
+        _ => {}
+    }
+
+    let year = *args.first().ok_or_else(usage)?;
+    let days = parse_days(args.get(1).copied().unwrap_or("all"), 25)?;
+    let parts = parse_parts(args.get(2).copied())?;
+    let runs = options.bench.unwrap_or(1);
+    let jobs = jobs(&options);
+
+    let solve = solver(year)?;
+    check_single_day(&options, &days)?;
+    if let Format::Gif | Format::Png = options.format {
+        return Err(invalid_input(String::from(
+            "images can only be drawn from aoc trace",
+        )));
+    }
+
+    //  The recorded answers belong to the default inputs, so they say nothing
+    //  about a generated or edited one.
+    let expected = match options.input {
+        Some(_) => Answers::default(),
+        None => load_answers(Path::new(ANSWERS_PATH))?,
+    };
+    let site = Site::from_env();
+    let total = Instant::now();
+
+    //  Inputs are fetched one at a time, to go easy on the site, before the
+    //  days are solved together.
+    let inputs: Vec<(usize, Result<String>)> = days
+        .into_iter()
+        .map(|day| (day, load_input(&options, &site, year, day)))
+        .collect();
+
+    let day_jobs = jobs_per_day(jobs, inputs.len());
+    let reports: Vec<DayReport> = pool::map(&inputs, jobs, |(day, input)| DayReport {
+        day: *day,
+        parts: parts.clone(),
+        solved: match input {
+            Ok(input) => solve(*day, input, &parts, runs, day_jobs),
+            Err(e) => Err(aoc_common::Error::from(Error::new(e.kind(), e.to_string()))),
+        },
+    });
+
+    let total = total.elapsed();
+
+    match options.format {
+        Format::Text => {
+            for line in report::text(year, &reports, &expected, options.bench, total) {
+                println!("{}", line);
+            }
+        }
+        Format::Json => println!("{}", report::json(year, &reports, &expected, runs, total)),
+        Format::Gif | Format::Png => unreachable!(),
+    }
+
+    let failures = report::failures(year, &reports, &expected);
+    if failures > 0 {
+        return Err(Error::other(format!(
+            "{} answers did not match {}",
+            failures, ANSWERS_PATH
+        )));
+    }
+
+    Ok(())
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    if let Err(e) = run(&args) {
+        eprintln!("{}", e);
+        process::exit(1);
+    }
+}
@@ -0,0 +1,319 @@
+use crate::{invalid_input, Part, Solved};
+use aoc_common::read_file;
+use std::env;
+use std::fs;
+use std::io::{Error, ErrorKind, Result};
+use std::path::Path;
+use std::process::{self, Command};
+use std::time::{Duration, Instant};
+
+//  Where the F# solutions live, relative to the workspace, one project per
+//  <year>/<day> directory.
+pub const FSHARP_PATH: &str = "../f#";
+
+//  The F# solutions read their input from a fixed file in the working
+//  directory, under one of these names. The rest have it written into the
+//  code, so can't be given another.
+const INPUT_NAMES: [&str; 4] = [
+    "real_data.txt",
+    "data.txt",
+    "input_data.txt",
+    "test_data.txt",
+];
+
+pub struct FSharpRun {
+    pub answers: Vec<(Part, String)>,
+    pub elapsed: Duration,
+}
+
+pub struct DayParity {
+    pub day: usize,
+    pub rust: aoc_common::Result<Solved>,
+    pub fsharp: Result<FSharpRun>,
+}
+
+#[cfg(test)]
+mod parity_tests {
+    use super::*;
+    use crate::bench::Timings;
+    use crate::Answer;
+
+    fn millis(millis: u64) -> Timings {
+        Timings::new(vec![Duration::from_millis(millis)])
+    }
+
+    fn rust_answer(part: Part, result: &str) -> Answer {
+        Answer {
+            part,
+            result: Ok(String::from(result)),
+            timings: millis(1),
+        }
+    }
+
+    fn get_test_results() -> Vec<DayParity> {
+        vec![
+            DayParity {
+                day: 1,
+                rust: Ok(Solved {
+                    parse: millis(2),
+                    answers: vec![rust_answer(Part::A, "100419"), rust_answer(Part::B, "7")],
+                }),
+                fsharp: Ok(FSharpRun {
+                    answers: vec![
+                        (Part::A, String::from("100419")),
+                        (Part::B, String::from("8")),
+                    ],
+                    elapsed: Duration::from_millis(400),
+                }),
+            },
+            DayParity {
+                day: 2,
+                rust: Ok(Solved {
+                    parse: millis(1),
+                    answers: vec![rust_answer(Part::A, "603")],
+                }),
+                fsharp: Err(invalid_input(String::from("dotnet could not be run"))),
+            },
+        ]
+    }
+
+    #[test]
+    fn should_read_answers_from_fsharp_output() {
+        let output = "floors seq [0; 1; 2]\nPart 1 74\nPart 1: 75\nPart 2: \"abc\"\n";
+        assert_eq!(
+            vec![
+                (Part::A, String::from("74")),
+                (Part::B, String::from("abc"))
+            ],
+            parse_output(output)
+        );
+        assert!(parse_output("Hello World from F#!").is_empty());
+    }
+
+    #[test]
+    fn should_find_the_file_a_solution_reads() {
+        assert_eq!(
+            Some("data.txt"),
+            input_name("let input = File.ReadAllLines \"data.txt\"")
+        );
+        assert_eq!(
+            Some("real_data.txt"),
+            input_name("// \"test_data.txt\"\nreadLines \"real_data.txt\"")
+        );
+        assert_eq!(None, input_name("let input = 36000000"));
+    }
+
+    #[test]
+    fn should_compare_answers_and_runtimes() {
+        assert_eq!(
+            vec![
+                "Day 1A - 100419 MATCH",
+                "Day 1B - Rust 7, F# 8 DIFFERENT",
+                "Day 1 - Rust 4ms, F# 400ms (100.0x)",
+                "Day 2 - F# error: dotnet could not be run",
+            ],
+            text(&get_test_results())
+        );
+        assert_eq!(1, differences(&get_test_results()));
+    }
+}
+
+//  The first answer the program prints for each part, from lines like
+//  "Part 1: 42" or "Part 2 \"abc\"".
+pub fn parse_output(output: &str) -> Vec<(Part, String)> {
+    let mut answers: Vec<(Part, String)> = vec![];
+
+    for line in output.lines() {
+        let rest = match line.trim().strip_prefix("Part ") {
+            Some(rest) => rest,
+            None => continue,
+        };
+
+        let part = match rest.chars().next() {
+            Some('1') => Part::A,
+            Some('2') => Part::B,
+            _ => continue,
+        };
+
+        if answers.iter().all(|(p, _)| *p != part) {
+            let answer = rest[1..].trim_start_matches(':').trim().trim_matches('"');
+            answers.push((part, String::from(answer)));
+        }
+    }
+
+    answers
+}
+
+fn input_name(source: &str) -> Option<&'static str> {
+    INPUT_NAMES
+        .iter()
+        .copied()
+        .find(|name| source.contains(&format!("\"{}\"", name)))
+}
+
+fn dotnet(directory: &Path, args: &[&str]) -> Result<String> {
+    let output = Command::new("dotnet")
+        .args(args)
+        .current_dir(directory)
+        .output()
+        .map_err(|e| match e.kind() {
+            ErrorKind::NotFound => invalid_input(String::from("dotnet is not installed")),
+            _ => e,
+        })?;
+
+    if !output.status.success() {
+        return Err(Error::other(format!(
+            "dotnet {} failed: {}",
+            args[0],
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+//  Builds and runs one F# solution on the given input. It runs in a scratch
+//  directory so that the input files kept beside the project are left alone,
+//  and only the run is timed, though that still includes starting .NET.
+pub fn run_fsharp(root: &Path, year: &str, day: usize, input: &str) -> Result<FSharpRun> {
+    let project = root.join(year).join(day.to_string());
+    let name = input_name(&read_file(&project.join("Program.fs"))?).ok_or_else(|| {
+        invalid_input(format!(
+            "{} has its input written into the code",
+            project.display()
+        ))
+    })?;
+    let project_file = fs::canonicalize(project.join(format!("{}.fsproj", day)))?;
+    let project_file = project_file.to_string_lossy();
+
+    let scratch = env::temp_dir().join(format!("aoc-parity-{}-{}-{}", year, day, process::id()));
+    fs::create_dir_all(&scratch)?;
+
+    let run = || {
+        fs::write(scratch.join(name), input)?;
+        dotnet(&scratch, &["build", "-c", "Release", &project_file])?;
+
+        let start = Instant::now();
+        let output = dotnet(
+            &scratch,
+            &[
+                "run",
+                "--no-build",
+                "-c",
+                "Release",
+                "--project",
+                &project_file,
+            ],
+        )?;
+
+        Ok(FSharpRun {
+            answers: parse_output(&output),
+            elapsed: start.elapsed(),
+        })
+    };
+
+    let result = run();
+    let _ = fs::remove_dir_all(&scratch);
+    result
+}
+
+fn rust_answers(solved: &Solved) -> Vec<(Part, String)> {
+    solved
+        .answers
+        .iter()
+        .map(|answer| {
+            let result = match &answer.result {
+                Ok(result) => result.clone(),
+                Err(e) => format!("error: {}", e),
+            };
+            (answer.part, result)
+        })
+        .collect()
+}
+
+//  Each part either side answered, with the Rust answer and then the F# one.
+fn pairs(rust: &Solved, fsharp: &FSharpRun) -> Vec<(Part, Option<String>, Option<String>)> {
+    let rust = rust_answers(rust);
+    let find = |answers: &[(Part, String)], part: Part| {
+        answers
+            .iter()
+            .find(|(p, _)| *p == part)
+            .map(|(_, answer)| answer.clone())
+    };
+
+    [Part::A, Part::B]
+        .iter()
+        .map(|&part| (part, find(&rust, part), find(&fsharp.answers, part)))
+        .filter(|(_, rust, fsharp)| rust.is_some() || fsharp.is_some())
+        .collect()
+}
+
+fn rust_time(solved: &Solved) -> Duration {
+    solved.parse.median()
+        + solved
+            .answers
+            .iter()
+            .map(|answer| answer.timings.median())
+            .sum::<Duration>()
+}
+
+pub fn differences(results: &[DayParity]) -> usize {
+    results
+        .iter()
+        .filter_map(|result| match (&result.rust, &result.fsharp) {
+            (Ok(rust), Ok(fsharp)) => Some(pairs(rust, fsharp)),
+            _ => None,
+        })
+        .flatten()
+        .filter(|(_, rust, fsharp)| matches!((rust, fsharp), (Some(r), Some(f)) if r != f))
+        .count()
+}
+
+pub fn text(results: &[DayParity]) -> Vec<String> {
+    let mut lines = vec![];
+
+    for result in results {
+        let day = result.day;
+        let (rust, fsharp) = match (&result.rust, &result.fsharp) {
+            (Err(e), _) => {
+                lines.push(format!("Day {} - Rust error: {}", day, e));
+                continue;
+            }
+            (_, Err(e)) => {
+                lines.push(format!("Day {} - F# error: {}", day, e));
+                continue;
+            }
+            (Ok(rust), Ok(fsharp)) => (rust, fsharp),
+        };
+
+        for (part, rust_answer, fsharp_answer) in pairs(rust, fsharp) {
+            let label = part.label();
+            lines.push(match (rust_answer, fsharp_answer) {
+                (Some(r), Some(f)) if r == f => format!("Day {}{} - {} MATCH", day, label, r),
+                (Some(r), Some(f)) => {
+                    format!("Day {}{} - Rust {}, F# {} DIFFERENT", day, label, r, f)
+                }
+                (Some(r), None) => format!("Day {}{} - Rust {}, F# none UNCHECKED", day, label, r),
+                (None, f) => format!(
+                    "Day {}{} - Rust none, F# {} UNCHECKED",
+                    day,
+                    label,
+                    f.unwrap_or_default()
+                ),
+            });
+        }
+
+        let rust_time = rust_time(rust);
+        let ratio =
+            fsharp.elapsed.as_secs_f64() / rust_time.max(Duration::from_micros(1)).as_secs_f64();
+        lines.push(format!(
+            "Day {} - Rust {}ms, F# {}ms ({:.1}x)",
+            day,
+            rust_time.as_millis(),
+            fsharp.elapsed.as_millis(),
+            ratio
+        ));
+    }
+
+    lines
+}
@@ -0,0 +1,78 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+#[cfg(test)]
+mod pool_tests {
+    use super::*;
+    use std::sync::Barrier;
+    use std::time::Duration;
+
+    #[test]
+    fn should_keep_results_in_order() {
+        let items: Vec<u64> = (0..20).collect();
+        let results = map(&items, 4, |&n| {
+            thread::sleep(Duration::from_millis(20 - n));
+            n * n
+        });
+
+        assert_eq!(items.iter().map(|n| n * n).collect::<Vec<u64>>(), results);
+    }
+
+    //  No worker can finish its item until all four are waiting at the
+    //  barrier, so the items must be on four different threads.
+    #[test]
+    fn should_use_every_worker() {
+        let barrier = Barrier::new(4);
+        let threads = Mutex::new(vec![]);
+        map(&[0; 4], 4, |_| {
+            threads.lock().unwrap().push(thread::current().id());
+            barrier.wait();
+        });
+
+        let mut threads = threads.into_inner().unwrap();
+        threads.sort_by_key(|id| format!("{:?}", id));
+        threads.dedup();
+        assert_eq!(4, threads.len());
+    }
+
+    #[test]
+    fn should_run_in_place_with_one_job() {
+        let caller = thread::current().id();
+        assert_eq!(
+            vec![true, true],
+            map(&[1, 2], 1, |_| thread::current().id() == caller)
+        );
+    }
+}
+
+//  Runs a task for each item on up to `jobs` threads, each taking the next
+//  item as soon as it is free, and returns the results in the order of the
+//  items whatever order they finish in.
+pub fn map<T: Sync, R: Send>(items: &[T], jobs: usize, task: impl Fn(&T) -> R + Sync) -> Vec<R> {
+    if jobs <= 1 || items.len() <= 1 {
+        return items.iter().map(task).collect();
+    }
+
+    let next = AtomicUsize::new(0);
+    let results: Vec<Mutex<Option<R>>> = items.iter().map(|_| Mutex::new(None)).collect();
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.min(items.len()) {
+            scope.spawn(|| loop {
+                let i = next.fetch_add(1, Ordering::Relaxed);
+                if i >= items.len() {
+                    break;
+                }
+
+                let result = task(&items[i]);
+                *results[i].lock().unwrap() = Some(result);
+            });
+        }
+    });
+
+    results
+        .into_iter()
+        .map(|result| result.into_inner().unwrap().unwrap())
+        .collect()
+}
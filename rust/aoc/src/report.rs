@@ -0,0 +1,355 @@
+use crate::answers::{Answers, Verdict};
+use crate::bench::{self, Row, Timings};
+use crate::{Answer, Part, Solved};
+use serde_json::{json, Value};
+use std::time::Duration;
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum Format {
+    Text,
+    Json,
+    //  Images are only drawn from a trace.
+    Gif,
+    Png,
+}
+
+pub struct DayReport {
+    pub day: usize,
+    pub parts: Vec<Part>,
+    pub solved: aoc_common::Result<Solved>,
+}
+
+#[cfg(test)]
+mod report_tests {
+    use super::*;
+    use aoc_common::Error;
+
+    fn timings(micros: u64) -> Timings {
+        Timings::new(vec![Duration::from_micros(micros)])
+    }
+
+    fn get_test_reports() -> Vec<DayReport> {
+        vec![
+            DayReport {
+                day: 1,
+                parts: vec![Part::A, Part::B],
+                solved: Ok(Solved {
+                    parse: timings(5),
+                    answers: vec![
+                        Answer {
+                            part: Part::A,
+                            result: Ok(String::from("100419")),
+                            timings: timings(10),
+                        },
+                        Answer {
+                            part: Part::B,
+                            result: Err(Error::no_solution("no three entries sum to 2020")),
+                            timings: timings(20),
+                        },
+                    ],
+                }),
+            },
+            DayReport {
+                day: 2,
+                parts: vec![Part::A, Part::B],
+                solved: Err(Error::parse(1, 1, "bad line")),
+            },
+        ]
+    }
+
+    #[test]
+    fn should_count_failed_answers() {
+        let expected = Answers::parse("2020 1 a 5").unwrap();
+        assert_eq!(1, failures("2020", &get_test_reports(), &expected));
+        assert_eq!(
+            0,
+            failures("2020", &get_test_reports(), &Answers::default())
+        );
+    }
+
+    #[test]
+    fn should_count_errors_with_recorded_answers_as_failures() {
+        let expected = Answers::parse("2020 1 b 5\n2020 2 a 6\n2020 2 b 7").unwrap();
+        assert_eq!(3, failures("2020", &get_test_reports(), &expected));
+
+        assert_eq!(
+            vec![
+                "Day 1A - 100419 (0ms) UNKNOWN",
+                "Day 1B - error: no solution: no three entries sum to 2020 FAIL (expected 5)",
+                "Day 2 - error: line 1, column 1: bad line FAIL",
+                "Total (1ms)",
+            ],
+            text(
+                "2020",
+                &get_test_reports(),
+                &expected,
+                None,
+                Duration::from_millis(1)
+            )
+        );
+    }
+
+    #[test]
+    fn should_write_results_as_text() {
+        let expected = Answers::parse("2020 1 a 100419").unwrap();
+
+        assert_eq!(
+            vec![
+                "Day 1A - 100419 (0ms) PASS",
+                "Day 1B - error: no solution: no three entries sum to 2020",
+                "Day 2 - error: line 1, column 1: bad line",
+                "Total (1ms)",
+            ],
+            text(
+                "2020",
+                &get_test_reports(),
+                &expected,
+                None,
+                Duration::from_millis(1)
+            )
+        );
+    }
+
+    #[test]
+    fn should_write_results_as_json() {
+        let expected = Answers::parse("2020 1 a 1").unwrap();
+        let report = json(
+            "2020",
+            &get_test_reports(),
+            &expected,
+            1,
+            Duration::from_millis(1),
+        );
+
+        assert_eq!(
+            json!({
+                "year": "2020",
+                "runs": 1,
+                "total_us": 1000,
+                "days": [
+                    {
+                        "day": 1,
+                        "error": null,
+                        "verdict": null,
+                        "parse": { "min_us": 5, "median_us": 5, "max_us": 5 },
+                        "parts": [
+                            {
+                                "part": "A",
+                                "answer": "100419",
+                                "error": null,
+                                "verdict": "FAIL",
+                                "expected": "1",
+                                "timings": { "min_us": 10, "median_us": 10, "max_us": 10 }
+                            },
+                            {
+                                "part": "B",
+                                "answer": null,
+                                "error": "no solution: no three entries sum to 2020",
+                                "verdict": null,
+                                "expected": null,
+                                "timings": { "min_us": 20, "median_us": 20, "max_us": 20 }
+                            }
+                        ]
+                    },
+                    {
+                        "day": 2,
+                        "error": "line 1, column 1: bad line",
+                        "verdict": null,
+                        "parse": null,
+                        "parts": []
+                    }
+                ]
+            }),
+            report
+        );
+    }
+}
+
+fn verdict(year: &str, day: usize, answer: &Answer, expected: &Answers) -> Verdict {
+    match &answer.result {
+        Ok(result) => expected.check(year, day, answer.part, result),
+        Err(_) => expected.check_missing(year, day, answer.part),
+    }
+}
+
+//  A day that could not be solved at all fails each part asked of it that
+//  has a recorded answer.
+fn missing(year: &str, report: &DayReport, expected: &Answers) -> usize {
+    report
+        .parts
+        .iter()
+        .filter(|&&part| {
+            matches!(
+                expected.check_missing(year, report.day, part),
+                Verdict::Fail(_)
+            )
+        })
+        .count()
+}
+
+pub fn failures(year: &str, reports: &[DayReport], expected: &Answers) -> usize {
+    reports
+        .iter()
+        .map(|report| match &report.solved {
+            Ok(solved) => solved
+                .answers
+                .iter()
+                .filter(|answer| {
+                    matches!(
+                        verdict(year, report.day, answer, expected),
+                        Verdict::Fail(_)
+                    )
+                })
+                .count(),
+            Err(_) => missing(year, report, expected),
+        })
+        .sum()
+}
+
+pub fn text(
+    year: &str,
+    reports: &[DayReport],
+    expected: &Answers,
+    bench: Option<usize>,
+    total: Duration,
+) -> Vec<String> {
+    let mut lines = vec![];
+    let mut rows = vec![];
+
+    for report in reports {
+        let day = report.day;
+        let solved = match &report.solved {
+            Ok(solved) => solved,
+            Err(e) => {
+                lines.push(match missing(year, report, expected) {
+                    0 => format!("Day {} - error: {}", day, e),
+                    _ => format!("Day {} - error: {} FAIL", day, e),
+                });
+                continue;
+            }
+        };
+
+        rows.push(Row {
+            day,
+            step: String::from("parse"),
+            timings: solved.parse.clone(),
+        });
+
+        for answer in &solved.answers {
+            lines.push(match &answer.result {
+                Ok(result) => format!(
+                    "Day {}{} - {} ({}ms) {}",
+                    day,
+                    answer.part.label(),
+                    result,
+                    answer.timings.median().as_millis(),
+                    expected.check(year, day, answer.part, result)
+                ),
+                Err(e) => match verdict(year, day, answer, expected) {
+                    failed @ Verdict::Fail(_) => {
+                        format!(
+                            "Day {}{} - error: {} {}",
+                            day,
+                            answer.part.label(),
+                            e,
+                            failed
+                        )
+                    }
+                    _ => format!("Day {}{} - error: {}", day, answer.part.label(), e),
+                },
+            });
+
+            rows.push(Row {
+                day,
+                step: answer.part.label().to_string(),
+                timings: answer.timings.clone(),
+            });
+        }
+    }
+
+    lines.push(format!("Total ({}ms)", total.as_millis()));
+
+    if let Some(runs) = bench {
+        lines.push(String::new());
+        lines.push(format!("Timings over {} runs", runs));
+        lines.extend(bench::summary(&rows));
+    }
+
+    lines
+}
+
+fn timings_json(timings: &Timings) -> Value {
+    json!({
+        "min_us": timings.min().as_micros() as u64,
+        "median_us": timings.median().as_micros() as u64,
+        "max_us": timings.max().as_micros() as u64,
+    })
+}
+
+pub fn json(
+    year: &str,
+    reports: &[DayReport],
+    expected: &Answers,
+    runs: usize,
+    total: Duration,
+) -> Value {
+    let days: Vec<Value> = reports
+        .iter()
+        .map(|report| match &report.solved {
+            Err(e) => json!({
+                "day": report.day,
+                "error": e.to_string(),
+                "verdict": match missing(year, report, expected) {
+                    0 => None,
+                    _ => Some("FAIL"),
+                },
+                "parse": null,
+                "parts": [],
+            }),
+            Ok(solved) => {
+                let parts: Vec<Value> = solved
+                    .answers
+                    .iter()
+                    .map(|answer| {
+                        let verdict = verdict(year, report.day, answer, expected);
+                        let (result, error, verdict) = match &answer.result {
+                            Ok(result) => (Some(result), None, Some(verdict)),
+                            Err(e) => (
+                                None,
+                                Some(e.to_string()),
+                                Some(verdict).filter(|v| matches!(v, Verdict::Fail(_))),
+                            ),
+                        };
+
+                        json!({
+                            "part": answer.part.label().to_string(),
+                            "answer": result,
+                            "error": error,
+                            "verdict": verdict.as_ref().map(Verdict::status),
+                            "expected": match verdict {
+                                Some(Verdict::Fail(expected)) => Some(expected),
+                                _ => None,
+                            },
+                            "timings": timings_json(&answer.timings),
+                        })
+                    })
+                    .collect();
+
+                json!({
+                    "day": report.day,
+                    "error": null,
+                    "verdict": null,
+                    "parse": timings_json(&solved.parse),
+                    "parts": parts,
+                })
+            }
+        })
+        .collect();
+
+    json!({
+        "year": year,
+        "runs": runs,
+        "total_us": total.as_micros() as u64,
+        "days": days,
+    })
+}
@@ -0,0 +1,408 @@
+use crate::{input_path, invalid_input, ANSWERS_PATH};
+use aoc_common::read_file;
+use std::fs::{self, OpenOptions};
+use std::io::{Result, Write};
+use std::path::Path;
+
+const MANIFEST_TEMPLATE: &str = r#"[package]
+name = "{package}"
+version = "0.1.0"
+authors = ["Ben Morton <ben.morton91@gmail.com>"]
+edition = "2018"
+
+# See more keys and their definitions at https://doc.rust-lang.org/cargo/reference/manifest.html
+
+[dependencies]
+aoc-common = { path = "../../common" }
+
+[lints]
+workspace = true
+"#;
+
+const SOLUTION_TEMPLATE: &str = r#"use aoc_common::input::lines;
+use aoc_common::{Error, Result, Solution};
+
+#[cfg(test)]
+mod day{day}_tests {
+    use super::*;
+
+    static TEST_INPUT: &str = "";
+
+    static TEST_ANSWER_A: usize = 0;
+
+    static TEST_ANSWER_B: usize = 0;
+
+    #[test]
+    #[ignore = "needs the example from the puzzle"]
+    fn day{day}a_test() {
+        let input = parse_input(TEST_INPUT).unwrap();
+        assert_eq!(TEST_ANSWER_A, day{day}a(&input).unwrap());
+    }
+
+    #[test]
+    #[ignore = "needs the example from the puzzle"]
+    fn day{day}b_test() {
+        let input = parse_input(TEST_INPUT).unwrap();
+        assert_eq!(TEST_ANSWER_B, day{day}b(&input).unwrap());
+    }
+}
+
+pub fn parse_input(input: &str) -> Result<Vec<String>> {
+    Ok(lines(input).map(String::from).collect())
+}
+
+pub fn day{day}a(_input: &[String]) -> Result<usize> {
+    Err(Error::no_solution("part one has not been solved"))
+}
+
+pub fn day{day}b(_input: &[String]) -> Result<usize> {
+    Err(Error::no_solution("part two has not been solved"))
+}
+
+pub struct Day{day};
+
+impl Solution for Day{day} {
+    type Input = Vec<String>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<String>> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Vec<String>) -> Result<usize> {
+        day{day}a(input)
+    }
+
+    fn part2(input: &Vec<String>) -> Result<usize> {
+        day{day}b(input)
+    }
+}
+"#;
+
+const YEAR_TEMPLATE: &str = r#"use crate::{solve_parts, Part, Solved};
+use aoc_common::trace::Tracer;
+use aoc_common::{Error, Result};
+
+pub fn solve(day: usize, input: &str, parts: &[Part], runs: usize, jobs: usize) -> Result<Solved> {
+    match day {
+        _ => Err(Error::no_solution(format!(
+            "day {} has not been solved",
+            day
+        ))),
+    }
+}
+
+pub fn trace(day: usize, _input: &str, _part: Part, _tracer: &mut dyn Tracer) -> Result<String> {
+    Err(Error::no_solution(format!("day {} cannot be traced", day)))
+}
+"#;
+
+#[cfg(test)]
+mod scaffold_tests {
+    use super::*;
+    use std::env;
+    use std::process;
+
+    #[test]
+    fn should_name_packages_by_year() {
+        assert_eq!("day7", package_name("2020", 7));
+        assert_eq!("year2021-day7", package_name("2021", 7));
+        assert_eq!("year2021_day7", crate_name("2021", 7));
+    }
+
+    #[test]
+    fn should_add_a_workspace_member() {
+        assert_eq!(
+            "[workspace]\nmembers = [\n    \"aoc\",\n    \"2021/day1\",\n]\n\n[workspace.lints]\n",
+            add_member(
+                "[workspace]\nmembers = [\n    \"aoc\",\n]\n\n[workspace.lints]\n",
+                "2021/day1"
+            )
+            .unwrap()
+        );
+        assert!(add_member("[package]\n", "2021/day1").is_err());
+    }
+
+    #[test]
+    fn should_add_a_dependency_at_the_end_of_the_list() {
+        assert_eq!(
+            "[dependencies]\nday1 = \"1\"\nday2 = \"1\"\n\n[dev-dependencies]\n",
+            add_dependency(
+                "[dependencies]\nday1 = \"1\"\n\n[dev-dependencies]\n",
+                "day2 = \"1\""
+            )
+            .unwrap()
+        );
+        assert_eq!(
+            "[dependencies]\nday1 = \"1\"\nday2 = \"1\"\n",
+            add_dependency("[dependencies]\nday1 = \"1\"\n", "day2 = \"1\"").unwrap()
+        );
+    }
+
+    #[test]
+    fn should_register_a_new_year_with_the_runner() {
+        let main = "mod site;\nmod year2020;\n\nfn solver(year: &str) -> Result<Solver> {\n    match year {\n        \"2020\" => Ok(year2020::solve),\n        _ => Err(()),\n    }\n}\n\nfn trace_solver(year: &str) -> Result<TraceSolver> {\n    match year {\n        \"2020\" => Ok(year2020::trace),\n        _ => Err(()),\n    }\n}\n";
+
+        let main = add_year(main, "2021").unwrap();
+        assert!(main.contains("mod year2020;\nmod year2021;\n"));
+        assert!(main.contains(
+            "        \"2020\" => Ok(year2020::solve),\n        \"2021\" => Ok(year2021::solve),\n"
+        ));
+        assert!(main.contains(
+            "        \"2020\" => Ok(year2020::trace),\n        \"2021\" => Ok(year2021::trace),\n"
+        ));
+    }
+
+    #[test]
+    fn should_register_a_day_with_its_year() {
+        let module = add_day(YEAR_TEMPLATE, "2021", 3).unwrap();
+        assert!(module.contains(
+            "    match day {\n        3 => solve_parts::<year2021_day3::Day3>(input, parts, runs, jobs),\n        _ => Err("
+        ));
+    }
+
+    #[test]
+    fn should_create_a_day_in_a_workspace() {
+        let root = env::temp_dir().join(format!("aoc-scaffold-{}", process::id()));
+        fs::create_dir_all(root.join("aoc").join("src")).unwrap();
+        fs::write(
+            root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\n    \"aoc\",\n]\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("aoc").join("Cargo.toml"),
+            "[package]\nname = \"aoc\"\n\n[dependencies]\n",
+        )
+        .unwrap();
+        fs::write(
+            root.join("aoc").join("src").join("main.rs"),
+            "mod site;\n\nfn solver(year: &str) -> Result<Solver> {\n    match year {\n        _ => Err(()),\n    }\n}\n\nfn trace_solver(year: &str) -> Result<TraceSolver> {\n    match year {\n        _ => Err(()),\n    }\n}\n",
+        )
+        .unwrap();
+
+        create(&root, "2021", 1).unwrap();
+
+        let lib = read_file(&root.join("2021").join("day1").join("src").join("lib.rs")).unwrap();
+        assert!(lib.contains("mod day1_tests {"));
+        assert!(lib.contains("impl Solution for Day1 {"));
+        assert!(read_file(&root.join("aoc").join("Cargo.toml"))
+            .unwrap()
+            .contains("year2021_day1 = { package = \"year2021-day1\", path = \"../2021/day1\" }"));
+        assert!(read_file(&root.join("aoc").join("src").join("year2021.rs"))
+            .unwrap()
+            .contains("1 => solve_parts::<year2021_day1::Day1>"));
+        assert_eq!(
+            "",
+            read_file(&root.join("inputs").join("2021").join("day01.txt")).unwrap()
+        );
+        assert_eq!(
+            "# 2021 1 a ?\n# 2021 1 b ?\n",
+            read_file(&root.join(ANSWERS_PATH)).unwrap()
+        );
+
+        assert!(create(&root, "2021", 1)
+            .unwrap_err()
+            .to_string()
+            .contains("already exists"));
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
+
+//  The 2020 days were named before there was more than one year.
+fn package_name(year: &str, day: usize) -> String {
+    match year {
+        "2020" => format!("day{}", day),
+        _ => format!("year{}-day{}", year, day),
+    }
+}
+
+fn crate_name(year: &str, day: usize) -> String {
+    package_name(year, day).replace('-', "_")
+}
+
+fn add_member(manifest: &str, member: &str) -> Result<String> {
+    let start = manifest
+        .find("members = [")
+        .ok_or_else(|| invalid_input(String::from("the workspace has no members")))?;
+    let end = start
+        + manifest[start..]
+            .find("\n]")
+            .ok_or_else(|| invalid_input(String::from("the workspace members are not closed")))?;
+
+    Ok(format!(
+        "{}\n    \"{}\",{}",
+        &manifest[..end],
+        member,
+        &manifest[end..]
+    ))
+}
+
+//  Adds a line to the end of the [dependencies] table, before any blank lines
+//  that separate it from the next table.
+fn add_dependency(manifest: &str, dependency: &str) -> Result<String> {
+    let start = manifest
+        .find("[dependencies]\n")
+        .ok_or_else(|| invalid_input(String::from("the runner has no dependencies")))?;
+    let table = &manifest[start..];
+    let end = start + table[1..].find("\n[").map_or(table.len(), |i| i + 1);
+    let end = manifest[..end].trim_end().len();
+
+    Ok(format!(
+        "{}\n{}{}",
+        &manifest[..end],
+        dependency,
+        &manifest[end..]
+    ))
+}
+
+//  Each new arm goes after the last of its kind, or first in the match.
+fn add_arm(source: &str, after: &str, before: &str, arm: &str) -> Result<String> {
+    let index = match source.rfind(after) {
+        Some(i) => {
+            let i = i + after.len();
+            i + source[i..].find('\n').map_or(source.len() - i, |j| j + 1)
+        }
+        None => source
+            .find(before)
+            .map(|i| source[..i].rfind('\n').map_or(0, |j| j + 1))
+            .ok_or_else(|| invalid_input(format!("nowhere to add '{}'", arm.trim())))?,
+    };
+
+    Ok(format!("{}{}\n{}", &source[..index], arm, &source[index..]))
+}
+
+fn add_year(main: &str, year: &str) -> Result<String> {
+    //  After the last module declaration, which is a line of its own.
+    let (mut index, mut offset) = (0, 0);
+    for line in main.split_inclusive('\n') {
+        offset += line.len();
+        if line.starts_with("mod ") && line.trim_end().ends_with(';') {
+            index = offset;
+        }
+    }
+    let main = format!("{}mod year{};\n{}", &main[..index], year, &main[index..]);
+
+    let main = add_arm(
+        &main,
+        "::solve),",
+        "_ => Err(",
+        &format!("        \"{}\" => Ok(year{}::solve),", year, year),
+    )?;
+    let index = main
+        .find("fn trace_solver")
+        .ok_or_else(|| invalid_input(String::from("the runner has no trace_solver")))?;
+    let traced = add_arm(
+        &main[index..],
+        "::trace),",
+        "_ => Err(",
+        &format!("        \"{}\" => Ok(year{}::trace),", year, year),
+    )?;
+
+    Ok(format!("{}{}", &main[..index], traced))
+}
+
+fn add_day(module: &str, year: &str, day: usize) -> Result<String> {
+    add_arm(
+        module,
+        "(input, parts, runs, jobs),",
+        "        _ => Err(",
+        &format!(
+            "        {} => solve_parts::<{}::Day{}>(input, parts, runs, jobs),",
+            day,
+            crate_name(year, day),
+            day
+        ),
+    )
+}
+
+fn append(path: &Path, text: &str) -> Result<()> {
+    OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)?
+        .write_all(text.as_bytes())
+}
+
+//  Creates a crate for a new day from the templates, registers it with the
+//  workspace and the runner, and leaves placeholders for its input and
+//  answers. Everything is relative to the workspace root.
+pub fn create(root: &Path, year: &str, day: usize) -> Result<()> {
+    if year.len() != 4 || !year.chars().all(|c| c.is_ascii_digit()) {
+        return Err(invalid_input(format!("'{}' is not a year", year)));
+    }
+
+    let directory = root.join(year).join(format!("day{}", day));
+    if directory.exists() {
+        return Err(invalid_input(format!(
+            "{} already exists",
+            directory.display()
+        )));
+    }
+
+    let workspace = root.join("Cargo.toml");
+    let runner = root.join("aoc").join("Cargo.toml");
+    let main = root.join("aoc").join("src").join("main.rs");
+    let module = root
+        .join("aoc")
+        .join("src")
+        .join(format!("year{}.rs", year));
+
+    //  Work out every change before writing any, so that a workspace which
+    //  isn't laid out as expected is left alone.
+    let package = package_name(year, day);
+    let member = format!("{}/day{}", year, day);
+    let workspace_manifest = add_member(&read_file(&workspace)?, &member)?;
+    let runner_manifest = add_dependency(
+        &read_file(&runner)?,
+        &match year {
+            "2020" => format!("{} = {{ path = \"../{}\" }}", package, member),
+            _ => format!(
+                "{} = {{ package = \"{}\", path = \"../{}\" }}",
+                crate_name(year, day),
+                package,
+                member
+            ),
+        },
+    )?;
+    let (main_source, module_source) = if module.exists() {
+        (None, read_file(&module)?)
+    } else {
+        (
+            Some(add_year(&read_file(&main)?, year)?),
+            String::from(YEAR_TEMPLATE),
+        )
+    };
+    let module_source = add_day(&module_source, year, day)?;
+
+    fs::create_dir_all(directory.join("src"))?;
+    fs::write(
+        directory.join("Cargo.toml"),
+        MANIFEST_TEMPLATE.replace("{package}", &package),
+    )?;
+    fs::write(
+        directory.join("src").join("lib.rs"),
+        SOLUTION_TEMPLATE.replace("{day}", &day.to_string()),
+    )?;
+    fs::write(&workspace, workspace_manifest)?;
+    fs::write(&runner, runner_manifest)?;
+    if let Some(main_source) = main_source {
+        fs::write(&main, main_source)?;
+    }
+    fs::write(&module, module_source)?;
+
+    //  An empty input is fetched from the site on the first run, and the
+    //  answers are commented out until they are known.
+    let input = root.join(input_path(year, day));
+    if let Some(inputs) = input.parent() {
+        fs::create_dir_all(inputs)?;
+    }
+    if !input.exists() {
+        fs::write(&input, "")?;
+    }
+    append(
+        &root.join(ANSWERS_PATH),
+        &format!("# {} {} a ?\n# {} {} b ?\n", year, day, year, day),
+    )
+}
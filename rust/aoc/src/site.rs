@@ -0,0 +1,201 @@
+use crate::{invalid_input, Part};
+use std::env;
+use std::io::{Error, Result};
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+//  The site asks automated tools to say who they are.
+const USER_AGENT: &str = concat!(
+    "aoc/",
+    env!("CARGO_PKG_VERSION"),
+    " (",
+    env!("CARGO_PKG_AUTHORS"),
+    ")"
+);
+
+#[derive(Debug, PartialEq)]
+pub enum Outcome {
+    Correct,
+    Wrong,
+    TooSoon,
+    WrongLevel,
+    Unrecognised,
+}
+
+pub struct Site {
+    base_url: String,
+    session: Option<String>,
+}
+
+#[cfg(test)]
+mod site_tests {
+    use super::*;
+    use aoc_mock_site::MockSite;
+
+    fn get_test_site(session: Option<&str>) -> Site {
+        let address = MockSite::new("abc")
+            .puzzle("2020", 1, "1721\n979\n")
+            .answer("2020", 1, 1, "514579")
+            .start()
+            .unwrap();
+
+        Site::new(&format!("http://{}/", address), session)
+    }
+
+    #[test]
+    fn should_fetch_an_input() {
+        let site = get_test_site(Some("abc"));
+        assert_eq!("1721\n979\n", site.input("2020", 1).unwrap());
+        assert!(site
+            .input("2020", 2)
+            .unwrap_err()
+            .to_string()
+            .contains("404"));
+    }
+
+    #[test]
+    fn should_need_a_session_to_fetch_an_input() {
+        let error = get_test_site(None).input("2020", 1).unwrap_err();
+        assert!(error.to_string().contains("AOC_SESSION"));
+
+        let error = get_test_site(Some("xyz")).input("2020", 1).unwrap_err();
+        assert!(error.to_string().contains("Please log in"));
+    }
+
+    #[test]
+    fn should_submit_an_answer() {
+        let site = get_test_site(Some("abc"));
+        let (outcome, message) = site.submit("2020", 1, Part::A, "600000").unwrap();
+        assert_eq!(Outcome::Wrong, outcome);
+        assert_eq!(
+            "That's not the right answer; your answer is too high.",
+            message
+        );
+
+        let (outcome, _) = site.submit("2020", 1, Part::A, "514579").unwrap();
+        assert_eq!(Outcome::Correct, outcome);
+    }
+
+    #[test]
+    fn should_judge_the_site_response() {
+        let page = "<html><main>\n<article><p>You gave an answer too recently; you have <span>37s</span> left to wait.</p></article>\n</main></html>";
+        assert_eq!(
+            (
+                Outcome::TooSoon,
+                String::from("You gave an answer too recently; you have 37s left to wait.")
+            ),
+            judge(page)
+        );
+        assert_eq!(Outcome::Unrecognised, judge("<html></html>").0);
+    }
+}
+
+//  What the site said about a submitted answer, from the article on the page
+//  it sends back.
+pub fn judge(page: &str) -> (Outcome, String) {
+    let article = page
+        .split_once("<article>")
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or("", |(article, _)| article);
+
+    let mut message = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => message.push(c),
+            _ => {}
+        }
+    }
+    let message = String::from(message.trim());
+
+    let outcome = if message.starts_with("That's the right answer") {
+        Outcome::Correct
+    } else if message.starts_with("That's not the right answer") {
+        Outcome::Wrong
+    } else if message.starts_with("You gave an answer too recently") {
+        Outcome::TooSoon
+    } else if message.starts_with("You don't seem to be solving the right level") {
+        Outcome::WrongLevel
+    } else {
+        Outcome::Unrecognised
+    };
+
+    (outcome, message)
+}
+
+fn request_error(url: &str, error: ureq::Error) -> Error {
+    match error {
+        ureq::Error::Status(status, response) => Error::other(format!(
+            "{}: {} {}",
+            url,
+            status,
+            response.into_string().unwrap_or_default().trim()
+        )),
+        error => Error::other(format!("{}: {}", url, error)),
+    }
+}
+
+impl Site {
+    pub fn new(base_url: &str, session: Option<&str>) -> Site {
+        Site {
+            base_url: String::from(base_url.trim_end_matches('/')),
+            session: session.map(String::from),
+        }
+    }
+
+    //  AOC_BASE_URL points the runner at another site, such as aoc-mock-site,
+    //  and AOC_SESSION holds the session cookie of a logged in browser.
+    pub fn from_env() -> Site {
+        let base_url = env::var("AOC_BASE_URL").unwrap_or_else(|_| String::from(BASE_URL));
+        let session = env::var("AOC_SESSION").ok();
+        Site::new(&base_url, session.as_deref().map(str::trim))
+    }
+
+    fn session(&self) -> Result<String> {
+        match &self.session {
+            Some(session) => Ok(format!("session={}", session)),
+            None => Err(invalid_input(String::from(
+                "AOC_SESSION is not set to a session cookie",
+            ))),
+        }
+    }
+
+    fn url(&self, year: &str, day: usize, page: &str) -> String {
+        format!("{}/{}/day/{}/{}", self.base_url, year, day, page)
+    }
+
+    pub fn input(&self, year: &str, day: usize) -> Result<String> {
+        let url = self.url(year, day, "input");
+        ureq::get(&url)
+            .set("User-Agent", USER_AGENT)
+            .set("Cookie", &self.session()?)
+            .call()
+            .map_err(|e| request_error(&url, e))?
+            .into_string()
+    }
+
+    pub fn submit(
+        &self,
+        year: &str,
+        day: usize,
+        part: Part,
+        answer: &str,
+    ) -> Result<(Outcome, String)> {
+        let url = self.url(year, day, "answer");
+        let level = match part {
+            Part::A => "1",
+            Part::B => "2",
+        };
+
+        let page = ureq::post(&url)
+            .set("User-Agent", USER_AGENT)
+            .set("Cookie", &self.session()?)
+            .send_form(&[("level", level), ("answer", answer)])
+            .map_err(|e| request_error(&url, e))?
+            .into_string()?;
+
+        Ok(judge(&page))
+    }
+}
@@ -0,0 +1,374 @@
+use crate::invalid_input;
+use crate::report::Format;
+use aoc_common::trace::{Frame, Tracer, Value};
+use serde_json::{json, Map};
+use std::convert::TryFrom;
+use std::fs::{self, File};
+use std::io::{BufWriter, Error, Result, Write};
+use std::path::{Path, PathBuf};
+
+//  Each cell of a picture is drawn as a square this many pixels wide.
+const SCALE: usize = 4;
+
+//  The colours of ' ', '.', '#', 'L' and anything else, in that order.
+const PALETTE: [u8; 15] = [
+    0xff, 0xff, 0xff, 0xdd, 0xdd, 0xdd, 0x20, 0x20, 0x20, 0x3c, 0xa0, 0x50, 0x90, 0x90, 0x90,
+];
+
+//  Images are only drawn once every frame is in, so without --steps they
+//  stop after this many rather than holding a long simulation in memory.
+pub const IMAGE_STEPS: usize = 1000;
+
+//  Text and JSON Lines are written out as each frame comes in, but the
+//  frames of an image are kept until the end.
+enum Sink<'a> {
+    Lines(Format, Box<dyn Write + 'a>),
+    Images(Format, PathBuf, Vec<Frame>),
+}
+
+//  Records every frame, or only the first few when told to stop early. A
+//  write that fails stops the recording, and is reported by finish.
+pub struct Recorder<'a> {
+    sink: Sink<'a>,
+    recorded: usize,
+    limit: Option<usize>,
+    error: Option<Error>,
+}
+
+#[cfg(test)]
+mod trace_tests {
+    use super::*;
+
+    fn get_test_frames() -> Vec<Frame> {
+        vec![
+            Frame::new(0, "start")
+                .picture(vec![String::from("#."), String::from("L ")])
+                .state("taken", 1),
+            Frame::new(1, "deal")
+                .state("player1", vec![9, 2])
+                .state("ship", (17, -3)),
+        ]
+    }
+
+    #[test]
+    fn should_stop_recording_at_the_limit() {
+        let mut written = vec![];
+        let mut recorder = Recorder::lines(Format::Json, &mut written, Some(2));
+        for step in 0..5 {
+            aoc_common::trace::trace(&mut recorder, || Frame::new(step, "step"));
+        }
+        assert!(!recorder.enabled());
+        assert_eq!(2, recorder.finish().unwrap());
+        assert_eq!(2, String::from_utf8(written).unwrap().lines().count());
+
+        let mut written = vec![];
+        assert!(Recorder::lines(Format::Text, &mut written, None).enabled());
+    }
+
+    #[test]
+    fn should_write_each_frame_as_it_is_recorded() {
+        let mut written = vec![];
+        let mut recorder = Recorder::lines(Format::Text, &mut written, None);
+        for frame in get_test_frames() {
+            recorder.record(frame);
+        }
+        assert_eq!(2, recorder.finish().unwrap());
+        assert_eq!(
+            text(&get_test_frames()[0])
+                .into_iter()
+                .chain(text(&get_test_frames()[1]))
+                .map(|line| line + "\n")
+                .collect::<String>(),
+            String::from_utf8(written).unwrap()
+        );
+    }
+
+    #[test]
+    fn should_cap_the_frames_kept_for_images() {
+        let recorder = Recorder::new(Format::Gif, Some(Path::new("trace.gif")), None).unwrap();
+        assert_eq!(Some(IMAGE_STEPS), recorder.limit);
+        let recorder = Recorder::new(Format::Png, Some(Path::new("trace")), Some(5)).unwrap();
+        assert_eq!(Some(5), recorder.limit);
+        assert!(Recorder::new(Format::Gif, None, None).is_err());
+    }
+
+    #[test]
+    fn should_write_frames_as_text() {
+        assert_eq!(
+            vec![
+                "Step 0: start",
+                "#.",
+                "L ",
+                "",
+                "Step 1: deal",
+                "player1: [9, 2]",
+                "ship: [17, -3]",
+                "",
+            ],
+            get_test_frames()
+                .iter()
+                .flat_map(text)
+                .collect::<Vec<String>>()
+        );
+    }
+
+    #[test]
+    fn should_write_frames_as_json_lines() {
+        assert_eq!(
+            vec![
+                r##"{"caption":"start","picture":["#.","L "],"state":{"taken":1},"step":0}"##,
+                r#"{"caption":"deal","picture":[],"state":{"player1":[9,2],"ship":[17,-3]},"step":1}"#,
+            ],
+            get_test_frames()
+                .iter()
+                .map(json_line)
+                .collect::<Vec<String>>()
+        );
+    }
+
+    #[test]
+    fn should_draw_pictures_with_the_palette() {
+        let (width, height, drawn) = images(&get_test_frames()).unwrap();
+        assert_eq!((2 * SCALE, 2 * SCALE), (width, height));
+        assert_eq!(1, drawn.len());
+        assert_eq!(2, drawn[0][0]);
+        assert_eq!(1, drawn[0][SCALE]);
+        assert_eq!(3, drawn[0][width * SCALE]);
+        assert_eq!(0, drawn[0][width * SCALE + SCALE]);
+
+        assert!(images(&get_test_frames()[1..]).is_err());
+    }
+
+    #[test]
+    fn should_pad_pictures_to_the_largest() {
+        let frames = vec![
+            Frame::new(0, "small").picture(vec![String::from("#")]),
+            Frame::new(1, "large").picture(vec![String::from("##"), String::from("#")]),
+        ];
+        let (width, height, drawn) = images(&frames).unwrap();
+        assert_eq!((2 * SCALE, 2 * SCALE), (width, height));
+        assert_eq!(0, drawn[0][SCALE]);
+        assert_eq!(0, drawn[1][width * SCALE + SCALE]);
+    }
+}
+
+impl<'a> Recorder<'a> {
+    //  Text and JSON Lines go to standard output unless a file is given, but
+    //  the images always need somewhere to go.
+    pub fn new(
+        format: Format,
+        output: Option<&Path>,
+        limit: Option<usize>,
+    ) -> Result<Recorder<'a>> {
+        if let Format::Text | Format::Json = format {
+            let writer: Box<dyn Write> = match output {
+                Some(path) => Box::new(BufWriter::new(File::create(path)?)),
+                None => Box::new(BufWriter::new(std::io::stdout().lock())),
+            };
+            return Ok(Recorder::lines(format, writer, limit));
+        }
+
+        let output = output
+            .ok_or_else(|| invalid_input(String::from("--output is needed to write images")))?;
+        Ok(Recorder {
+            sink: Sink::Images(format, output.to_path_buf(), vec![]),
+            recorded: 0,
+            limit: limit.or(Some(IMAGE_STEPS)),
+            error: None,
+        })
+    }
+
+    pub fn lines(format: Format, writer: impl Write + 'a, limit: Option<usize>) -> Recorder<'a> {
+        Recorder {
+            sink: Sink::Lines(format, Box::new(writer)),
+            recorded: 0,
+            limit,
+            error: None,
+        }
+    }
+
+    //  Writes out whatever is still to be written, and says how many frames
+    //  were recorded.
+    pub fn finish(self) -> Result<usize> {
+        if let Some(e) = self.error {
+            return Err(e);
+        }
+
+        match self.sink {
+            Sink::Lines(_, mut writer) => writer.flush()?,
+            Sink::Images(Format::Gif, path, frames) => gif(&frames, &path)?,
+            Sink::Images(_, path, frames) => png(&frames, &path)?,
+        }
+
+        Ok(self.recorded)
+    }
+}
+
+impl Tracer for Recorder<'_> {
+    fn enabled(&self) -> bool {
+        self.error.is_none() && self.limit.is_none_or(|limit| self.recorded < limit)
+    }
+
+    fn record(&mut self, frame: Frame) {
+        self.recorded += 1;
+        let written = match &mut self.sink {
+            Sink::Lines(Format::Json, writer) => writeln!(writer, "{}", json_line(&frame)),
+            Sink::Lines(_, writer) => text(&frame)
+                .iter()
+                .try_for_each(|line| writeln!(writer, "{}", line)),
+            Sink::Images(_, _, frames) => {
+                frames.push(frame);
+                Ok(())
+            }
+        };
+        self.error = written.err();
+    }
+}
+
+fn value_text(value: &Value) -> String {
+    match value {
+        Value::Number(n) => n.to_string(),
+        Value::Text(text) => text.clone(),
+        Value::List(values) => format!(
+            "[{}]",
+            values.iter().map(value_text).collect::<Vec<_>>().join(", ")
+        ),
+    }
+}
+
+fn value_json(value: &Value) -> serde_json::Value {
+    match value {
+        Value::Number(n) => json!(n),
+        Value::Text(text) => json!(text),
+        Value::List(values) => values.iter().map(value_json).collect(),
+    }
+}
+
+//  The state is only written out for frames without a picture, since the
+//  picture already shows it.
+fn text(frame: &Frame) -> Vec<String> {
+    let mut lines = vec![format!("Step {}: {}", frame.step, frame.caption)];
+
+    if frame.picture.is_empty() {
+        for (name, value) in &frame.state {
+            lines.push(format!("{}: {}", name, value_text(value)));
+        }
+    } else {
+        lines.extend(frame.picture.iter().cloned());
+    }
+    lines.push(String::new());
+
+    lines
+}
+
+fn json_line(frame: &Frame) -> String {
+    let state: Map<String, serde_json::Value> = frame
+        .state
+        .iter()
+        .map(|(name, value)| (name.to_string(), value_json(value)))
+        .collect();
+
+    json!({
+        "step": frame.step,
+        "caption": frame.caption,
+        "picture": frame.picture,
+        "state": state,
+    })
+    .to_string()
+}
+
+fn colour(cell: char) -> u8 {
+    match cell {
+        ' ' => 0,
+        '.' => 1,
+        '#' => 2,
+        'L' => 3,
+        _ => 4,
+    }
+}
+
+//  Draws every frame that has a picture as palette indices, padding them all
+//  to the size of the largest so that they can be played as an animation.
+fn images(frames: &[Frame]) -> Result<(usize, usize, Vec<Vec<u8>>)> {
+    let pictures: Vec<&Vec<String>> = frames
+        .iter()
+        .map(|frame| &frame.picture)
+        .filter(|picture| !picture.is_empty())
+        .collect();
+
+    let columns = pictures
+        .iter()
+        .flat_map(|picture| picture.iter().map(|row| row.chars().count()))
+        .max()
+        .unwrap_or(0);
+    let rows = pictures
+        .iter()
+        .map(|picture| picture.len())
+        .max()
+        .unwrap_or(0);
+    if columns == 0 {
+        return Err(invalid_input(String::from(
+            "the trace has no pictures to draw",
+        )));
+    }
+
+    let (width, height) = (columns * SCALE, rows * SCALE);
+    let images = pictures
+        .into_iter()
+        .map(|picture| {
+            let mut pixels = vec![0; width * height];
+            for (y, row) in picture.iter().enumerate() {
+                for (x, cell) in row.chars().enumerate() {
+                    for dy in 0..SCALE {
+                        let start = (y * SCALE + dy) * width + x * SCALE;
+                        pixels[start..start + SCALE].fill(colour(cell));
+                    }
+                }
+            }
+            pixels
+        })
+        .collect();
+
+    Ok((width, height, images))
+}
+
+fn gif(frames: &[Frame], path: &Path) -> Result<()> {
+    let (width, height, images) = images(frames)?;
+    let too_large = || invalid_input(format!("{}x{} is too large for a GIF", width, height));
+    let width = u16::try_from(width).map_err(|_| too_large())?;
+    let height = u16::try_from(height).map_err(|_| too_large())?;
+
+    let file = BufWriter::new(File::create(path)?);
+    let mut encoder = gif::Encoder::new(file, width, height, &PALETTE).map_err(Error::other)?;
+    encoder
+        .set_repeat(gif::Repeat::Infinite)
+        .map_err(Error::other)?;
+
+    for pixels in images {
+        let mut frame = gif::Frame::from_indexed_pixels(width, height, pixels, None);
+        frame.delay = 10;
+        encoder.write_frame(&frame).map_err(Error::other)?;
+    }
+
+    Ok(())
+}
+
+//  One numbered image per frame, in a directory.
+fn png(frames: &[Frame], directory: &Path) -> Result<()> {
+    let (width, height, images) = images(frames)?;
+    fs::create_dir_all(directory)?;
+
+    for (i, pixels) in images.iter().enumerate() {
+        let file = BufWriter::new(File::create(directory.join(format!("{:05}.png", i)))?);
+        let mut encoder = png::Encoder::new(file, width as u32, height as u32);
+        encoder.set_color(png::ColorType::Indexed);
+        encoder.set_depth(png::BitDepth::Eight);
+        encoder.set_palette(&PALETTE[..]);
+        encoder
+            .write_header()
+            .and_then(|mut writer| writer.write_image_data(pixels))
+            .map_err(Error::other)?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,104 @@
+use crate::{solve_parts, trace_part, Part, Solved};
+use aoc_common::trace::Tracer;
+use aoc_common::{Error, Result};
+
+pub fn solve(day: usize, input: &str, parts: &[Part], runs: usize, jobs: usize) -> Result<Solved> {
+    match day {
+        1 => solve_parts::<day1::Day1>(input, parts, runs, jobs),
+        2 => solve_parts::<day2::Day2>(input, parts, runs, jobs),
+        3 => solve_parts::<day3::Day3>(input, parts, runs, jobs),
+        4 => solve_parts::<day4::Day4>(input, parts, runs, jobs),
+        5 => solve_parts::<day5::Day5>(input, parts, runs, jobs),
+        6 => solve_parts::<day6::Day6>(input, parts, runs, jobs),
+        7 => solve_parts::<day7::Day7>(input, parts, runs, jobs),
+        8 => solve_parts::<day8::Day8>(input, parts, runs, jobs),
+        9 => solve_parts::<day9::Day9>(input, parts, runs, jobs),
+        10 => solve_parts::<day10::Day10>(input, parts, runs, jobs),
+        11 => solve_parts::<day11::Day11>(input, parts, runs, jobs),
+        12 => solve_parts::<day12::Day12>(input, parts, runs, jobs),
+        13 => solve_parts::<day13::Day13>(input, parts, runs, jobs),
+        14 => solve_parts::<day14::Day14>(input, parts, runs, jobs),
+        15 => solve_parts::<day15::Day15>(input, parts, runs, jobs),
+        16 => solve_parts::<day16::Day16>(input, parts, runs, jobs),
+        17 => solve_parts::<day17::Day17>(input, parts, runs, jobs),
+        18 => solve_parts::<day18::Day18>(input, parts, runs, jobs),
+        19 => solve_parts::<day19::Day19>(input, parts, runs, jobs),
+        20 => solve_parts::<day20::Day20>(input, parts, runs, jobs),
+        21 => solve_parts::<day21::Day21>(input, parts, runs, jobs),
+        22 => solve_parts::<day22::Day22>(input, parts, runs, jobs),
+        23 => solve_parts::<day23::Day23>(input, parts, runs, jobs),
+        24 => solve_parts::<day24::Day24>(input, parts, runs, jobs),
+        25 => solve_parts::<day25::Day25>(input, parts, runs, jobs),
+        _ => Err(Error::no_solution(format!(
+            "day {} has not been solved",
+            day
+        ))),
+    }
+}
+
+pub fn trace(day: usize, input: &str, part: Part, tracer: &mut dyn Tracer) -> Result<String> {
+    match day {
+        11 => trace_part::<day11::Day11>(input, part, tracer),
+        12 => trace_part::<day12::Day12>(input, part, tracer),
+        17 => trace_part::<day17::Day17>(input, part, tracer),
+        22 => trace_part::<day22::Day22>(input, part, tracer),
+        23 => trace_part::<day23::Day23>(input, part, tracer),
+        24 => trace_part::<day24::Day24>(input, part, tracer),
+        _ => Err(Error::no_solution(format!("day {} cannot be traced", day))),
+    }
+}
+
+//  Errors in a policy are located within its spec rather than the input, so
+//  the policies are all checked before the input is read.
+pub fn check_policies(specs: &[&str]) -> Result<()> {
+    let registry = day2::Registry::new();
+    for spec in specs {
+        registry.parse(spec)?;
+    }
+
+    Ok(())
+}
+
+//  Checks the day 2 passwords against a policy from the registry, naming the
+//  rule each one breaks, then saying how many meet it.
+pub fn check_passwords(input: &str, spec: &str) -> Result<Vec<String>> {
+    let policy = day2::Registry::new().parse(spec)?;
+    let passwords = day2::parse_input(input)?;
+
+    let mut lines: Vec<String> = day2::failures(&passwords, policy.as_ref())
+        .into_iter()
+        .map(|(i, rule)| format!("{} {}", passwords[i].password, rule))
+        .collect();
+    lines.push(format!(
+        "{} of {} passwords meet '{}'",
+        day2::count_meeting(&passwords, policy.as_ref()),
+        passwords.len(),
+        spec
+    ));
+
+    Ok(lines)
+}
+
+//  Lists the lines of the day 2 input that could not be read, then how many
+//  of the rest meet each policy, which defaults to both of the puzzle's.
+pub fn summarise_passwords(input: &str, specs: &[&str]) -> Result<Vec<String>> {
+    let specs = match specs {
+        [] => &["old", "new"][..],
+        specs => specs,
+    };
+
+    let summary = day2::summarise(input, specs, &day2::Registry::new())?;
+    let mut lines: Vec<String> = summary
+        .malformed
+        .iter()
+        .map(|e| format!("malformed {}", e))
+        .collect();
+    lines.extend(summary.counts.iter().map(|count| {
+        format!(
+            "{}: {} valid, {} invalid",
+            count.spec, count.valid, count.invalid
+        )
+    }));
+
+    Ok(lines)
+}
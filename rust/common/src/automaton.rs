@@ -0,0 +1,605 @@
+use crate::grid::{Grid, Point, SURROUNDING};
+use crate::{Error, Result};
+use std::collections::{HashMap, HashSet};
+use std::hash::{BuildHasherDefault, Hash, Hasher};
+use std::str::FromStr;
+
+//  Axial coordinates, in the order east, north east, north west, west, south
+//  west and south east.
+pub const HEX_DIRECTIONS: [(isize, isize); 6] =
+    [(1, 0), (1, -1), (0, -1), (-1, 0), (-1, 1), (0, 1)];
+
+//  How cells are laid out, and which cells count as each other's neighbours.
+pub trait Topology {
+    type Cell: Copy + Eq + Hash;
+
+    fn neighbours(&self, cell: Self::Cell, visit: impl FnMut(Self::Cell));
+
+    //  Every cell, for a topology small enough to list them. Without this only
+    //  live cells and their neighbours are considered, so a rule that brings
+    //  cells with no live neighbours to life needs it.
+    fn cells(&self) -> Option<&[Self::Cell]> {
+        None
+    }
+}
+
+//  Whether a cell is alive in the next generation, given whether it is alive
+//  now and how many of its neighbours are.
+pub trait Rule {
+    fn next(&self, alive: bool, neighbours: usize) -> bool;
+}
+
+impl<F: Fn(bool, usize) -> bool> Rule for F {
+    fn next(&self, alive: bool, neighbours: usize) -> bool {
+        self(alive, neighbours)
+    }
+}
+
+//  The neighbour counts that bring a dead cell to life, and that keep a live
+//  one alive, as in "B3/S23".
+#[derive(Debug, PartialEq, Clone)]
+pub struct BirthSurvival {
+    birth: Vec<usize>,
+    survival: Vec<usize>,
+}
+
+//  Every cell in any number of dimensions, with the 3^N - 1 cells around it as
+//  neighbours.
+#[derive(Debug, Clone)]
+pub struct Cartesian<const N: usize> {
+    offsets: Vec<[isize; N]>,
+}
+
+//  A plane of hexagons in axial coordinates.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Hex;
+
+//  The cells of a grid that are marked as taking part, with neighbours worked
+//  out once up front.
+#[derive(Debug, Clone)]
+pub struct FiniteGrid {
+    cells: Vec<Point>,
+    neighbours: Grid<Vec<Point>>,
+}
+
+type Cells<C> = HashSet<C, BuildHasherDefault<CellHasher>>;
+
+//  Earlier generations by their fingerprint, each with its number.
+type History<C> = HashMap<u64, Vec<(usize, Cells<C>)>>;
+
+//  Only the live cells are stored, so it works as well for an unbounded space
+//  as for a finite one.
+#[derive(Debug, Clone)]
+pub struct Automaton<T: Topology, R: Rule> {
+    topology: T,
+    rule: R,
+    live: Cells<T::Cell>,
+    generation: usize,
+    detect_cycles: bool,
+}
+
+#[cfg(test)]
+mod automaton_tests {
+    use super::*;
+
+    fn neighbours<T: Topology>(topology: &T, cell: T::Cell) -> Vec<T::Cell> {
+        let mut neighbours = vec![];
+        topology.neighbours(cell, |neighbour| neighbours.push(neighbour));
+        neighbours
+    }
+
+    fn life(cells: &[[isize; 2]]) -> Automaton<Cartesian<2>, BirthSurvival> {
+        Automaton::new(
+            Cartesian::new(),
+            BirthSurvival::new(&[3], &[2, 3]),
+            cells.iter().copied(),
+        )
+    }
+
+    #[test]
+    fn should_parse_a_birth_survival_rule() {
+        assert_eq!(BirthSurvival::new(&[3], &[2, 3]), "B3/S23".parse().unwrap());
+        assert_eq!(BirthSurvival::new(&[], &[0]), "B/S0".parse().unwrap());
+        assert_eq!(
+            "line 1, column 6: unexpected 'x'",
+            "B3/S2x".parse::<BirthSurvival>().unwrap_err().to_string()
+        );
+        assert_eq!(
+            "line 1, column 1: expected 'B' before the birth counts",
+            "3/S23".parse::<BirthSurvival>().unwrap_err().to_string()
+        );
+        assert!("B3S23".parse::<BirthSurvival>().is_err());
+    }
+
+    #[test]
+    fn should_find_cartesian_neighbours() {
+        let cube = neighbours(&Cartesian::<3>::new(), [0, 0, 0]);
+        assert_eq!(26, cube.len());
+        assert!(!cube.contains(&[0, 0, 0]));
+        assert!(cube.contains(&[-1, 1, 0]));
+        assert_eq!(80, neighbours(&Cartesian::<4>::new(), [5, 5, 5, 5]).len());
+    }
+
+    #[test]
+    fn should_find_hex_neighbours() {
+        assert_eq!(
+            vec![(3, 2), (3, 1), (2, 1), (1, 2), (1, 3), (2, 3)],
+            neighbours(&Hex, (2, 2))
+        );
+    }
+
+    #[test]
+    fn should_find_neighbours_on_a_finite_grid() {
+        let cells = Grid::parse("#.#\n...\n#.#", |c| Some(c == '#')).unwrap();
+
+        assert_eq!(
+            Some(&[(0, 0), (2, 0), (0, 2), (2, 2)][..]),
+            FiniteGrid::adjacent(&cells).cells()
+        );
+        assert!(neighbours(&FiniteGrid::adjacent(&cells), (0, 0)).is_empty());
+        assert_eq!(
+            vec![(2, 0), (0, 2), (2, 2)],
+            neighbours(&FiniteGrid::line_of_sight(&cells), (0, 0))
+        );
+        assert!(neighbours(&FiniteGrid::line_of_sight(&cells), (1, 1)).is_empty());
+    }
+
+    #[test]
+    fn should_step_a_generation() {
+        let mut automaton = life(&[[0, 0], [1, 0], [2, 0]]);
+
+        assert!(automaton.step());
+        assert_eq!(1, automaton.generation());
+        assert_eq!(
+            [[1, -1], [1, 0], [1, 1]]
+                .iter()
+                .copied()
+                .collect::<HashSet<[isize; 2]>>(),
+            automaton.live().collect::<HashSet<[isize; 2]>>()
+        );
+    }
+
+    #[test]
+    fn should_run_a_number_of_generations() {
+        let mut glider = life(&[[1, 0], [2, 1], [0, 2], [1, 2], [2, 2]]);
+        glider.run(4);
+
+        assert_eq!(4, glider.generation());
+        assert_eq!(5, glider.population());
+        assert!(glider.is_alive([3, 3]));
+    }
+
+    //  Cells in a circle, each one's only neighbour the next one round.
+    struct Ring(Vec<usize>);
+
+    impl Topology for Ring {
+        type Cell = usize;
+
+        fn neighbours(&self, cell: usize, mut visit: impl FnMut(usize)) {
+            visit((cell + 1) % self.0.len());
+        }
+
+        fn cells(&self) -> Option<&[usize]> {
+            Some(&self.0)
+        }
+    }
+
+    #[test]
+    fn should_run_until_stable() {
+        //  Two cells next to each other die, leaving nothing.
+        assert_eq!(1, life(&[[0, 0], [1, 0]]).run_until_stable(10).unwrap());
+        assert_eq!(
+            0,
+            life(&[[0, 0], [1, 0], [0, 1], [1, 1]])
+                .run_until_stable(10)
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn should_stop_when_a_state_repeats() {
+        assert_eq!(
+            "no solution: generation 2 repeats generation 0, so it never settles",
+            life(&[[0, 0], [1, 0], [2, 0]])
+                .detect_cycles()
+                .run_until_stable(100)
+                .unwrap_err()
+                .to_string()
+        );
+
+        //  A single live cell that moves round a circle of five.
+        let mut ring = Automaton::new(
+            Ring((0..5).collect()),
+            |_: bool, neighbours: usize| neighbours == 1,
+            vec![0],
+        )
+        .detect_cycles();
+        assert!(ring.run_until_stable(100).is_err());
+        assert_eq!(5, ring.generation());
+    }
+
+    #[test]
+    fn should_only_look_for_repeats_when_asked() {
+        let mut blinker = life(&[[0, 0], [1, 0], [2, 0]]);
+
+        assert_eq!(
+            "no solution: still changing after 7 generations",
+            blinker.run_until_stable(7).unwrap_err().to_string()
+        );
+        assert_eq!(7, blinker.generation());
+    }
+
+    #[test]
+    fn should_stop_at_the_generation_limit() {
+        let mut glider = life(&[[1, 0], [2, 1], [0, 2], [1, 2], [2, 2]]);
+
+        assert_eq!(
+            "no solution: still changing after 50 generations",
+            glider.run_until_stable(50).unwrap_err().to_string()
+        );
+        assert_eq!(50, glider.generation());
+    }
+
+    #[test]
+    fn should_show_each_generation_to_a_watcher() {
+        let mut populations = vec![];
+        life(&[[0, 0], [1, 0], [2, 0]]).run_watching(2, |automaton| {
+            populations.push((automaton.generation(), automaton.population()))
+        });
+        assert_eq!(vec![(1, 3), (2, 3)], populations);
+
+        let mut generations = vec![];
+        life(&[[0, 0], [1, 0]])
+            .run_until_stable_watching(10, |automaton| generations.push(automaton.generation()))
+            .unwrap();
+        assert_eq!(vec![1, 2], generations);
+    }
+
+    #[test]
+    fn should_use_a_closure_as_a_rule() {
+        let cells = Grid::from_fn(3, 1, |_| true);
+        let mut automaton = Automaton::new(
+            FiniteGrid::adjacent(&cells),
+            |alive: bool, neighbours: usize| !alive && neighbours == 0,
+            vec![],
+        );
+
+        assert!(automaton.step());
+        assert_eq!(3, automaton.population());
+        assert!(automaton.step());
+        assert_eq!(0, automaton.population());
+    }
+}
+
+//  Cells are small runs of integers, which the default hasher is needlessly
+//  slow for. This mixes in each one with a multiply, as rustc's own hasher does.
+#[derive(Default)]
+struct CellHasher(u64);
+
+impl Hasher for CellHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.write_u64(byte as u64);
+        }
+    }
+
+    fn write_u64(&mut self, n: u64) {
+        self.0 = (self.0.rotate_left(5) ^ n).wrapping_mul(0x51_7c_c1_b7_27_22_0a_95);
+    }
+
+    fn write_usize(&mut self, n: usize) {
+        self.write_u64(n as u64);
+    }
+
+    fn write_isize(&mut self, n: isize) {
+        self.write_u64(n as u64);
+    }
+}
+
+impl BirthSurvival {
+    pub fn new(birth: &[usize], survival: &[usize]) -> BirthSurvival {
+        BirthSurvival {
+            birth: birth.to_vec(),
+            survival: survival.to_vec(),
+        }
+    }
+}
+
+impl Rule for BirthSurvival {
+    fn next(&self, alive: bool, neighbours: usize) -> bool {
+        if alive {
+            self.survival.contains(&neighbours)
+        } else {
+            self.birth.contains(&neighbours)
+        }
+    }
+}
+
+//  Any error is reported relative to `rule`, which `part` is a part of.
+fn parse_counts(rule: &str, part: &str, prefix: char, name: &str) -> Result<Vec<usize>> {
+    let digits = part.strip_prefix(prefix).ok_or_else(|| {
+        Error::parse_at(
+            rule,
+            part,
+            format!("expected '{}' before the {} counts", prefix, name),
+        )
+    })?;
+
+    digits
+        .char_indices()
+        .map(|(i, c)| {
+            c.to_digit(10)
+                .map(|count| count as usize)
+                .ok_or_else(|| Error::parse_at(rule, &digits[i..], format!("unexpected '{}'", c)))
+        })
+        .collect()
+}
+
+impl FromStr for BirthSurvival {
+    type Err = Error;
+
+    fn from_str(rule: &str) -> Result<BirthSurvival> {
+        let (birth, survival) = rule.split_once('/').ok_or_else(|| {
+            Error::parse_at(
+                rule,
+                rule,
+                format!("expected a rule like 'B3/S23', found '{}'", rule),
+            )
+        })?;
+
+        Ok(BirthSurvival {
+            birth: parse_counts(rule, birth, 'B', "birth")?,
+            survival: parse_counts(rule, survival, 'S', "survival")?,
+        })
+    }
+}
+
+impl<const N: usize> Cartesian<N> {
+    pub fn new() -> Cartesian<N> {
+        //  Count through every combination of -1, 0 and 1 in base 3, skipping
+        //  the one that doesn't move.
+        let offsets = (0..3usize.pow(N as u32))
+            .map(|mut n| {
+                let mut offset = [0; N];
+                for axis in offset.iter_mut() {
+                    *axis = (n % 3) as isize - 1;
+                    n /= 3;
+                }
+                offset
+            })
+            .filter(|offset| offset.iter().any(|&axis| axis != 0))
+            .collect();
+
+        Cartesian { offsets }
+    }
+}
+
+impl<const N: usize> Default for Cartesian<N> {
+    fn default() -> Cartesian<N> {
+        Cartesian::new()
+    }
+}
+
+impl<const N: usize> Topology for Cartesian<N> {
+    type Cell = [isize; N];
+
+    fn neighbours(&self, cell: [isize; N], mut visit: impl FnMut([isize; N])) {
+        for offset in &self.offsets {
+            let mut neighbour = cell;
+            for (axis, delta) in neighbour.iter_mut().zip(offset) {
+                *axis += delta;
+            }
+            visit(neighbour);
+        }
+    }
+}
+
+impl Topology for Hex {
+    type Cell = (isize, isize);
+
+    fn neighbours(&self, (q, r): (isize, isize), mut visit: impl FnMut((isize, isize))) {
+        for (dq, dr) in HEX_DIRECTIONS.iter() {
+            visit((q + dq, r + dr));
+        }
+    }
+}
+
+impl FiniteGrid {
+    fn new(cells: &Grid<bool>, mut neighbours: impl FnMut(Point) -> Vec<Point>) -> FiniteGrid {
+        FiniteGrid {
+            cells: cells.points().filter(|&point| cells[point]).collect(),
+            neighbours: Grid::from_fn(cells.width(), cells.height(), |point| {
+                if cells[point] {
+                    neighbours(point)
+                } else {
+                    vec![]
+                }
+            }),
+        }
+    }
+
+    //  Each of the eight surrounding cells that takes part.
+    pub fn adjacent(cells: &Grid<bool>) -> FiniteGrid {
+        FiniteGrid::new(cells, |point| {
+            cells.neighbours8(point).filter(|&p| cells[p]).collect()
+        })
+    }
+
+    //  The first cell that takes part looking out in each of the eight
+    //  directions, however far away it is.
+    pub fn line_of_sight(cells: &Grid<bool>) -> FiniteGrid {
+        FiniteGrid::new(cells, |point| {
+            SURROUNDING
+                .iter()
+                .filter_map(|&direction| cells.ray(point, direction).find(|&p| cells[p]))
+                .collect()
+        })
+    }
+}
+
+impl Topology for FiniteGrid {
+    type Cell = Point;
+
+    fn neighbours(&self, cell: Point, mut visit: impl FnMut(Point)) {
+        for &neighbour in &self.neighbours[cell] {
+            visit(neighbour);
+        }
+    }
+
+    fn cells(&self) -> Option<&[Point]> {
+        Some(&self.cells)
+    }
+}
+
+impl<T: Topology, R: Rule> Automaton<T, R> {
+    pub fn new(topology: T, rule: R, live: impl IntoIterator<Item = T::Cell>) -> Automaton<T, R> {
+        Automaton {
+            topology,
+            rule,
+            live: live.into_iter().collect(),
+            generation: 0,
+            detect_cycles: false,
+        }
+    }
+
+    //  Makes `run_until_stable` fail as soon as the cells come back to a state
+    //  they were in before, at the cost of keeping every generation it runs.
+    pub fn detect_cycles(mut self) -> Automaton<T, R> {
+        self.detect_cycles = true;
+        self
+    }
+
+    pub fn live(&self) -> impl Iterator<Item = T::Cell> + '_ {
+        self.live.iter().copied()
+    }
+
+    pub fn is_alive(&self, cell: T::Cell) -> bool {
+        self.live.contains(&cell)
+    }
+
+    pub fn population(&self) -> usize {
+        self.live.len()
+    }
+
+    pub fn generation(&self) -> usize {
+        self.generation
+    }
+
+    //  Moves on one generation, returning whether any cell changed.
+    pub fn step(&mut self) -> bool {
+        self.advance().1
+    }
+
+    //  As `step`, also handing back the generation that was replaced.
+    fn advance(&mut self) -> (Cells<T::Cell>, bool) {
+        let mut counts = HashMap::<T::Cell, usize, BuildHasherDefault<CellHasher>>::default();
+        for &cell in &self.live {
+            self.topology
+                .neighbours(cell, |neighbour| *counts.entry(neighbour).or_insert(0) += 1);
+        }
+
+        let rule = &self.rule;
+        let live = &self.live;
+        let mut changed = false;
+        let mut alive = |cell: &T::Cell| {
+            let was_alive = live.contains(cell);
+            let is_alive = rule.next(was_alive, counts.get(cell).copied().unwrap_or(0));
+            changed = changed || was_alive != is_alive;
+            is_alive
+        };
+
+        let next: Cells<T::Cell> = match self.topology.cells() {
+            Some(cells) => cells.iter().filter(|cell| alive(cell)).copied().collect(),
+            None => live
+                .iter()
+                .chain(counts.keys())
+                .filter(|cell| alive(cell))
+                .copied()
+                .collect(),
+        };
+
+        let previous = std::mem::replace(&mut self.live, next);
+        self.generation += 1;
+
+        (previous, changed)
+    }
+
+    pub fn run(&mut self, generations: usize) {
+        self.run_watching(generations, |_| {});
+    }
+
+    //  As `run`, showing each new generation to `watch`.
+    pub fn run_watching(&mut self, generations: usize, mut watch: impl FnMut(&Self)) {
+        for _ in 0..generations {
+            self.step();
+            watch(self);
+        }
+    }
+
+    //  Steps until nothing changes, returning how many generations changed
+    //  something. Fails if they are still changing after `limit` generations,
+    //  as a pattern that grows, moves away or oscillates would be, or sooner
+    //  with `detect_cycles` once the cells come back to an earlier state.
+    pub fn run_until_stable(&mut self, limit: usize) -> Result<usize> {
+        self.run_until_stable_watching(limit, |_| {})
+    }
+
+    //  As `run_until_stable`, showing each new generation to `watch`.
+    pub fn run_until_stable_watching(
+        &mut self,
+        limit: usize,
+        mut watch: impl FnMut(&Self),
+    ) -> Result<usize> {
+        let start = self.generation;
+        let mut history: History<T::Cell> = HashMap::new();
+
+        while self.generation - start < limit {
+            let (previous, changed) = self.advance();
+            watch(self);
+            if !changed {
+                return Ok(self.generation - start - 1);
+            }
+            if !self.detect_cycles {
+                continue;
+            }
+
+            history
+                .entry(fingerprint(&previous))
+                .or_default()
+                .push((self.generation - 1, previous));
+
+            let repeated = history
+                .get(&fingerprint(&self.live))
+                .and_then(|states| states.iter().find(|(_, state)| *state == self.live));
+            if let Some((generation, _)) = repeated {
+                return Err(Error::no_solution(format!(
+                    "generation {} repeats generation {}, so it never settles",
+                    self.generation, generation
+                )));
+            }
+        }
+
+        Err(Error::no_solution(format!(
+            "still changing after {} generations",
+            limit
+        )))
+    }
+}
+
+//  The same for any order the cells are stored in, so that equal states can
+//  be found by it. Different states can share one, so they still need to be
+//  compared.
+fn fingerprint<C: Hash>(cells: &Cells<C>) -> u64 {
+    cells
+        .iter()
+        .map(|cell| {
+            let mut hasher = CellHasher::default();
+            cell.hash(&mut hasher);
+            hasher.finish()
+        })
+        .fold(cells.len() as u64, u64::wrapping_add)
+}
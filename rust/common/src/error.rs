@@ -0,0 +1,105 @@
+use std::fmt;
+use std::io;
+
+#[cfg(test)]
+mod error_tests {
+    use super::*;
+
+    static TEST_INPUT: &str = "nop +0\nacc +1\njmp +x\n";
+
+    #[test]
+    fn should_locate_a_slice_of_the_input() {
+        let bad = &TEST_INPUT[18..20];
+
+        match Error::parse_at(TEST_INPUT, bad, "not a number") {
+            Error::Parse { line, column, .. } => assert_eq!((3, 5), (line, column)),
+            e => panic!("unexpected error {}", e),
+        }
+    }
+
+    #[test]
+    fn should_describe_a_parse_error() {
+        assert_eq!(
+            "line 3, column 5: not a number",
+            Error::parse(3, 5, "not a number").to_string()
+        );
+    }
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Io(io::Error),
+    //  Line and column are both counted from 1.
+    Parse {
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    NoSolution(String),
+    //  More than one answer fits the puzzle.
+    Ambiguous(String),
+    InvalidShape(String),
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl Error {
+    pub fn parse<S: Into<String>>(line: usize, column: usize, message: S) -> Error {
+        Error::Parse {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+
+    /// A parse error for `part`, which must be a slice of `input`.
+    pub fn parse_at<S: Into<String>>(input: &str, part: &str, message: S) -> Error {
+        let offset = (part.as_ptr() as usize)
+            .saturating_sub(input.as_ptr() as usize)
+            .min(input.len());
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+
+        Error::parse(
+            before.matches('\n').count() + 1,
+            before[line_start..].chars().count() + 1,
+            message,
+        )
+    }
+
+    pub fn no_solution<S: Into<String>>(message: S) -> Error {
+        Error::NoSolution(message.into())
+    }
+
+    pub fn ambiguous<S: Into<String>>(message: S) -> Error {
+        Error::Ambiguous(message.into())
+    }
+
+    pub fn invalid_shape<S: Into<String>>(message: S) -> Error {
+        Error::InvalidShape(message.into())
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Io(e) => write!(f, "{}", e),
+            Error::Parse {
+                line,
+                column,
+                message,
+            } => write!(f, "line {}, column {}: {}", line, column, message),
+            Error::NoSolution(message) => write!(f, "no solution: {}", message),
+            Error::Ambiguous(message) => write!(f, "ambiguous: {}", message),
+            Error::InvalidShape(message) => write!(f, "invalid puzzle shape: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Error {
+        Error::Io(e)
+    }
+}
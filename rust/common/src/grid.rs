@@ -0,0 +1,367 @@
+use crate::input::lines;
+use crate::{Error, Result};
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+//  Points are (x, y), counted from the top left corner.
+pub type Point = (usize, usize);
+pub type Direction = (isize, isize);
+
+pub const ORTHOGONAL: [Direction; 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+pub const SURROUNDING: [Direction; 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+#[cfg(test)]
+mod grid_tests {
+    use super::*;
+
+    static TEST_INPUT: &str = "
+#..
+.#.
+##.
+...";
+
+    fn get_test_grid() -> Grid<char> {
+        Grid::parse(TEST_INPUT, Some).unwrap()
+    }
+
+    #[test]
+    fn should_parse_a_char_map() {
+        let grid = get_test_grid();
+        assert_eq!((3, 4), (grid.width(), grid.height()));
+        assert_eq!('#', grid[(1, 2)]);
+        assert_eq!(Some(&'.'), grid.get((2, 2)));
+        assert_eq!(None, grid.get((3, 0)));
+    }
+
+    #[test]
+    fn should_reject_malformed_char_maps() {
+        let pixel = |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        };
+
+        assert_eq!(
+            "line 2, column 1: expected a row of 2 cells, found 3",
+            Grid::parse("#.\n...", pixel).unwrap_err().to_string()
+        );
+        assert_eq!(
+            "line 2, column 2: unexpected 'x'",
+            Grid::parse("#.\n.x", pixel).unwrap_err().to_string()
+        );
+        assert!(Grid::parse("\n\n", pixel).is_err());
+    }
+
+    #[test]
+    fn should_report_errors_relative_to_the_whole_input() {
+        let input = "Tile 1:\n#.\n.x";
+        assert_eq!(
+            "line 3, column 2: unexpected 'x'",
+            Grid::parse_within(input, &input[8..], |c| if c == 'x' {
+                None
+            } else {
+                Some(c)
+            })
+            .unwrap_err()
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn should_wrap_around_the_edges() {
+        let grid = get_test_grid();
+        assert_eq!('#', *grid.get_wrapping(3, 4));
+        assert_eq!('#', *grid.get_wrapping(-3, -4));
+        assert_eq!('.', *grid.get_wrapping(-1, -1));
+    }
+
+    #[test]
+    fn should_find_neighbours_inside_the_grid() {
+        let grid = get_test_grid();
+        assert_eq!(
+            vec![(1, 0), (0, 1)],
+            grid.neighbours4((0, 0)).collect::<Vec<Point>>()
+        );
+        assert_eq!(
+            vec![(1, 0), (0, 1), (1, 1)],
+            grid.neighbours8((0, 0)).collect::<Vec<Point>>()
+        );
+        assert_eq!(8, grid.neighbours8((1, 1)).count());
+        assert_eq!(4, grid.neighbours4((1, 2)).count());
+    }
+
+    #[test]
+    fn should_cast_a_ray_to_the_edge() {
+        let grid = get_test_grid();
+        assert_eq!(
+            vec![(1, 1), (2, 2)],
+            grid.ray((0, 0), (1, 1)).collect::<Vec<Point>>()
+        );
+        assert_eq!(
+            Some((0, 2)),
+            grid.ray((0, 0), (0, 1)).find(|&p| grid[p] == '#')
+        );
+        assert_eq!(0, grid.ray((0, 0), (0, 0)).count());
+    }
+
+    #[test]
+    fn should_rotate_clockwise() {
+        assert_eq!(".#.#\n.##.\n....", get_test_grid().rotate().to_string());
+    }
+
+    #[test]
+    fn should_flip_upside_down() {
+        assert_eq!("...\n##.\n.#.\n#..", get_test_grid().flip().to_string());
+    }
+
+    #[test]
+    fn should_cut_out_part_of_the_grid() {
+        assert_eq!("#.\n#.", get_test_grid().subgrid((1, 1), 2, 2).to_string());
+    }
+
+    #[test]
+    fn should_render_a_grid() {
+        let grid = Grid::from_fn(3, 2, |(x, y)| x + y);
+        assert_eq!("012\n123", grid.to_string());
+        assert_eq!(
+            "#.#\n.#.",
+            grid.map(|&n| if n % 2 == 0 { '#' } else { '.' })
+                .to_string()
+        );
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Result<Grid<T>> {
+        if cells.len() != width * height {
+            return Err(Error::invalid_shape(format!(
+                "{} cells cannot fill a {}x{} grid",
+                cells.len(),
+                width,
+                height
+            )));
+        }
+
+        Ok(Grid {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    pub fn from_fn(width: usize, height: usize, cell: impl FnMut(Point) -> T) -> Grid<T> {
+        Grid {
+            width,
+            height,
+            cells: (0..height)
+                .flat_map(|y| (0..width).map(move |x| (x, y)))
+                .map(cell)
+                .collect(),
+        }
+    }
+
+    //  Builds a grid from the non-blank lines of a char map, where `cell`
+    //  returns None for any character it doesn't recognise.
+    pub fn parse(input: &str, cell: impl FnMut(char) -> Option<T>) -> Result<Grid<T>> {
+        Grid::parse_within(input, input, cell)
+    }
+
+    //  As `parse`, for a char map that is part of a larger input; any error is
+    //  reported relative to `input`.
+    pub fn parse_within(
+        input: &str,
+        part: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Grid<T>> {
+        let rows: Vec<&str> = lines(part).collect();
+        let width = rows.first().map_or(0, |row| row.chars().count());
+
+        if width == 0 {
+            return Err(Error::invalid_shape("the grid is empty"));
+        }
+
+        let mut cells = Vec::with_capacity(width * rows.len());
+
+        for row in rows.iter() {
+            let row_width = row.chars().count();
+            if row_width != width {
+                return Err(Error::parse_at(
+                    input,
+                    row,
+                    format!("expected a row of {} cells, found {}", width, row_width),
+                ));
+            }
+
+            for (i, c) in row.char_indices() {
+                cells.push(cell(c).ok_or_else(|| {
+                    Error::parse_at(input, &row[i..], format!("unexpected '{}'", c))
+                })?);
+            }
+        }
+
+        Ok(Grid {
+            width,
+            height: rows.len(),
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, (x, y): Point) -> Option<&T> {
+        if x < self.width && y < self.height {
+            Some(&self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    //  Treats the grid as repeating forever in every direction.
+    pub fn get_wrapping(&self, x: isize, y: isize) -> &T {
+        let x = x.rem_euclid(self.width as isize) as usize;
+        let y = y.rem_euclid(self.height as isize) as usize;
+
+        &self.cells[y * self.width + x]
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.cells.iter()
+    }
+
+    //  Every point in the grid, a row at a time.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    //  The next point in a direction, if it is still inside the grid.
+    pub fn step(&self, (x, y): Point, (dx, dy): Direction) -> Option<Point> {
+        let x = x.checked_add_signed(dx)?;
+        let y = y.checked_add_signed(dy)?;
+
+        if x < self.width && y < self.height {
+            Some((x, y))
+        } else {
+            None
+        }
+    }
+
+    pub fn neighbours4(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        ORTHOGONAL
+            .iter()
+            .filter_map(move |&direction| self.step(point, direction))
+    }
+
+    pub fn neighbours8(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        SURROUNDING
+            .iter()
+            .filter_map(move |&direction| self.step(point, direction))
+    }
+
+    //  Each point passed moving from `start` in a direction until the edge,
+    //  not including `start` itself.
+    pub fn ray(&self, start: Point, direction: Direction) -> impl Iterator<Item = Point> + '_ {
+        let first = match direction {
+            (0, 0) => None,
+            _ => self.step(start, direction),
+        };
+
+        std::iter::successors(first, move |&point| self.step(point, direction))
+    }
+
+    pub fn map<U>(&self, cell: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(cell).collect(),
+        }
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    //  A quarter turn clockwise.
+    pub fn rotate(&self) -> Grid<T> {
+        Grid::from_fn(self.height, self.width, |(x, y)| {
+            self[(y, self.height - 1 - x)].clone()
+        })
+    }
+
+    //  Upside down, so the top row becomes the bottom one.
+    pub fn flip(&self) -> Grid<T> {
+        Grid::from_fn(self.width, self.height, |(x, y)| {
+            self[(x, self.height - 1 - y)].clone()
+        })
+    }
+
+    pub fn subgrid(&self, (left, top): Point, width: usize, height: usize) -> Grid<T> {
+        Grid::from_fn(width, height, |(x, y)| self[(left + x, top + y)].clone())
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): Point) -> &T {
+        assert!(
+            x < self.width && y < self.height,
+            "({}, {}) is outside the grid",
+            x,
+            y
+        );
+        &self.cells[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, (x, y): Point) -> &mut T {
+        assert!(
+            x < self.width && y < self.height,
+            "({}, {}) is outside the grid",
+            x,
+            y
+        );
+        &mut self.cells[y * self.width + x]
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for y in 0..self.height {
+            if y > 0 {
+                writeln!(f)?;
+            }
+
+            for cell in self.row(y) {
+                write!(f, "{}", cell)?;
+            }
+        }
+
+        Ok(())
+    }
+}
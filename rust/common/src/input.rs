@@ -0,0 +1,190 @@
+use crate::{Error, Result};
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{self, BufReader, Read};
+use std::path::Path;
+use std::str::FromStr;
+
+#[cfg(test)]
+mod input_tests {
+    use super::*;
+
+    static TEST_INPUT: &str =
+        "ecl:gry pid:860033327\nhcl:#fffffd\n\niyr:2013 ecl:amb\r\n\r\n\r\nhgt:179cm\n";
+
+    #[test]
+    fn should_name_a_missing_input_file() {
+        let error = read_input(Path::new("no/such/input.txt")).unwrap_err();
+        assert!(error.to_string().starts_with("no/such/input.txt: "));
+    }
+
+    #[test]
+    fn should_skip_blank_lines() {
+        assert_eq!(
+            vec!["1", "2", "3"],
+            lines("1\r\n\n2\n3\n\n").collect::<Vec<&str>>()
+        );
+    }
+
+    #[test]
+    fn should_parse_numbers() {
+        assert_eq!(
+            vec![1721, -979, 366],
+            numbers::<i32>("1721\n-979\n366\n").unwrap()
+        );
+    }
+
+    #[test]
+    fn should_report_the_line_of_a_bad_number() {
+        let error = numbers::<u32>("1\n2\n  three\n").unwrap_err();
+
+        assert!(error.to_string().starts_with("line 3, column 3:"));
+    }
+
+    #[test]
+    fn should_split_groups_on_blank_lines() {
+        assert_eq!(
+            vec![
+                "ecl:gry pid:860033327\nhcl:#fffffd",
+                "iyr:2013 ecl:amb",
+                "hgt:179cm"
+            ],
+            groups(TEST_INPUT)
+        );
+    }
+
+    #[test]
+    fn should_parse_a_char_grid() {
+        assert_eq!(
+            vec![vec!['.', '#'], vec!['#', '.']],
+            char_grid(".#\r\n#.\n")
+        );
+    }
+
+    #[test]
+    fn should_parse_key_value_records() {
+        let records = records(TEST_INPUT).unwrap();
+
+        assert_eq!(3, records.len());
+        assert_eq!(Some(&"860033327"), records[0].get("pid"));
+        assert_eq!(Some(&"#fffffd"), records[0].get("hcl"));
+        assert_eq!(Some(&"amb"), records[1].get("ecl"));
+        assert_eq!(None, records[2].get("ecl"));
+    }
+
+    #[test]
+    fn should_reject_a_pair_without_a_separator() {
+        assert!(key_values("ecl:gry pid").is_err());
+    }
+
+    #[test]
+    fn should_report_where_a_record_is_malformed() {
+        let error = records(TEST_INPUT.replace("amb", "amb hgt").as_str()).unwrap_err();
+
+        assert!(error.to_string().starts_with("line 4, column 18:"));
+    }
+}
+
+pub fn read_file(path: &Path) -> io::Result<String> {
+    let file = File::open(path)
+        .map_err(|e| io::Error::new(e.kind(), format!("{}: {}", path.display(), e)))?;
+    let mut buf_reader = BufReader::new(file);
+    let mut input = String::new();
+    buf_reader.read_to_string(&mut input)?;
+
+    Ok(input)
+}
+
+//  Reads the whole of stdin when the path is "-", otherwise the named file.
+pub fn read_input(path: &Path) -> io::Result<String> {
+    if path == Path::new("-") {
+        let mut input = String::new();
+        io::stdin().read_to_string(&mut input)?;
+        return Ok(input);
+    }
+
+    read_file(path)
+}
+
+/// The non-blank lines of the input, without any trailing `\r`.
+pub fn lines(input: &str) -> impl Iterator<Item = &str> {
+    input
+        .lines()
+        .map(|line| line.trim_end_matches('\r'))
+        .filter(|line| !line.trim().is_empty())
+}
+
+/// One number per line, failing on the first line that does not parse.
+pub fn numbers<T: FromStr>(input: &str) -> Result<Vec<T>>
+where
+    T::Err: std::fmt::Display,
+{
+    lines(input)
+        .map(|line| {
+            let line = line.trim();
+            line.parse::<T>()
+                .map_err(|e| Error::parse_at(input, line, format!("'{}' {}", line, e)))
+        })
+        .collect()
+}
+
+/// Blocks of lines separated by one or more blank lines.
+pub fn groups(input: &str) -> Vec<&str> {
+    let mut groups = Vec::new();
+    let mut start = None;
+    let mut end = 0;
+    let mut offset = 0;
+
+    for line in input.split_inclusive('\n') {
+        if line.trim().is_empty() {
+            if let Some(s) = start.take() {
+                groups.push(&input[s..end]);
+            }
+        } else {
+            start.get_or_insert(offset);
+            end = offset + line.trim_end().len();
+        }
+
+        offset += line.len();
+    }
+
+    if let Some(s) = start {
+        groups.push(&input[s..end]);
+    }
+
+    groups
+}
+
+pub fn char_grid(input: &str) -> Vec<Vec<char>> {
+    lines(input).map(|line| line.chars().collect()).collect()
+}
+
+/// Whitespace separated `key:value` pairs, which may span several lines.
+pub fn key_values(record: &str) -> Result<HashMap<&str, &str>> {
+    key_values_in(record, record)
+}
+
+/// A `key:value` record for each blank-line separated group.
+pub fn records(input: &str) -> Result<Vec<HashMap<&str, &str>>> {
+    groups(input)
+        .into_iter()
+        .map(|record| key_values_in(input, record))
+        .collect()
+}
+
+fn key_values_in<'a>(input: &str, record: &'a str) -> Result<HashMap<&'a str, &'a str>> {
+    record
+        .split_whitespace()
+        .map(|pair| {
+            let mut parts = pair.splitn(2, ':');
+            match (parts.next(), parts.next()) {
+                (Some(key), Some(value)) => Ok((key, value)),
+                _ => Err(Error::parse_at(
+                    input,
+                    pair,
+                    format!("'{}' is not a key:value pair", pair),
+                )),
+            }
+        })
+        .collect()
+}
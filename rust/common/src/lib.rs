@@ -0,0 +1,12 @@
+pub mod automaton;
+mod error;
+pub mod grid;
+pub mod input;
+pub mod numtheory;
+pub mod random;
+mod solution;
+pub mod trace;
+
+pub use error::{Error, Result};
+pub use input::{read_file, read_input};
+pub use solution::Solution;
@@ -0,0 +1,304 @@
+use std::collections::HashMap;
+
+//  Moduli are expected to fit in an i64, and products are worked out in 128
+//  bits so that they never overflow.
+
+#[cfg(test)]
+mod numtheory_tests {
+    use super::*;
+
+    #[test]
+    fn should_find_the_gcd_and_bezout_coefficients() {
+        assert_eq!((2, -9, 47), egcd(240, 46));
+        assert_eq!((5, 0, 1), egcd(0, 5));
+        assert_eq!((1, 1, 0), egcd(1, 0));
+    }
+
+    #[test]
+    fn should_find_a_modular_inverse() {
+        assert_eq!(Some(4), mod_inv(3, 11));
+        assert_eq!(Some(7), mod_inv(-3, 11));
+        assert_eq!(None, mod_inv(6, 9));
+        assert_eq!(
+            Some(1),
+            mod_inv(i64::MAX - 1, i64::MAX)
+                .map(|inv| (i64::MAX - 1) as i128 * inv as i128 % i64::MAX as i128)
+        );
+    }
+
+    #[test]
+    fn should_raise_to_a_power_by_squaring() {
+        assert_eq!(14897079, mod_pow(17807724, 8, 20201227));
+        assert_eq!(1, mod_pow(12345, 0, 20201227));
+        assert_eq!(0, mod_pow(12345, 0, 1));
+        assert_eq!(2, mod_pow(2, 64, u64::MAX >> 1));
+    }
+
+    #[test]
+    fn should_solve_coprime_congruences() {
+        assert_eq!(Some((23, 105)), crt(&[(2, 3), (3, 5), (2, 7)]));
+        assert_eq!(Some((0, 1)), crt(&[]));
+        assert_eq!(Some((1, 3)), crt(&[(-2, 3)]));
+    }
+
+    #[test]
+    fn should_solve_congruences_that_share_factors() {
+        assert_eq!(Some((10, 12)), crt(&[(2, 4), (4, 6)]));
+        assert_eq!(None, crt(&[(1, 4), (2, 6)]));
+    }
+
+    #[test]
+    fn should_not_overflow_with_large_moduli() {
+        let big = 1_000_000_007;
+        let bigger = 998_244_353;
+        let (x, m) = crt(&[(big - 1, big), (bigger - 1, bigger)]).unwrap();
+        assert_eq!(big * bigger, m);
+        assert_eq!(m - 1, x);
+        assert_eq!(None, crt(&[(0, i64::MAX), (0, i64::MAX - 1)]));
+    }
+
+    #[test]
+    fn should_factorise_a_number() {
+        assert_eq!(
+            vec![(2, 1), (3, 1), (29, 1), (116099, 1)],
+            prime_factors(20201226)
+        );
+        assert_eq!(vec![(2, 3), (7, 2)], prime_factors(392));
+        assert!(prime_factors(1).is_empty());
+    }
+
+    #[test]
+    fn should_factorise_a_prime_near_the_largest_u64() {
+        let prime = u64::MAX - 58;
+        assert_eq!(vec![(prime, 1)], prime_factors(prime));
+        assert_eq!(
+            vec![
+                (3, 1),
+                (5, 1),
+                (17, 1),
+                (257, 1),
+                (641, 1),
+                (65537, 1),
+                (6700417, 1)
+            ],
+            prime_factors(u64::MAX)
+        );
+    }
+
+    #[test]
+    fn should_find_a_discrete_log() {
+        assert_eq!(Some(8), discrete_log(7, 5764801, 20201227));
+        assert_eq!(Some(11), discrete_log(7, 17807724, 20201227));
+        assert_eq!(Some(0), discrete_log(7, 1, 20201227));
+        assert_eq!(None, discrete_log(2, 3, 7));
+    }
+
+    #[test]
+    fn should_find_a_discrete_log_by_pohlig_hellman() {
+        assert_eq!(Some(8), pohlig_hellman(7, 5764801, 20201227));
+        assert_eq!(Some(11), pohlig_hellman(7, 17807724, 20201227));
+        assert_eq!(None, pohlig_hellman(2, 3, 7));
+
+        //  2 only generates half of the group mod 17, so check the smallest
+        //  answer comes back for every reachable target.
+        for prime in [17, 101, 7919] {
+            for base in 2..20 {
+                for x in 0..40 {
+                    let target = mod_pow(base, x, prime);
+                    assert_eq!(
+                        discrete_log(base, target, prime),
+                        pohlig_hellman(base, target, prime)
+                    );
+                }
+            }
+        }
+    }
+}
+
+//  Returns (g, x, y) where g is the gcd of a and b, and a * x + b * y = g.
+pub fn egcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (mut old_r, mut r) = (a as i128, b as i128);
+    let (mut old_x, mut x) = (1i128, 0i128);
+    let (mut old_y, mut y) = (0i128, 1i128);
+
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_x, x) = (x, old_x - q * x);
+        (old_y, y) = (y, old_y - q * y);
+    }
+
+    (old_r as i64, old_x as i64, old_y as i64)
+}
+
+//  The x in 0..modulus with a * x = 1, if there is one.
+pub fn mod_inv(a: i64, modulus: i64) -> Option<i64> {
+    let (g, x, _) = egcd(a.rem_euclid(modulus), modulus);
+
+    if g == 1 {
+        Some(x.rem_euclid(modulus))
+    } else {
+        None
+    }
+}
+
+pub fn mod_mul(a: u64, b: u64, modulus: u64) -> u64 {
+    (a as u128 * b as u128 % modulus as u128) as u64
+}
+
+pub fn mod_pow(base: u64, mut exponent: u64, modulus: u64) -> u64 {
+    let mut result = 1 % modulus;
+    let mut base = base % modulus;
+
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = mod_mul(result, base, modulus);
+        }
+
+        base = mod_mul(base, base, modulus);
+        exponent >>= 1;
+    }
+
+    result
+}
+
+//  Combines congruences x = a (mod m) into a single x (mod lcm), with x in
+//  0..lcm. The moduli don't need to be coprime, but then there is only an
+//  answer if the congruences agree wherever they overlap. Also None if the
+//  lcm wouldn't fit in an i64.
+pub fn crt(congruences: &[(i64, i64)]) -> Option<(i64, i64)> {
+    congruences
+        .iter()
+        .try_fold((0, 1), |(x, modulus), &(a, m)| {
+            let a = a.rem_euclid(m);
+            let (g, p, _) = egcd(modulus, m);
+
+            if (a - x) % g != 0 {
+                return None;
+            }
+
+            let lcm = (modulus / g).checked_mul(m)?;
+
+            //  x + modulus * k is the answer, where modulus * k = a - x (mod m).
+            let step = m / g;
+            let k = ((a - x) / g) as i128 * p as i128 % step as i128;
+            let result = (x as i128 + modulus as i128 * k).rem_euclid(lcm as i128);
+
+            Some((result as i64, lcm))
+        })
+}
+
+//  Each prime factor with its power, smallest first, by trial division.
+pub fn prime_factors(mut n: u64) -> Vec<(u64, u32)> {
+    let mut factors = vec![];
+    let mut p = 2;
+
+    //  p * p overflows for the largest primes, where p passes 2^32.
+    while p <= n / p {
+        let mut power = 0;
+        while n.is_multiple_of(p) {
+            n /= p;
+            power += 1;
+        }
+
+        if power > 0 {
+            factors.push((p, power));
+        }
+
+        p += if p == 2 { 1 } else { 2 };
+    }
+
+    if n > 1 {
+        factors.push((n, 1));
+    }
+
+    factors
+}
+
+//  The smallest x in 0..bound with base^x = target, where base is coprime to
+//  the modulus, by baby-step giant-step.
+fn bounded_log(base: u64, target: u64, modulus: u64, bound: u64) -> Option<u64> {
+    let n = (bound as f64).sqrt().ceil().max(1.0) as u64;
+    let mut baby_steps = HashMap::<u64, u64>::new();
+    let mut value = 1 % modulus;
+
+    for j in 0..n {
+        baby_steps.entry(value).or_insert(j);
+        value = mod_mul(value, base, modulus);
+    }
+
+    let inverse = mod_inv(base as i64, modulus as i64)? as u64;
+    let giant_step = mod_pow(inverse, n, modulus);
+    let mut gamma = target % modulus;
+
+    for i in 0..n {
+        if let Some(j) = baby_steps.get(&gamma) {
+            let x = i * n + j;
+            return if x < bound { Some(x) } else { None };
+        }
+
+        gamma = mod_mul(gamma, giant_step, modulus);
+    }
+
+    None
+}
+
+//  The smallest x with base^x = target (mod modulus), for a base coprime to
+//  the modulus. Takes around sqrt(modulus) time and space.
+pub fn discrete_log(base: u64, target: u64, modulus: u64) -> Option<u64> {
+    bounded_log(base, target, modulus, modulus)
+}
+
+//  As `discrete_log` for a prime modulus, but splits the problem up by the
+//  prime factors of prime - 1, so it is much faster when those are all small.
+pub fn pohlig_hellman(base: u64, target: u64, prime: u64) -> Option<u64> {
+    let base = base % prime;
+    let target = target % prime;
+    if base == 0 || target == 0 {
+        return None;
+    }
+
+    //  Solve in the subgroup the base generates, so that each piece below has
+    //  a generator of exactly the right order.
+    let mut order = prime - 1;
+    for &(p, _) in &prime_factors(order) {
+        while order.is_multiple_of(p) && mod_pow(base, order / p, prime) == 1 {
+            order /= p;
+        }
+    }
+
+    let mut congruences = vec![];
+    for &(p, e) in &prime_factors(order) {
+        let prime_power = p.pow(e);
+        let cofactor = order / prime_power;
+        let g = mod_pow(base, cofactor, prime);
+        let h = mod_pow(target, cofactor, prime);
+
+        //  Work out x (mod p^e) one base p digit at a time, each of which is
+        //  a log in the subgroup of order p.
+        let gamma = mod_pow(g, prime_power / p, prime);
+        let g_inverse = mod_inv(g as i64, prime as i64)? as u64;
+        let mut x = 0;
+        let mut place = 1;
+
+        for k in 0..e {
+            let remaining = mod_mul(mod_pow(g_inverse, x, prime), h, prime);
+            let h_k = mod_pow(remaining, prime_power / p.pow(k + 1), prime);
+            let digit = bounded_log(gamma, h_k, prime, p)?;
+
+            x += digit * place;
+            place *= p;
+        }
+
+        congruences.push((x as i64, prime_power as i64));
+    }
+
+    //  The answer comes out modulo the order of the base, so it is already the
+    //  smallest one, but the target may not be a power of the base at all.
+    let (x, _) = crt(&congruences)?;
+    if mod_pow(base, x as u64, prime) != target {
+        return None;
+    }
+
+    Some(x as u64)
+}
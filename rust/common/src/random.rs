@@ -0,0 +1,71 @@
+//  A small xorshift generator, so synthetic puzzle inputs are the same on
+//  every machine and every run for a given seed.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+#[cfg(test)]
+mod random_tests {
+    use super::*;
+
+    #[test]
+    fn should_repeat_a_sequence_for_a_seed() {
+        let first: Vec<u64> = (0..5)
+            .scan(Rng::new(7), |rng, _| Some(rng.next()))
+            .collect();
+        let second: Vec<u64> = (0..5)
+            .scan(Rng::new(7), |rng, _| Some(rng.next()))
+            .collect();
+        assert_eq!(first, second);
+        assert_ne!(first[0], Rng::new(8).next());
+    }
+
+    #[test]
+    fn should_stay_below_the_bound() {
+        let mut rng = Rng::new(0);
+        assert!((0..1000).all(|_| rng.below(10) < 10));
+    }
+
+    #[test]
+    fn should_shuffle_without_losing_items() {
+        let mut items: Vec<usize> = (0..50).collect();
+        Rng::new(3).shuffle(&mut items);
+        assert_ne!((0..50).collect::<Vec<usize>>(), items);
+
+        items.sort_unstable();
+        assert_eq!((0..50).collect::<Vec<usize>>(), items);
+    }
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        //  Xorshift never leaves zero, so mix the seed into a non-zero state.
+        Rng {
+            state: seed.wrapping_mul(0x9E37_79B9_7F4A_7C15) | 1,
+        }
+    }
+
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> u64 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        self.state
+    }
+
+    pub fn below(&mut self, bound: usize) -> usize {
+        (self.next() % bound as u64) as usize
+    }
+
+    //  True with the given chance, out of 100.
+    pub fn chance(&mut self, percent: usize) -> bool {
+        self.below(100) < percent
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
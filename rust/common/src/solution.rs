@@ -0,0 +1,17 @@
+use crate::Result;
+use std::fmt::Display;
+
+/// A single day's puzzle, split into a parse step and the two parts that
+/// share its parsed input.
+pub trait Solution {
+    type Input;
+    type Part1: Display;
+    type Part2: Display;
+
+    /// Christmas Day only has one puzzle, so it can opt out of part two.
+    const HAS_PART2: bool = true;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+    fn part1(input: &Self::Input) -> Result<Self::Part1>;
+    fn part2(input: &Self::Input) -> Result<Self::Part2>;
+}
@@ -0,0 +1,220 @@
+//  Step by step state from the simulation days, for debugging and drawing
+//  them. A solution hands each step to a Tracer, which decides what to keep.
+//  NoTrace keeps nothing, and says so up front, so that the untraced
+//  solutions never build a frame.
+use crate::{Result, Solution};
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Number(i64),
+    Text(String),
+    List(Vec<Value>),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Frame {
+    pub step: usize,
+    //  One line saying what the state is after this step.
+    pub caption: String,
+    //  The state drawn one character per cell, for the simulations that have
+    //  a layout worth drawing. '#' is something alive or taken and '.' is
+    //  something that isn't.
+    pub picture: Vec<String>,
+    //  The state itself, by name.
+    pub state: Vec<(&'static str, Value)>,
+}
+
+pub trait Tracer {
+    //  Whether frames are wanted at all, which can change as it goes.
+    fn enabled(&self) -> bool {
+        true
+    }
+
+    fn record(&mut self, frame: Frame);
+}
+
+pub struct NoTrace;
+
+//  A solution whose parts can be run with a tracer watching.
+pub trait Traced: Solution {
+    fn trace_part1(input: &Self::Input, tracer: &mut dyn Tracer) -> Result<Self::Part1>;
+    fn trace_part2(input: &Self::Input, tracer: &mut dyn Tracer) -> Result<Self::Part2>;
+}
+
+#[cfg(test)]
+mod trace_tests {
+    use super::*;
+
+    #[test]
+    fn should_only_build_frames_that_are_wanted() {
+        let mut frames = vec![];
+        trace(&mut frames, || Frame::new(1, "kept").state("count", 3));
+        trace(&mut NoTrace, || panic!("frame built for nothing"));
+
+        assert_eq!(
+            vec![Frame {
+                step: 1,
+                caption: String::from("kept"),
+                picture: vec![],
+                state: vec![("count", Value::Number(3))],
+            }],
+            frames
+        );
+    }
+
+    #[test]
+    fn should_convert_state_to_values() {
+        assert_eq!(
+            Value::List(vec![
+                Value::List(vec![Value::Number(1), Value::Number(-2)]),
+                Value::List(vec![Value::Number(0), Value::Number(3)])
+            ]),
+            Value::from(vec![(1, -2), (0, 3)])
+        );
+        assert_eq!(Value::Text(String::from("East")), Value::from("East"));
+    }
+
+    #[test]
+    fn should_draw_points_within_their_bounds() {
+        let points = vec![(-1, 4), (1, 5)];
+        assert_eq!(Some(((-1, 4), (1, 5))), bounds(points.clone()));
+        assert_eq!(None, bounds(vec![]));
+
+        assert_eq!(
+            vec!["#..", "..#"],
+            draw_points(points.clone(), ((-1, 4), (1, 5)))
+        );
+        assert_eq!(vec![".", "#"], draw_points(points, ((1, 4), (1, 5))));
+    }
+}
+
+impl Frame {
+    pub fn new(step: usize, caption: impl Into<String>) -> Frame {
+        Frame {
+            step,
+            caption: caption.into(),
+            picture: vec![],
+            state: vec![],
+        }
+    }
+
+    pub fn picture(mut self, picture: Vec<String>) -> Frame {
+        self.picture = picture;
+        self
+    }
+
+    pub fn state(mut self, name: &'static str, value: impl Into<Value>) -> Frame {
+        self.state.push((name, value.into()));
+        self
+    }
+}
+
+impl From<i64> for Value {
+    fn from(n: i64) -> Value {
+        Value::Number(n)
+    }
+}
+
+impl From<isize> for Value {
+    fn from(n: isize) -> Value {
+        Value::Number(n as i64)
+    }
+}
+
+impl From<usize> for Value {
+    fn from(n: usize) -> Value {
+        Value::Number(n as i64)
+    }
+}
+
+impl From<i32> for Value {
+    fn from(n: i32) -> Value {
+        Value::Number(n as i64)
+    }
+}
+
+impl From<&str> for Value {
+    fn from(text: &str) -> Value {
+        Value::Text(String::from(text))
+    }
+}
+
+impl From<String> for Value {
+    fn from(text: String) -> Value {
+        Value::Text(text)
+    }
+}
+
+impl<T: Into<Value>> From<Vec<T>> for Value {
+    fn from(values: Vec<T>) -> Value {
+        Value::List(values.into_iter().map(Into::into).collect())
+    }
+}
+
+impl<T: Into<Value>, U: Into<Value>> From<(T, U)> for Value {
+    fn from((first, second): (T, U)) -> Value {
+        Value::List(vec![first.into(), second.into()])
+    }
+}
+
+impl<const N: usize> From<[isize; N]> for Value {
+    fn from(values: [isize; N]) -> Value {
+        Value::from(values.to_vec())
+    }
+}
+
+impl Tracer for NoTrace {
+    fn enabled(&self) -> bool {
+        false
+    }
+
+    fn record(&mut self, _: Frame) {}
+}
+
+impl Tracer for Vec<Frame> {
+    fn record(&mut self, frame: Frame) {
+        self.push(frame);
+    }
+}
+
+//  Builds and records a frame, but only if the tracer wants it.
+pub fn trace<T: Tracer + ?Sized>(tracer: &mut T, frame: impl FnOnce() -> Frame) {
+    if tracer.enabled() {
+        tracer.record(frame());
+    }
+}
+
+//  The top left and bottom right corners of a rectangle, inclusive.
+pub type Bounds = ((isize, isize), (isize, isize));
+
+pub fn bounds(points: impl IntoIterator<Item = (isize, isize)>) -> Option<Bounds> {
+    points
+        .into_iter()
+        .fold(None, |bounds, (x, y)| match bounds {
+            None => Some(((x, y), (x, y))),
+            Some(((left, top), (right, bottom))) => {
+                Some(((left.min(x), top.min(y)), (right.max(x), bottom.max(y))))
+            }
+        })
+}
+
+//  Draws points as '#' on a background of '.', leaving out any that fall
+//  outside the bounds.
+pub fn draw_points(
+    points: impl IntoIterator<Item = (isize, isize)>,
+    ((left, top), (right, bottom)): Bounds,
+) -> Vec<String> {
+    let width = (right - left + 1).max(0) as usize;
+    let height = (bottom - top + 1).max(0) as usize;
+
+    let mut rows = vec![vec!['.'; width]; height];
+    for (x, y) in points {
+        if (left..=right).contains(&x) && (top..=bottom).contains(&y) {
+            rows[(y - top) as usize][(x - left) as usize] = '#';
+        }
+    }
+
+    rows.into_iter()
+        .map(|row| row.into_iter().collect())
+        .collect()
+}
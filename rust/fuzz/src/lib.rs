@@ -0,0 +1,25 @@
+//  Strategies for throwing generated input at the puzzle parsers. The tests
+//  live in tests/parsers.rs; run more cases with PROPTEST_CASES=<n>.
+use proptest::prelude::*;
+
+//  Strings glued together from pieces of a day's real input format, which get
+//  much further into its parser than arbitrary text does, mixed with arbitrary
+//  text for everything the pieces don't cover.
+pub fn puzzle_input(pieces: &'static [&'static str]) -> impl Strategy<Value = String> {
+    prop_oneof![
+        1 => any::<String>(),
+        3 => prop::collection::vec(prop::sample::select(pieces), 0..60)
+            .prop_map(|pieces| pieces.concat()),
+    ]
+}
+
+//  The rows of a rectangular char map.
+pub fn char_map(cells: &'static [char]) -> impl Strategy<Value = Vec<String>> {
+    (1..12usize, 1..12usize).prop_flat_map(move |(width, height)| {
+        prop::collection::vec(
+            prop::collection::vec(prop::sample::select(cells), width)
+                .prop_map(|row| row.into_iter().collect::<String>()),
+            height,
+        )
+    })
+}
@@ -0,0 +1,107 @@
+use aoc_common::grid::Grid;
+use aoc_common::Solution;
+use aoc_fuzz::{char_map, puzzle_input};
+use proptest::prelude::*;
+
+//  Each parser must return, with an error if need be, whatever it is given.
+macro_rules! never_panics {
+    ($($name:ident: $day:ty => $pieces:expr;)*) => {
+        proptest! {
+            $(
+                #[test]
+                fn $name(input in puzzle_input($pieces)) {
+                    let _ = <$day as Solution>::parse(&input);
+                }
+            )*
+        }
+    };
+}
+
+never_panics! {
+    day1_never_panics: day1::Day1 => &["1721", "979", "-5", "0", "2020", "99999999999", "\n", " ", "x"];
+    day2_never_panics: day2::Day2 => &["1", "3", "0", "-", " ", ": ", "a", "abcde", "\n", "99999999999999999999"];
+    day3_never_panics: day3::Day3 => &[".", "#", "\n", "x"];
+    day4_never_panics: day4::Day4 => &[
+        "byr:", "iyr:", "eyr:", "hgt:", "hcl:", "ecl:", "pid:", "cid:", "1980", "cm", "in", "#",
+        "abc", "blu", "012345678", ":", " ", "\n", "\n\n",
+    ];
+    day5_never_panics: day5::Day5 => &["F", "B", "L", "R", "FBFBBFF", "RLR", "\n", "X"];
+    day6_never_panics: day6::Day6 => &["a", "b", "z", "A", " ", "\n", "\n\n"];
+    day7_never_panics: day7::Day7 => &[
+        "light red", "bright white", " bags contain ", "no other bags", "1 ", "2 ", " bag",
+        " bags", ", ", ".", "contain", "\n",
+    ];
+    day8_never_panics: day8::Day8 => &["nop ", "acc ", "jmp ", "+0", "+1", "-3", "+99999999999", " ", "\n", "x"];
+    day9_never_panics: day9::Day9 => &["35", "20", "0", "127", "99999999999999999999", "-1", "\n", "x"];
+    day10_never_panics: day10::Day10 => &["1", "3", "4", "0", "99999999999", "-1", "\n", "x"];
+    day11_never_panics: day11::Day11 => &["L", ".", "#", "\n", "x"];
+    day12_never_panics: day12::Day12 => &["N", "S", "E", "W", "L", "R", "F", "10", "90", "45", "-", "99999999999999999999", "\n"];
+    day13_never_panics: day13::Day13 => &["939", "7", "13", "0", "-1", "x", ",", "99999999999999999999", "\n"];
+    day14_never_panics: day14::Day14 => &[
+        "mask = ", "X", "1", "0", "mem[", "8", "]", " = ", "11", "99999999999999999999", "\n",
+    ];
+    day15_never_panics: day15::Day15 => &["0", "3", "6", ",", " ", "99999999999999999999", "\n"];
+    day16_never_panics: day16::Day16 => &[
+        "class: ", "1-3", " or ", "5-7", "-", "your ticket:", "nearby tickets:", "7,1,14", ",",
+        "999999999999999999999", "\n", "\n\n",
+    ];
+    day17_never_panics: day17::Day17 => &[".", "#", "\n", "x"];
+    day18_never_panics: day18::Day18 => &["1", "2", " + ", " * ", "(", ")", " ", "99999999999999999999", "\n"];
+    day19_never_panics: day19::Day19 => &[
+        "0: ", "1: ", "8: ", "4 1 5", "2 3 | 3 2", "42", " | ", "\"a\"", "\"b\"", "ababbb", " ",
+        "\n", "\n\n",
+    ];
+    day20_never_panics: day20::Day20 => &["Tile ", "2311", ":", "#", ".", "#.#", "\n", "\n\n", "x"];
+    day21_never_panics: day21::Day21 => &[
+        "mxmxvkd ", "kfcds ", "sqjhc ", "(contains ", "dairy", "fish", ", ", ")", "\n",
+    ];
+    day22_never_panics: day22::Day22 => &["Player 1:", "Player 2:", "9", "2", "6", "99999999999999999999", "\n", "\n\n"];
+    day23_never_panics: day23::Day23 => &["3", "8", "9", "1", "2", "5", "4", "6", "7", "0", "\n", "x"];
+    day24_never_panics: day24::Day24 => &["e", "w", "ne", "nw", "se", "sw", "n", "s", "\n", "x"];
+    day25_never_panics: day25::Day25 => &["5764801", "17807724", "20201227", "0", "\n", "x"];
+}
+
+proptest! {
+    #[test]
+    fn day18_tokenise_never_panics(line in puzzle_input(&["1", "23", " + ", " * ", "(", ")", " ", "x"])) {
+        let _ = day18::tokenise(line);
+    }
+
+    #[test]
+    fn char_maps_round_trip(rows in char_map(&['.', '#', 'L'])) {
+        let input = rows.join("\n");
+        prop_assert_eq!(&input, &Grid::parse(&input, Some).unwrap().to_string());
+    }
+
+    #[test]
+    fn expense_reports_round_trip(entries in prop::collection::vec(any::<i32>(), 1..20)) {
+        let input = entries.iter().map(|entry| entry.to_string()).collect::<Vec<String>>().join("\n");
+        prop_assert_eq!(entries, day1::Day1::parse(&input).unwrap());
+    }
+
+    #[test]
+    fn xmas_data_round_trips(numbers in prop::collection::vec(any::<u64>(), 1..20)) {
+        let input = numbers.iter().map(|number| number.to_string()).collect::<Vec<String>>().join("\n");
+        prop_assert_eq!(numbers, day9::Day9::parse(&input).unwrap());
+    }
+
+    #[test]
+    fn bus_schedules_round_trip(
+        time in 0..i64::MAX,
+        bus_ids in prop::collection::vec(prop::option::of(1..i64::MAX), 1..20),
+    ) {
+        let buses = bus_ids
+            .iter()
+            .map(|bus_id| bus_id.map_or(String::from("x"), |id| id.to_string()))
+            .collect::<Vec<String>>()
+            .join(",");
+        let input = format!("{}\n{}", time, buses);
+        prop_assert_eq!((time, bus_ids), day13::Day13::parse(&input).unwrap());
+    }
+
+    #[test]
+    fn starting_numbers_round_trip(numbers in prop::collection::vec(any::<usize>(), 1..20)) {
+        let input = numbers.iter().map(|number| number.to_string()).collect::<Vec<String>>().join(",");
+        prop_assert_eq!(numbers, day15::Day15::parse(&input).unwrap());
+    }
+}
@@ -0,0 +1,74 @@
+//  Random puzzle inputs, for stress testing and benchmarking the solutions on
+//  inputs much larger than the real ones. Each generator builds its input
+//  around answers it already knows, so the solutions can be checked against
+//  it; tests/answers.rs does that for every day.
+use aoc_common::random::Rng;
+
+pub mod year2020;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Generated {
+    pub input: String,
+    //  An answer is left out when working it out would take as long as
+    //  solving the puzzle does, or it would overflow the solution's type.
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+#[cfg(test)]
+mod generate_tests {
+    use super::*;
+
+    #[test]
+    fn should_repeat_an_input_for_a_seed() {
+        for day in 1..=25 {
+            assert_eq!(
+                generate("2020", day, 7, Some(10)),
+                generate("2020", day, 7, Some(10))
+            );
+        }
+
+        assert_ne!(
+            generate("2020", 1, 7, None).unwrap().input,
+            generate("2020", 1, 8, None).unwrap().input
+        );
+    }
+
+    #[test]
+    fn should_only_generate_days_that_exist() {
+        assert!(generate("2020", 0, 0, None).is_none());
+        assert!(generate("2020", 26, 0, None).is_none());
+        assert!(generate("2019", 1, 0, None).is_none());
+    }
+}
+
+impl Generated {
+    pub fn new(input: String) -> Generated {
+        Generated {
+            input,
+            part1: None,
+            part2: None,
+        }
+    }
+
+    pub fn part1(mut self, answer: impl ToString) -> Generated {
+        self.part1 = Some(answer.to_string());
+        self
+    }
+
+    pub fn part2(mut self, answer: impl ToString) -> Generated {
+        self.part2 = Some(answer.to_string());
+        self
+    }
+}
+
+//  What the size means is up to each day, but it is always roughly how many
+//  lines or records the input has, and defaults to the size of the real one.
+pub fn generate(year: &str, day: usize, seed: u64, size: Option<usize>) -> Option<Generated> {
+    let (generator, real_size) = match year {
+        "2020" => year2020::generator(day)?,
+        _ => return None,
+    };
+
+    Some(generator(&mut Rng::new(seed), size.unwrap_or(real_size)))
+}
@@ -0,0 +1,142 @@
+use aoc_generate::generate;
+use std::env;
+use std::io::{Error, ErrorKind, Result};
+use std::process;
+
+#[derive(Debug, PartialEq)]
+struct Options {
+    seed: u64,
+    size: Option<usize>,
+    answers: bool,
+}
+
+#[cfg(test)]
+mod generate_main_tests {
+    use super::*;
+
+    #[test]
+    fn should_separate_options_from_arguments() {
+        let args: Vec<String> = vec!["--seed", "12", "2020", "--size", "50", "7"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+
+        let (options, positional) = parse_options(&args).unwrap();
+        assert_eq!(
+            Options {
+                seed: 12,
+                size: Some(50),
+                answers: false
+            },
+            options
+        );
+        assert_eq!(vec!["2020", "7"], positional);
+
+        let (options, _) = parse_options(&[String::from("--answers")]).unwrap();
+        assert!(options.answers);
+    }
+
+    #[test]
+    fn should_reject_bad_options() {
+        assert!(parse_options(&[String::from("--seed")]).is_err());
+        assert!(parse_options(&[String::from("--seed"), String::from("x")]).is_err());
+        assert!(parse_options(&[String::from("--size"), String::from("-1")]).is_err());
+        assert!(parse_options(&[String::from("--fast")]).is_err());
+    }
+
+    #[test]
+    fn should_write_answers_in_the_manifest_format() {
+        let generated = generate("2020", 25, 1, None).unwrap();
+        let lines = answer_lines("2020", 25, &generated);
+
+        assert_eq!(1, lines.len());
+        assert!(lines[0].starts_with("2020 25 a "));
+    }
+}
+
+fn invalid_input(message: String) -> Error {
+    Error::new(ErrorKind::InvalidInput, message)
+}
+
+fn parse_number<T: std::str::FromStr>(value: Option<&String>, what: &str) -> Result<T> {
+    let value = value.map_or("", |v| v.as_str());
+    value
+        .parse::<T>()
+        .map_err(|_| invalid_input(format!("'{}' is not a {}", value, what)))
+}
+
+fn parse_options(args: &[String]) -> Result<(Options, Vec<&str>)> {
+    let mut options = Options {
+        seed: 0,
+        size: None,
+        answers: false,
+    };
+    let mut positional = vec![];
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--seed" => options.seed = parse_number(args.next(), "seed")?,
+            "--size" => options.size = Some(parse_number(args.next(), "size")?),
+            "--answers" => options.answers = true,
+            a if a.starts_with("--") => {
+                return Err(invalid_input(format!("unknown option '{}'", a)))
+            }
+            a => positional.push(a),
+        }
+    }
+
+    Ok((options, positional))
+}
+
+//  The planted answers, in the same form as the runner's answers.txt.
+fn answer_lines(year: &str, day: usize, generated: &aoc_generate::Generated) -> Vec<String> {
+    [("a", &generated.part1), ("b", &generated.part2)]
+        .iter()
+        .filter_map(|(part, answer)| {
+            answer
+                .as_ref()
+                .map(|answer| format!("{} {} {} {}", year, day, part, answer))
+        })
+        .collect()
+}
+
+fn usage() -> Error {
+    invalid_input(String::from(
+        "usage: aoc-generate [--seed <n>] [--size <n>] [--answers] <year> <day>",
+    ))
+}
+
+fn run(args: &[String]) -> Result<()> {
+    let (options, args) = parse_options(args)?;
+    if args.len() != 2 {
+        return Err(usage());
+    }
+
+    let (year, day) = (args[0], args[1]);
+    let day = day
+        .parse::<usize>()
+        .map_err(|_| invalid_input(format!("'{}' is not a day", day)))?;
+
+    let generated = generate(year, day, options.seed, options.size)
+        .ok_or_else(|| invalid_input(format!("no generator for {} day {}", year, day)))?;
+
+    if options.answers {
+        for line in answer_lines(year, day, &generated) {
+            println!("{}", line);
+        }
+    } else {
+        println!("{}", generated.input);
+    }
+
+    Ok(())
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    if let Err(e) = run(&args) {
+        eprintln!("{}", e);
+        process::exit(1);
+    }
+}
@@ -0,0 +1,72 @@
+use crate::Generated;
+use aoc_common::random::Rng;
+
+mod day1;
+mod day10;
+mod day11;
+mod day12;
+mod day13;
+mod day14;
+mod day15;
+mod day16;
+mod day17;
+mod day18;
+mod day19;
+mod day2;
+mod day20;
+mod day21;
+mod day22;
+mod day23;
+mod day24;
+mod day25;
+mod day3;
+mod day4;
+mod day5;
+mod day6;
+mod day7;
+mod day8;
+mod day9;
+
+pub type Generator = fn(&mut Rng, usize) -> Generated;
+
+//  Each day's generator, with the size of the real puzzle input.
+pub fn generator(day: usize) -> Option<(Generator, usize)> {
+    match day {
+        1 => Some((day1::generate, day1::SIZE)),
+        2 => Some((day2::generate, day2::SIZE)),
+        3 => Some((day3::generate, day3::SIZE)),
+        4 => Some((day4::generate, day4::SIZE)),
+        5 => Some((day5::generate, day5::SIZE)),
+        6 => Some((day6::generate, day6::SIZE)),
+        7 => Some((day7::generate, day7::SIZE)),
+        8 => Some((day8::generate, day8::SIZE)),
+        9 => Some((day9::generate, day9::SIZE)),
+        10 => Some((day10::generate, day10::SIZE)),
+        11 => Some((day11::generate, day11::SIZE)),
+        12 => Some((day12::generate, day12::SIZE)),
+        13 => Some((day13::generate, day13::SIZE)),
+        14 => Some((day14::generate, day14::SIZE)),
+        15 => Some((day15::generate, day15::SIZE)),
+        16 => Some((day16::generate, day16::SIZE)),
+        17 => Some((day17::generate, day17::SIZE)),
+        18 => Some((day18::generate, day18::SIZE)),
+        19 => Some((day19::generate, day19::SIZE)),
+        20 => Some((day20::generate, day20::SIZE)),
+        21 => Some((day21::generate, day21::SIZE)),
+        22 => Some((day22::generate, day22::SIZE)),
+        23 => Some((day23::generate, day23::SIZE)),
+        24 => Some((day24::generate, day24::SIZE)),
+        25 => Some((day25::generate, day25::SIZE)),
+        _ => None,
+    }
+}
+
+//  Picks one of the items at random.
+fn pick<'a, T>(rng: &mut Rng, items: &'a [T]) -> &'a T {
+    &items[rng.below(items.len())]
+}
+
+//  A random number in low..=high.
+fn between(rng: &mut Rng, low: usize, high: usize) -> usize {
+    low + rng.below(high - low + 1)
+}
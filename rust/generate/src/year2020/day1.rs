@@ -0,0 +1,84 @@
+use super::between;
+use crate::Generated;
+use aoc_common::random::Rng;
+use std::collections::HashSet;
+
+pub const SIZE: usize = 200;
+
+//  Whether adding the entry would give another pair or triple that sums to
+//  2020. Sums that use one entry more than once count too, so that a solution
+//  can't find a different answer by reusing an entry.
+fn makes_a_sum(present: &HashSet<i32>, entry: i32) -> bool {
+    entry * 2 == 2020
+        || present.contains(&(2020 - entry))
+        || present.contains(&(2020 - entry * 2))
+        || present
+            .iter()
+            .any(|&other| present.contains(&(2020 - entry - other)))
+}
+
+//  The number of pairs and triples of entries that sum to 2020, counting
+//  those that use an entry more than once.
+fn count_sums(entries: &[i32]) -> (usize, usize) {
+    let mut pairs = 0;
+    let mut triples = 0;
+
+    for i in 0..entries.len() {
+        for j in i..entries.len() {
+            if entries[i] + entries[j] == 2020 {
+                pairs += 1;
+            }
+
+            for k in j..entries.len() {
+                if entries[i] + entries[j] + entries[k] == 2020 {
+                    triples += 1;
+                }
+            }
+        }
+    }
+
+    (pairs, triples)
+}
+
+//  Plants one pair and one triple that sum to 2020, then fills up with
+//  entries that don't make any more of either.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let (pair, first, second) = loop {
+        let pair = between(rng, 100, 900) as i32;
+        let first = between(rng, 10, 600) as i32;
+        let second = between(rng, 10, 600) as i32;
+        let planted = [pair, 2020 - pair, first, second, 2020 - first - second];
+
+        if count_sums(&planted) == (1, 1) {
+            break (pair, first, second);
+        }
+    };
+
+    let planted = [pair, 2020 - pair, first, second, 2020 - first - second];
+    let mut entries = planted.to_vec();
+    let mut present: HashSet<i32> = entries.iter().copied().collect();
+    while entries.len() < size.max(5) {
+        //  Most of a real report is over 1010, which can't add up to 2020
+        //  with each other.
+        let entry = if rng.chance(80) {
+            between(rng, 1011, 2019)
+        } else {
+            between(rng, 1, 1009)
+        } as i32;
+
+        //  Another copy of an entry can't make a new sum, as using an entry
+        //  twice has already been ruled out.
+        if !planted.contains(&entry) && (present.contains(&entry) || !makes_a_sum(&present, entry))
+        {
+            entries.push(entry);
+            present.insert(entry);
+        }
+    }
+
+    let part1 = pair * (2020 - pair);
+    let part2 = first * second * (2020 - first - second);
+    rng.shuffle(&mut entries);
+
+    let lines: Vec<String> = entries.iter().map(|entry| entry.to_string()).collect();
+    Generated::new(lines.join("\n")).part1(part1).part2(part2)
+}
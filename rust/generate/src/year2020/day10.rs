@@ -0,0 +1,55 @@
+use super::between;
+use crate::Generated;
+use aoc_common::random::Rng;
+
+pub const SIZE: usize = 100;
+
+//  Adapters that are 1 or 3 jolts apart, with at most four 1 jolt steps in a
+//  row as in the real puzzle. Every extra adapter multiplies the number of
+//  arrangements, so part two is left out once it no longer fits in a u64.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let mut adapters = vec![];
+    let mut joltage = 0;
+    let mut ones = 0;
+    let mut threes = 1;
+
+    while adapters.len() < size.max(1) {
+        for _ in 0..between(rng, 1, 4) {
+            joltage += 1;
+            ones += 1;
+            adapters.push(joltage);
+        }
+
+        joltage += 3;
+        threes += 1;
+        adapters.push(joltage);
+    }
+
+    //  The number of ways of getting to each joltage from the outlet, which
+    //  starts off two places into the list.
+    let mut ways: Vec<Option<u64>> = vec![Some(0), Some(0), Some(1)];
+    let mut next = 1;
+    for &adapter in &adapters {
+        while next < adapter {
+            ways.push(Some(0));
+            next += 1;
+        }
+        let last = ways.len();
+        ways.push(
+            ways[last - 3..]
+                .iter()
+                .try_fold(0_u64, |total, &way| total.checked_add(way?)),
+        );
+        next += 1;
+    }
+
+    let part2 = *ways.last().unwrap();
+    rng.shuffle(&mut adapters);
+
+    let lines: Vec<String> = adapters.iter().map(|adapter| adapter.to_string()).collect();
+    let generated = Generated::new(lines.join("\n")).part1(ones * threes);
+    match part2 {
+        Some(part2) => generated.part2(part2),
+        None => generated,
+    }
+}
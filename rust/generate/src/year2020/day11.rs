@@ -0,0 +1,105 @@
+use crate::Generated;
+use aoc_common::random::Rng;
+use std::collections::HashSet;
+
+pub const SIZE: usize = 95;
+
+const DIRECTIONS: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+#[cfg(test)]
+mod day11_tests {
+    use super::*;
+
+    #[test]
+    fn should_give_up_on_seats_that_never_settle() {
+        let seats: Vec<Vec<bool>> = [".LL.", "LLLL", "LLLL", ".LL."]
+            .iter()
+            .map(|row| row.chars().map(|c| c == 'L').collect())
+            .collect();
+
+        assert_eq!(None, settle(&seats, 1, 4));
+        assert_eq!(Some(0), settle(&[vec![false]], 1, 4));
+    }
+}
+
+//  Follows the rules a round at a time until nothing changes, then counts
+//  the occupied seats. Only looking at the adjacent seats is the same as
+//  looking along each line of sight for one step. Some layouts never settle,
+//  and go round the same states forever instead, which gives None.
+fn settle(seats: &[Vec<bool>], range: usize, crowd: usize) -> Option<usize> {
+    let (width, height) = (seats[0].len() as isize, seats.len() as isize);
+    let mut occupied = vec![vec![false; width as usize]; height as usize];
+    let mut seen = HashSet::new();
+
+    loop {
+        let neighbours = |x: isize, y: isize| {
+            DIRECTIONS
+                .iter()
+                .filter(|&&(dx, dy)| {
+                    (1..=range as isize)
+                        .map(|distance| (x + dx * distance, y + dy * distance))
+                        .take_while(|&(x, y)| x >= 0 && y >= 0 && x < width && y < height)
+                        .find(|&(x, y)| seats[y as usize][x as usize])
+                        .is_some_and(|(x, y)| occupied[y as usize][x as usize])
+                })
+                .count()
+        };
+
+        let next: Vec<Vec<bool>> = (0..height)
+            .map(|y| {
+                (0..width)
+                    .map(|x| {
+                        let (cell, count) = (occupied[y as usize][x as usize], neighbours(x, y));
+                        seats[y as usize][x as usize]
+                            && (!cell && count == 0 || cell && count < crowd)
+                    })
+                    .collect()
+            })
+            .collect();
+
+        if next == occupied {
+            return Some(occupied.iter().flatten().filter(|&&taken| taken).count());
+        }
+        if !seen.insert(occupied) {
+            return None;
+        }
+
+        occupied = next;
+    }
+}
+
+//  A square seating area with a scattering of floor, with the seats settled
+//  by a plain simulation of the rules. Layouts that never settle under both
+//  sets of rules are thrown away.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let size = size.max(1);
+    let (seats, part1, part2) = loop {
+        let seats: Vec<Vec<bool>> = (0..size)
+            .map(|_| (0..size).map(|_| rng.chance(75)).collect())
+            .collect();
+
+        if let (Some(part1), Some(part2)) = (settle(&seats, 1, 4), settle(&seats, size, 5)) {
+            break (seats, part1, part2);
+        }
+    };
+
+    let rows: Vec<String> = seats
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|&seat| if seat { 'L' } else { '.' })
+                .collect()
+        })
+        .collect();
+
+    Generated::new(rows.join("\n")).part1(part1).part2(part2)
+}
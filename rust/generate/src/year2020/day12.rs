@@ -0,0 +1,63 @@
+use super::{between, pick};
+use crate::Generated;
+use aoc_common::random::Rng;
+
+pub const SIZE: usize = 780;
+
+//  Turns (x, y) by a number of right angles, clockwise for a positive turn.
+fn turn((x, y): (isize, isize), right_angles: isize) -> (isize, isize) {
+    match right_angles.rem_euclid(4) {
+        0 => (x, y),
+        1 => (y, -x),
+        2 => (-x, -y),
+        _ => (-y, x),
+    }
+}
+
+//  Random instructions, with both ways of navigating worked out as they are
+//  laid down.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let mut lines = vec![];
+    let (mut ship, mut heading) = ((0_isize, 0_isize), (1_isize, 0_isize));
+    let (mut boat, mut waypoint) = ((0_isize, 0_isize), (10_isize, 1_isize));
+
+    for _ in 0..size {
+        let action = *pick(rng, &['N', 'S', 'E', 'W', 'L', 'R', 'F', 'F']);
+        let value = match action {
+            'L' | 'R' => 90 * between(rng, 1, 3),
+            _ => between(rng, 1, 100),
+        } as isize;
+
+        match action {
+            'L' | 'R' => {
+                let right_angles = if action == 'R' {
+                    value / 90
+                } else {
+                    -value / 90
+                };
+                heading = turn(heading, right_angles);
+                waypoint = turn(waypoint, right_angles);
+            }
+            'F' => {
+                ship = (ship.0 + heading.0 * value, ship.1 + heading.1 * value);
+                boat = (boat.0 + waypoint.0 * value, boat.1 + waypoint.1 * value);
+            }
+            _ => {
+                let (dx, dy) = match action {
+                    'N' => (0, value),
+                    'S' => (0, -value),
+                    'E' => (value, 0),
+                    _ => (-value, 0),
+                };
+                ship = (ship.0 + dx, ship.1 + dy);
+                waypoint = (waypoint.0 + dx, waypoint.1 + dy);
+            }
+        }
+
+        lines.push(format!("{}{}", action, value));
+    }
+
+    Generated::new(lines.join("\n"))
+        .part1(ship.0.abs() + ship.1.abs())
+        .part2(boat.0.abs() + boat.1.abs())
+}
@@ -0,0 +1,73 @@
+use super::between;
+use crate::Generated;
+use aoc_common::random::Rng;
+
+pub const SIZE: usize = 9;
+
+//  Keeps the product of the bus ids, and with it the answer to part two,
+//  well inside an i64.
+const LARGEST_PRODUCT: i64 = 1 << 56;
+
+fn is_prime(n: i64) -> bool {
+    n >= 2 && (2..).take_while(|d| d * d <= n).all(|d| n % d != 0)
+}
+
+//  Buses with prime ids, placed in the schedule so that the answer to part
+//  two is a time picked up front. Bus i leaves at t + i, so a bus with id p
+//  goes at any position that is -t modulo p and isn't already taken.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let primes: Vec<i64> = (7..1000).filter(|&n| is_prime(n)).collect();
+    let mut buses = vec![];
+    let mut product = 1_i64;
+
+    while buses.len() < size.max(1) {
+        let bus = primes[rng.below(primes.len())];
+        if buses.contains(&bus) || product.saturating_mul(bus) > LARGEST_PRODUCT {
+            if product.saturating_mul(primes[0]) > LARGEST_PRODUCT {
+                break;
+            }
+            continue;
+        }
+
+        buses.push(bus);
+        product *= bus;
+    }
+
+    let time = (rng.next() % product as u64) as i64;
+    let mut schedule: Vec<Option<i64>> = vec![];
+    for &bus in &buses {
+        let mut position = (-time).rem_euclid(bus) as usize;
+        while schedule.get(position).is_some_and(Option::is_some) {
+            position += bus as usize;
+        }
+
+        if schedule.len() <= position {
+            schedule.resize(position + 1, None);
+        }
+        schedule[position] = Some(bus);
+    }
+
+    //  The first bus to turn up after we reach the stop, where no two buses
+    //  turn up at the same time.
+    let (arrival, earliest, wait) = loop {
+        let arrival = between(rng, 100_000, 10_000_000) as i64;
+        let mut waits: Vec<(i64, i64)> = buses
+            .iter()
+            .map(|&bus| (bus - arrival % bus, bus))
+            .collect();
+        waits.sort_unstable();
+
+        if waits.len() == 1 || waits[0].0 != waits[1].0 {
+            break (arrival, waits[0].1, waits[0].0);
+        }
+    };
+
+    let ids: Vec<String> = schedule
+        .iter()
+        .map(|bus| bus.map_or(String::from("x"), |id| id.to_string()))
+        .collect();
+
+    Generated::new(format!("{}\n{}", arrival, ids.join(",")))
+        .part1(earliest * wait)
+        .part2(time)
+}
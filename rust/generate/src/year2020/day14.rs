@@ -0,0 +1,71 @@
+use super::between;
+use crate::Generated;
+use aoc_common::random::Rng;
+use std::collections::HashMap;
+
+pub const SIZE: usize = 570;
+
+const BITS: usize = 36;
+
+//  Every address that a floating mask writes to.
+fn addresses(address: u64, mask: &[u8]) -> Vec<u64> {
+    mask.iter()
+        .enumerate()
+        .fold(vec![0], |addresses, (i, &bit)| {
+            let bit_of_address = address >> (BITS - 1 - i) & 1;
+            let options: &[u64] = match bit {
+                b'X' => &[0, 1],
+                b'1' => &[1],
+                _ => &[bit_of_address],
+            };
+
+            addresses
+                .iter()
+                .flat_map(|address| options.iter().map(move |option| address * 2 + option))
+                .collect()
+        })
+}
+
+//  Masks with only a few floating bits, so that part two doesn't write to
+//  more than a few hundred addresses at a time, and both versions of the
+//  docking program run alongside.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let mut lines = vec![];
+    let mut mask = vec![b'0'; BITS];
+    let mut values = HashMap::<u64, u64>::new();
+    let mut decoded = HashMap::<u64, u64>::new();
+
+    for i in 0..size.max(1) {
+        if i == 0 || rng.chance(20) {
+            mask = (0..BITS)
+                .map(|_| if rng.chance(50) { b'1' } else { b'0' })
+                .collect();
+            for _ in 0..between(rng, 2, 8) {
+                mask[rng.below(BITS)] = b'X';
+            }
+
+            lines.push(format!("mask = {}", String::from_utf8_lossy(&mask)));
+            continue;
+        }
+
+        let address = rng.below(1 << 16) as u64;
+        let value = rng.next() >> (64 - BITS);
+        let masked = mask
+            .iter()
+            .fold(0, |masked, &bit| masked * 2 + (bit == b'1') as u64);
+        let floating = mask
+            .iter()
+            .fold(0, |floating, &bit| floating * 2 + (bit == b'X') as u64);
+
+        values.insert(address, value & floating | masked);
+        for decoded_address in addresses(address, &mask) {
+            decoded.insert(decoded_address, value);
+        }
+
+        lines.push(format!("mem[{}] = {}", address, value));
+    }
+
+    Generated::new(lines.join("\n"))
+        .part1(values.values().sum::<u64>())
+        .part2(decoded.values().sum::<u64>())
+}
@@ -0,0 +1,29 @@
+use crate::Generated;
+use aoc_common::random::Rng;
+
+pub const SIZE: usize = 7;
+
+//  The size is the number of starting numbers. Part two plays for thirty
+//  million turns, which is no quicker done here than by the solution, so
+//  only part one is worked out.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let count = size.max(1);
+    let mut starting: Vec<usize> = (0..count * 3).collect();
+    rng.shuffle(&mut starting);
+    starting.truncate(count);
+
+    let mut last_spoken = vec![None; 2020.max(count * 3)];
+    let mut spoken = starting[0];
+    for turn in 1..2020 {
+        let next = match starting.get(turn) {
+            Some(&number) => number,
+            None => last_spoken[spoken].map_or(0, |last| turn - 1 - last),
+        };
+
+        last_spoken[spoken] = Some(turn - 1);
+        spoken = next;
+    }
+
+    let numbers: Vec<String> = starting.iter().map(|number| number.to_string()).collect();
+    Generated::new(numbers.join(",")).part1(spoken)
+}
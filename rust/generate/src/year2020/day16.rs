@@ -0,0 +1,119 @@
+use super::{between, pick};
+use crate::Generated;
+use aoc_common::random::Rng;
+
+pub const SIZE: usize = 240;
+
+const FIELDS: [&str; 20] = [
+    "departure location",
+    "departure station",
+    "departure platform",
+    "departure track",
+    "departure date",
+    "departure time",
+    "arrival location",
+    "arrival station",
+    "arrival platform",
+    "arrival track",
+    "class",
+    "duration",
+    "price",
+    "route",
+    "row",
+    "seat",
+    "train",
+    "type",
+    "wagon",
+    "zone",
+];
+
+//  Each field's values come from a band of its own, and only the lower part
+//  of each band is ever on a ticket.
+const BAND: usize = 50;
+const USED: usize = 40;
+
+//  Each field is given a rank, and a field accepts the values of the bands
+//  for its own rank and every rank above it. So the highest ranked field fits
+//  only one position, the next one fits that and one other, and so on down,
+//  which leaves just one order of fields. The ranges of a field are split
+//  around a gap that no ticket uses, and there is a spare band on top so the
+//  gap can come after the last band too.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let fields = FIELDS.len();
+    let mut order: Vec<usize> = (0..fields).collect();
+    rng.shuffle(&mut order);
+    let mut positions: Vec<usize> = (0..fields).collect();
+    rng.shuffle(&mut positions);
+
+    let base = between(rng, 1, 30);
+    let band = |n: usize| base + n * BAND;
+    let top = band(fields + 1) - 1;
+
+    let mut rules = vec![];
+    for (rank, &field) in order.iter().enumerate() {
+        let gap = band(between(rng, rank, fields - 1)) + USED;
+        rules.push((
+            field,
+            format!(
+                "{}-{} or {}-{}",
+                band(rank),
+                gap - 1,
+                gap + BAND - USED,
+                top
+            ),
+        ));
+    }
+    rules.sort_unstable();
+
+    //  The field of each rank goes at one of the positions on the ticket.
+    let ticket = |rng: &mut Rng| {
+        let mut values = vec![0; fields];
+        for rank in 0..fields {
+            values[positions[rank]] = band(rank) + rng.below(USED);
+        }
+        values
+    };
+
+    let yours = ticket(rng);
+    let mut nearby = vec![];
+    let mut error_rate = 0;
+    for i in 0..size.max(1) {
+        let mut values = ticket(rng);
+
+        //  The first ticket is always valid, so there is at least one.
+        if i > 0 && rng.chance(25) {
+            let beyond = top + between(rng, 1, 100);
+            let invalid = *pick(rng, &[0, beyond]);
+            values[rng.below(fields)] = invalid;
+            error_rate += invalid;
+        }
+
+        nearby.push(values);
+    }
+    rng.shuffle(&mut nearby);
+
+    let departure: usize = (0..fields)
+        .filter(|&rank| FIELDS[order[rank]].starts_with("departure"))
+        .map(|rank| yours[positions[rank]])
+        .product();
+
+    let list = |values: &[usize]| {
+        let values: Vec<String> = values.iter().map(|value| value.to_string()).collect();
+        values.join(",")
+    };
+
+    let rules: Vec<String> = rules
+        .into_iter()
+        .map(|(field, ranges)| format!("{}: {}", FIELDS[field], ranges))
+        .collect();
+    let nearby: Vec<String> = nearby.iter().map(|values| list(values)).collect();
+
+    Generated::new(format!(
+        "{}\n\nyour ticket:\n{}\n\nnearby tickets:\n{}",
+        rules.join("\n"),
+        list(&yours),
+        nearby.join("\n")
+    ))
+    .part1(error_rate)
+    .part2(departure)
+}
@@ -0,0 +1,77 @@
+use crate::Generated;
+use aoc_common::random::Rng;
+use std::collections::{HashMap, HashSet};
+
+pub const SIZE: usize = 8;
+
+const CYCLES: usize = 6;
+
+//  Boots up the pocket dimension the simple way, by counting every active
+//  cube's neighbours into a map. Cubes are stored with four coordinates, and
+//  the unused ones stay at zero.
+fn boot(active: &[(isize, isize)], dimensions: usize) -> usize {
+    let mut cubes: HashSet<[isize; 4]> = active.iter().map(|&(x, y)| [x, y, 0, 0]).collect();
+    let offsets: Vec<[isize; 4]> = (0..3_usize.pow(dimensions as u32))
+        .map(|i| {
+            let mut offset = [0; 4];
+            for (axis, delta) in offset.iter_mut().enumerate().take(dimensions) {
+                *delta = (i / 3_usize.pow(axis as u32) % 3) as isize - 1;
+            }
+            offset
+        })
+        .filter(|&offset| offset != [0; 4])
+        .collect();
+
+    for _ in 0..CYCLES {
+        let mut neighbours = HashMap::<[isize; 4], usize>::new();
+        for cube in &cubes {
+            for offset in &offsets {
+                let mut neighbour = *cube;
+                for (value, delta) in neighbour.iter_mut().zip(offset) {
+                    *value += delta;
+                }
+                *neighbours.entry(neighbour).or_insert(0) += 1;
+            }
+        }
+
+        cubes = neighbours
+            .into_iter()
+            .filter(|(cube, count)| *count == 3 || *count == 2 && cubes.contains(cube))
+            .map(|(cube, _)| cube)
+            .collect();
+    }
+
+    cubes.len()
+}
+
+//  A square starting slice, about half of it active.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let size = size.max(1);
+    let rows: Vec<Vec<bool>> = (0..size)
+        .map(|_| (0..size).map(|_| rng.chance(50)).collect())
+        .collect();
+
+    let active: Vec<(isize, isize)> = rows
+        .iter()
+        .enumerate()
+        .flat_map(|(y, row)| {
+            row.iter()
+                .enumerate()
+                .filter(|(_, &cube)| cube)
+                .map(move |(x, _)| (x as isize, y as isize))
+        })
+        .collect();
+
+    let lines: Vec<String> = rows
+        .iter()
+        .map(|row| {
+            row.iter()
+                .map(|&cube| if cube { '#' } else { '.' })
+                .collect()
+        })
+        .collect();
+
+    Generated::new(lines.join("\n"))
+        .part1(boot(&active, 3))
+        .part2(boot(&active, 4))
+}
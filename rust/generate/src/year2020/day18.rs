@@ -0,0 +1,107 @@
+use super::between;
+use crate::Generated;
+use aoc_common::random::Rng;
+
+pub const SIZE: usize = 375;
+
+const LARGEST: usize = 1 << 40;
+
+enum Expression {
+    Number(usize),
+    //  The first operand, then each operator and operand after it, where
+    //  true is an addition.
+    Chain(Box<Expression>, Vec<(bool, Expression)>),
+}
+
+fn expression(rng: &mut Rng, depth: usize) -> Expression {
+    let operand = |rng: &mut Rng| {
+        if depth < 2 && rng.chance(25) {
+            expression(rng, depth + 1)
+        } else {
+            Expression::Number(between(rng, 1, 9))
+        }
+    };
+
+    let first = operand(rng);
+    let rest = (0..between(rng, 1, 5))
+        .map(|_| (rng.chance(50), operand(rng)))
+        .collect();
+    Expression::Chain(Box::new(first), rest)
+}
+
+impl Expression {
+    fn write(&self, nested: bool) -> String {
+        match self {
+            Expression::Number(n) => n.to_string(),
+            Expression::Chain(first, rest) => {
+                let mut text = first.write(true);
+                for (addition, operand) in rest {
+                    text.push_str(if *addition { " + " } else { " * " });
+                    text.push_str(&operand.write(true));
+                }
+
+                if nested {
+                    format!("({})", text)
+                } else {
+                    text
+                }
+            }
+        }
+    }
+
+    //  Left to right, or with the additions done before the products. None if
+    //  the value gets too big for a usize.
+    fn evaluate(&self, additions_first: bool) -> Option<usize> {
+        match self {
+            Expression::Number(n) => Some(*n),
+            Expression::Chain(first, rest) if additions_first => {
+                let mut products = vec![first.evaluate(true)?];
+                for (addition, operand) in rest {
+                    let value = operand.evaluate(true)?;
+                    if *addition {
+                        let last = products.last_mut().unwrap();
+                        *last = last.checked_add(value)?;
+                    } else {
+                        products.push(value);
+                    }
+                }
+                products.into_iter().try_fold(1, usize::checked_mul)
+            }
+            Expression::Chain(first, rest) => {
+                rest.iter()
+                    .try_fold(first.evaluate(false)?, |total, (addition, operand)| {
+                        let value = operand.evaluate(false)?;
+                        if *addition {
+                            total.checked_add(value)
+                        } else {
+                            total.checked_mul(value)
+                        }
+                    })
+            }
+        }
+    }
+}
+
+//  Expressions of single digits with brackets nested at most two deep, and
+//  kept to values much like the real puzzle's so that the sums fit.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let mut lines = vec![];
+    let mut left_to_right = 0;
+    let mut additions_first = 0;
+
+    while lines.len() < size {
+        let expression = expression(rng, 0);
+        if let (Some(first), Some(second)) = (expression.evaluate(false), expression.evaluate(true))
+        {
+            if first.max(second) <= LARGEST {
+                left_to_right += first;
+                additions_first += second;
+                lines.push(expression.write(false));
+            }
+        }
+    }
+
+    Generated::new(lines.join("\n"))
+        .part1(left_to_right)
+        .part2(additions_first)
+}
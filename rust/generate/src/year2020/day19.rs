@@ -0,0 +1,163 @@
+use super::between;
+use crate::Generated;
+use aoc_common::random::Rng;
+
+pub const SIZE: usize = 470;
+
+//  Rules 42 and 31 each match half of the strings of this length.
+const CHUNK: usize = 5;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Chunk {
+    FortyTwo,
+    ThirtyOne,
+}
+
+struct Grammar {
+    rules: Vec<(usize, String)>,
+    next: usize,
+}
+
+impl Grammar {
+    fn rule(&mut self, body: String) -> usize {
+        while [0, 8, 11, 31, 42].contains(&self.next) {
+            self.next += 1;
+        }
+
+        self.add(self.next, body);
+        self.next += 1;
+        self.next - 1
+    }
+
+    fn add(&mut self, index: usize, body: String) {
+        self.rules.push((index, body));
+    }
+
+    //  A rule that matches exactly the given strings, which are all the same
+    //  length, by splitting them on their first letter.
+    fn matching(&mut self, strings: &[Vec<u8>], letters: [usize; 2]) -> String {
+        let mut options = vec![];
+        for (letter, &rule) in [b'a', b'b'].iter().zip(letters.iter()) {
+            let rest: Vec<Vec<u8>> = strings
+                .iter()
+                .filter(|string| string[0] == *letter)
+                .map(|string| string[1..].to_vec())
+                .collect();
+
+            if rest.is_empty() {
+                continue;
+            }
+
+            if rest[0].is_empty() {
+                options.push(rule.to_string());
+            } else {
+                let body = self.matching(&rest, letters);
+                let after = self.rule(body);
+                options.push(format!("{} {}", rule, after));
+            }
+        }
+
+        options.join(" | ")
+    }
+}
+
+//  Each chunk of a message is matched by one rule or the other, so whether a
+//  message is valid comes down to its sequence of chunks.
+fn classify(chunks: &[Chunk], loops: bool) -> bool {
+    let forty_twos = chunks.iter().take_while(|&&c| c == Chunk::FortyTwo).count();
+    let thirty_ones = chunks.len() - forty_twos;
+
+    chunks[forty_twos..].iter().all(|&c| c == Chunk::ThirtyOne)
+        && if loops {
+            thirty_ones >= 1 && forty_twos > thirty_ones
+        } else {
+            forty_twos == 2 && thirty_ones == 1
+        }
+}
+
+//  Rule 0 is 8 11, where 8 is 42 and 11 is 42 31, and part two turns those
+//  into loops. Messages are made of chunks that each match 42 or 31, with
+//  some planted to match each part and some random.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let mut strings: Vec<Vec<u8>> = (0..1 << CHUNK)
+        .map(|n: usize| {
+            (0..CHUNK)
+                .map(|bit| if n >> bit & 1 == 1 { b'b' } else { b'a' })
+                .collect()
+        })
+        .collect();
+    rng.shuffle(&mut strings);
+    let (forty_two, thirty_one) = strings.split_at(strings.len() / 2);
+
+    let mut grammar = Grammar {
+        rules: vec![],
+        next: 1,
+    };
+    let a = grammar.rule(String::from("\"a\""));
+    let b = grammar.rule(String::from("\"b\""));
+    let body = grammar.matching(forty_two, [a, b]);
+    grammar.add(42, body);
+    let body = grammar.matching(thirty_one, [a, b]);
+    grammar.add(31, body);
+    grammar.add(0, String::from("8 11"));
+    grammar.add(8, String::from("42"));
+    grammar.add(11, String::from("42 31"));
+
+    let mut rules = grammar.rules;
+    rng.shuffle(&mut rules);
+
+    let mut messages = vec![];
+    let mut first = 0;
+    let mut second = 0;
+    //  Without a message there is no section after the blank line to read.
+    for _ in 0..size.max(1) {
+        let chunks: Vec<Chunk> = match rng.below(3) {
+            0 => vec![Chunk::FortyTwo, Chunk::FortyTwo, Chunk::ThirtyOne],
+            //  The solution only unrolls the loop in rule 11 four times.
+            1 => {
+                let thirty_ones = between(rng, 1, 4);
+                let forty_twos = between(rng, thirty_ones + 1, thirty_ones + 3);
+                let mut chunks = vec![Chunk::FortyTwo; forty_twos];
+                chunks.extend(vec![Chunk::ThirtyOne; thirty_ones]);
+                chunks
+            }
+            _ => (0..between(rng, 1, 8))
+                .map(|_| {
+                    if rng.chance(60) {
+                        Chunk::FortyTwo
+                    } else {
+                        Chunk::ThirtyOne
+                    }
+                })
+                .collect(),
+        };
+
+        let mut message = vec![];
+        for &chunk in &chunks {
+            let choices = match chunk {
+                Chunk::FortyTwo => forty_two,
+                Chunk::ThirtyOne => thirty_one,
+            };
+            message.extend(&choices[rng.below(choices.len())]);
+        }
+
+        //  A stray letter throws the chunks out of line, so nothing matches.
+        if rng.chance(10) {
+            message.insert(rng.below(message.len() + 1), b'a');
+        } else {
+            first += classify(&chunks, false) as usize;
+            second += classify(&chunks, true) as usize;
+        }
+
+        messages.push(String::from_utf8(message).unwrap());
+    }
+
+    let rules: Vec<String> = rules
+        .into_iter()
+        .map(|(index, body)| format!("{}: {}", index, body))
+        .collect();
+
+    Generated::new(format!("{}\n\n{}", rules.join("\n"), messages.join("\n")))
+        .part1(first)
+        .part2(second)
+}
@@ -0,0 +1,41 @@
+use super::between;
+use crate::Generated;
+use aoc_common::random::Rng;
+
+pub const SIZE: usize = 1000;
+
+//  Passwords drawn from a few letters, so that the policy's letter turns up
+//  often enough for both policies to pass and fail.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let mut lines = vec![];
+    let mut old_policy = 0;
+    let mut new_policy = 0;
+
+    for _ in 0..size {
+        let first = between(rng, 1, 8);
+        let second = between(rng, first + 1, first + 12);
+        let letters: Vec<char> = (0..between(rng, 2, 5))
+            .map(|_| (b'a' + rng.below(26) as u8) as char)
+            .collect();
+        let letter = letters[0];
+        let password: String = (0..between(rng, first, second + 4))
+            .map(|_| letters[rng.below(letters.len())])
+            .collect();
+
+        let count = password.chars().filter(|&c| c == letter).count();
+        if count >= first && count <= second {
+            old_policy += 1;
+        }
+
+        let at = |position: usize| password.chars().nth(position - 1) == Some(letter);
+        if at(first) != at(second) {
+            new_policy += 1;
+        }
+
+        lines.push(format!("{}-{} {}: {}", first, second, letter, password));
+    }
+
+    Generated::new(lines.join("\n"))
+        .part1(old_policy)
+        .part2(new_policy)
+}
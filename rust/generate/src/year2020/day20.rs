@@ -0,0 +1,186 @@
+use super::between;
+use crate::Generated;
+use aoc_common::random::Rng;
+use std::collections::HashSet;
+
+pub const SIZE: usize = 12;
+
+const MONSTER: [&str; 3] = [
+    "                  # ",
+    "#    ##    ##    ###",
+    " #  #  #  #  #  #   ",
+];
+const MONSTER_WIDTH: usize = 20;
+
+type Picture = Vec<Vec<bool>>;
+
+fn rotate(picture: &Picture) -> Picture {
+    (0..picture[0].len())
+        .map(|x| picture.iter().rev().map(|row| row[x]).collect())
+        .collect()
+}
+
+fn monster_cells() -> impl Iterator<Item = (usize, usize)> {
+    MONSTER.iter().enumerate().flat_map(|(y, row)| {
+        row.char_indices()
+            .filter(|&(_, c)| c == '#')
+            .map(move |(x, _)| (x, y))
+    })
+}
+
+fn count_monsters(picture: &Picture) -> usize {
+    let size = picture.len();
+    (0..=size - MONSTER.len())
+        .flat_map(|y| (0..=size - MONSTER_WIDTH).map(move |x| (x, y)))
+        .filter(|&(x, y)| monster_cells().all(|(dx, dy)| picture[y + dy][x + dx]))
+        .count()
+}
+
+//  Sea monsters in every orientation of the picture but the one they were
+//  planted in.
+fn stray_monsters(picture: &Picture) -> usize {
+    let mut picture = picture.clone();
+    let mut count = 0;
+
+    for orientation in 1..8 {
+        picture = rotate(&picture);
+        if orientation == 4 {
+            picture.reverse();
+        }
+        count += count_monsters(&picture);
+    }
+
+    count
+}
+
+//  Rewrites the inside of each tile edge until no two edges match in either
+//  direction, as in the real puzzle, so there is only one arrangement.
+fn make_edges_unique(picture: &mut Picture, side: usize, rng: &mut Rng) {
+    let mut used = HashSet::new();
+
+    for edge in 0..(side + 1) * side * 2 {
+        let (line, block) = (edge / 2 / side * 9, edge / 2 % side * 9);
+        let pixel = |i: usize| match edge % 2 {
+            0 => (line, block + i),
+            _ => (block + i, line),
+        };
+
+        loop {
+            for i in 1..9 {
+                let (y, x) = pixel(i);
+                picture[y][x] = rng.chance(50);
+            }
+
+            let value: Vec<bool> = (0..10)
+                .map(|i| {
+                    let (y, x) = pixel(i);
+                    picture[y][x]
+                })
+                .collect();
+            let reversed: Vec<bool> = value.iter().rev().copied().collect();
+
+            if !used.contains(&value) && !used.contains(&reversed) {
+                used.insert(value);
+                used.insert(reversed);
+                break;
+            }
+        }
+    }
+}
+
+//  The size is the number of tiles along each side of the image, which is
+//  kept to between 3, the fewest a sea monster fits in, and 12, after which
+//  10 pixel edges run out of ways to be unique.
+//
+//  The image is a sparse random one with sea monsters planted in it, and is
+//  regenerated until no other monsters turn up by chance. Then it is cut into
+//  tiles that share their edges, which are shuffled, rotated and flipped.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let side = size.clamp(3, 12);
+    let width = side * 8;
+
+    let (image, monsters) = loop {
+        let mut image: Picture = (0..width)
+            .map(|_| (0..width).map(|_| rng.chance(30)).collect())
+            .collect();
+
+        let mut planted: Vec<(usize, usize)> = vec![];
+        for _ in 0..width * width / 100 {
+            let x = rng.below(width - MONSTER_WIDTH + 1);
+            let y = rng.below(width - MONSTER.len() + 1);
+            let overlaps = planted.iter().any(|&(other_x, other_y)| {
+                x < other_x + MONSTER_WIDTH
+                    && other_x < x + MONSTER_WIDTH
+                    && y < other_y + MONSTER.len()
+                    && other_y < y + MONSTER.len()
+            });
+
+            if !overlaps {
+                planted.push((x, y));
+                for (dx, dy) in monster_cells() {
+                    image[y + dy][x + dx] = true;
+                }
+            }
+        }
+
+        if count_monsters(&image) == planted.len() && stray_monsters(&image) == 0 {
+            break (image, planted.len());
+        }
+    };
+
+    //  The image is the inside of each tile, so put a border round each one.
+    let full = side * 9 + 1;
+    let mut picture: Picture = (0..full)
+        .map(|y| {
+            (0..full)
+                .map(|x| match (x % 9, y % 9) {
+                    (0, _) | (_, 0) => rng.chance(50),
+                    _ => image[y - y / 9 - 1][x - x / 9 - 1],
+                })
+                .collect()
+        })
+        .collect();
+    make_edges_unique(&mut picture, side, rng);
+
+    let mut numbers = HashSet::new();
+    while numbers.len() < side * side {
+        numbers.insert(between(rng, 1000, 9999));
+    }
+    let mut numbers: Vec<usize> = numbers.into_iter().collect();
+    numbers.sort_unstable();
+    rng.shuffle(&mut numbers);
+
+    let corners: usize = [0, side - 1, side * (side - 1), side * side - 1]
+        .iter()
+        .map(|&corner| numbers[corner])
+        .product();
+
+    let mut tiles: Vec<String> = (0..side * side)
+        .map(|i| {
+            let (x, y) = (i % side * 9, i / side * 9);
+            let mut tile: Picture = picture[y..y + 10]
+                .iter()
+                .map(|row| row[x..x + 10].to_vec())
+                .collect();
+
+            for _ in 0..rng.below(4) {
+                tile = rotate(&tile);
+            }
+            if rng.chance(50) {
+                tile.reverse();
+            }
+
+            let rows: Vec<String> = tile
+                .iter()
+                .map(|row| row.iter().map(|&on| if on { '#' } else { '.' }).collect())
+                .collect();
+            format!("Tile {}:\n{}", numbers[i], rows.join("\n"))
+        })
+        .collect();
+    rng.shuffle(&mut tiles);
+
+    let roughness = image.iter().flatten().filter(|&&on| on).count() - monsters * 15;
+    Generated::new(tiles.join("\n\n"))
+        .part1(corners)
+        .part2(roughness)
+}
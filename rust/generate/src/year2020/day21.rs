@@ -0,0 +1,93 @@
+use super::between;
+use crate::Generated;
+use aoc_common::random::Rng;
+use std::collections::HashSet;
+
+pub const SIZE: usize = 40;
+
+const ALLERGENS: [&str; 8] = [
+    "dairy", "eggs", "fish", "nuts", "peanuts", "sesame", "soy", "wheat",
+];
+const SAFE: usize = 200;
+
+fn ingredient(rng: &mut Rng) -> String {
+    (0..between(rng, 4, 8))
+        .map(|_| (b'a' + rng.below(26) as u8) as char)
+        .collect()
+}
+
+//  The allergens are ranked, and any food that lists an allergen has the
+//  dangerous ingredients for that allergen and every one ranked below it. So
+//  the lowest ranked allergen's ingredient is found first, then the next, and
+//  so on. Each allergen also gets two foods of its own with no safe
+//  ingredients in common, which rules the safe ones out.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let mut names = HashSet::new();
+    let mut ingredients = vec![];
+    while ingredients.len() < ALLERGENS.len() + SAFE {
+        let name = ingredient(rng);
+        if names.insert(name.clone()) {
+            ingredients.push(name);
+        }
+    }
+    let (dangerous, safe) = ingredients.split_at(ALLERGENS.len());
+
+    //  The allergen with each rank, which contains the dangerous ingredient
+    //  of the same rank.
+    let mut allergens: Vec<&str> = ALLERGENS.to_vec();
+    rng.shuffle(&mut allergens);
+
+    //  The ranks of the dangerous ingredients in each food, the safe ones it
+    //  has, and the ranks of the allergens it lists.
+    let mut foods: Vec<(Vec<usize>, Vec<usize>, Vec<usize>)> = vec![];
+    while foods.len() < size {
+        let listed: Vec<usize> = (0..allergens.len()).filter(|_| rng.chance(25)).collect();
+        if let Some(&highest) = listed.iter().max() {
+            let unlisted = (highest + 1..allergens.len()).filter(|_| rng.chance(30));
+            let ranks = (0..=highest).chain(unlisted).collect();
+            let safe_ones = (0..SAFE).filter(|_| rng.chance(8)).collect();
+            foods.push((ranks, safe_ones, listed));
+        }
+    }
+
+    for rank in 0..allergens.len() {
+        let mut shuffled: Vec<usize> = (0..SAFE).collect();
+        rng.shuffle(&mut shuffled);
+
+        for half in shuffled.chunks(SAFE / 2) {
+            let safe_ones = half.iter().copied().filter(|_| rng.chance(10)).collect();
+            foods.push(((0..=rank).collect(), safe_ones, vec![rank]));
+        }
+    }
+    rng.shuffle(&mut foods);
+
+    let lines: Vec<String> = foods
+        .iter()
+        .map(|(ranks, safe_ones, listed)| {
+            let mut food: Vec<&str> = ranks.iter().map(|&rank| dangerous[rank].as_str()).collect();
+            food.extend(safe_ones.iter().map(|&i| safe[i].as_str()));
+            rng.shuffle(&mut food);
+
+            let mut listed: Vec<&str> = listed.iter().map(|&rank| allergens[rank]).collect();
+            rng.shuffle(&mut listed);
+
+            format!("{} (contains {})", food.join(" "), listed.join(", "))
+        })
+        .collect();
+
+    let mut by_allergen: Vec<usize> = (0..allergens.len()).collect();
+    by_allergen.sort_unstable_by_key(|&rank| allergens[rank]);
+    let canonical: Vec<&str> = by_allergen
+        .iter()
+        .map(|&rank| dangerous[rank].as_str())
+        .collect();
+
+    Generated::new(lines.join("\n"))
+        .part1(
+            foods
+                .iter()
+                .map(|(_, safe_ones, _)| safe_ones.len())
+                .sum::<usize>(),
+        )
+        .part2(canonical.join(","))
+}
@@ -0,0 +1,108 @@
+use crate::Generated;
+use aoc_common::random::Rng;
+use std::collections::{HashSet, VecDeque};
+
+pub const SIZE: usize = 25;
+
+type Deck = VecDeque<usize>;
+
+fn score(deck: &Deck) -> usize {
+    deck.iter()
+        .rev()
+        .enumerate()
+        .map(|(i, card)| card * (i + 1))
+        .sum()
+}
+
+//  The winning deck, or None if the game goes on for too long to be
+//  finishing, since plain combat has no rule to stop it going round forever.
+fn combat(mut first: Deck, mut second: Deck) -> Option<Deck> {
+    for _ in 0..100_000 {
+        let (a, b) = match (first.pop_front(), second.pop_front()) {
+            (Some(a), Some(b)) => (a, b),
+            (Some(a), None) => {
+                first.push_front(a);
+                return Some(first);
+            }
+            (None, Some(b)) => {
+                second.push_front(b);
+                return Some(second);
+            }
+            (None, None) => unreachable!(),
+        };
+
+        if a > b {
+            first.extend([a, b].iter());
+        } else {
+            second.extend([b, a].iter());
+        }
+    }
+
+    None
+}
+
+//  Whether the first player wins, and the winning deck.
+fn recursive_combat(mut first: Deck, mut second: Deck) -> (bool, Deck) {
+    let mut seen = HashSet::new();
+
+    while !first.is_empty() && !second.is_empty() {
+        if !seen.insert((first.clone(), second.clone())) {
+            return (true, first);
+        }
+
+        let a = first.pop_front().unwrap();
+        let b = second.pop_front().unwrap();
+        let first_wins = if a <= first.len() && b <= second.len() {
+            recursive_combat(
+                first.iter().take(a).copied().collect(),
+                second.iter().take(b).copied().collect(),
+            )
+            .0
+        } else {
+            a > b
+        };
+
+        if first_wins {
+            first.extend([a, b].iter());
+        } else {
+            second.extend([b, a].iter());
+        }
+    }
+
+    if first.is_empty() {
+        (false, second)
+    } else {
+        (true, first)
+    }
+}
+
+//  The size is the number of cards each player has, dealt from a shuffled
+//  deck numbered from one.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let size = size.max(1);
+
+    let (first, second, winner) = loop {
+        let mut cards: Vec<usize> = (1..=size * 2).collect();
+        rng.shuffle(&mut cards);
+        let first: Deck = cards[..size].iter().copied().collect();
+        let second: Deck = cards[size..].iter().copied().collect();
+
+        if let Some(winner) = combat(first.clone(), second.clone()) {
+            break (first, second, winner);
+        }
+    };
+
+    let hand = |deck: &Deck| {
+        let cards: Vec<String> = deck.iter().map(|card| card.to_string()).collect();
+        cards.join("\n")
+    };
+    let input = format!(
+        "Player 1:\n{}\n\nPlayer 2:\n{}",
+        hand(&first),
+        hand(&second)
+    );
+
+    Generated::new(input)
+        .part1(score(&winner))
+        .part2(score(&recursive_combat(first, second).1))
+}
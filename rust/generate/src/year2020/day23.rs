@@ -0,0 +1,41 @@
+use crate::Generated;
+use aoc_common::random::Rng;
+
+pub const SIZE: usize = 9;
+
+const MOVES: usize = 100;
+
+//  There are always nine cups, so the size makes no difference. Part two
+//  plays ten million moves on a million cups, which is no quicker done here
+//  than by the solution, so only part one is worked out.
+pub fn generate(rng: &mut Rng, _: usize) -> Generated {
+    let mut labels: Vec<usize> = (1..=9).collect();
+    rng.shuffle(&mut labels);
+
+    //  Plays the game on the circle as it is written down, with the current
+    //  cup always kept at the front.
+    let mut cups = labels.clone();
+    for _ in 0..MOVES {
+        let current = cups[0];
+        let picked: Vec<usize> = cups.drain(1..4).collect();
+
+        let mut destination = current;
+        loop {
+            destination = if destination == 1 { 9 } else { destination - 1 };
+            if !picked.contains(&destination) {
+                break;
+            }
+        }
+
+        let at = cups.iter().position(|&cup| cup == destination).unwrap();
+        cups.splice(at + 1..at + 1, picked);
+        cups.rotate_left(1);
+    }
+
+    let one = cups.iter().position(|&cup| cup == 1).unwrap();
+    cups.rotate_left(one);
+
+    let labels: String = labels.iter().map(|label| label.to_string()).collect();
+    let after: String = cups[1..].iter().map(|label| label.to_string()).collect();
+    Generated::new(labels).part1(after)
+}
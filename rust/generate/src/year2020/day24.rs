@@ -0,0 +1,65 @@
+use super::between;
+use crate::Generated;
+use aoc_common::random::Rng;
+use std::collections::{HashMap, HashSet};
+
+pub const SIZE: usize = 400;
+
+const DAYS: usize = 100;
+
+//  Axial coordinates, with east along x and south east along y.
+const STEPS: [(&str, (isize, isize)); 6] = [
+    ("e", (1, 0)),
+    ("ne", (1, -1)),
+    ("se", (0, 1)),
+    ("w", (-1, 0)),
+    ("nw", (0, -1)),
+    ("sw", (-1, 1)),
+];
+
+//  Flips the floor the simple way, by counting every black tile's
+//  neighbours into a map.
+fn live(mut black: HashSet<(isize, isize)>) -> usize {
+    for _ in 0..DAYS {
+        let mut neighbours = HashMap::<(isize, isize), usize>::new();
+        for &(x, y) in &black {
+            for (_, (dx, dy)) in &STEPS {
+                *neighbours.entry((x + dx, y + dy)).or_insert(0) += 1;
+            }
+        }
+
+        black = neighbours
+            .into_iter()
+            .filter(|(tile, count)| *count == 2 || *count == 1 && black.contains(tile))
+            .map(|(tile, _)| tile)
+            .collect();
+    }
+
+    black.len()
+}
+
+//  Each line is a random walk to a tile near the middle of the floor, some of
+//  which are picked more than once so that they get flipped back.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let mut lines = vec![];
+    let mut black = HashSet::new();
+
+    for _ in 0..size {
+        let mut walk = vec![];
+        let mut tile = (0, 0);
+        for _ in 0..between(rng, 1, 25) {
+            let (name, (dx, dy)) = STEPS[rng.below(STEPS.len())];
+            walk.push(name);
+            tile = (tile.0 + dx, tile.1 + dy);
+        }
+
+        if !black.insert(tile) {
+            black.remove(&tile);
+        }
+        lines.push(walk.concat());
+    }
+
+    Generated::new(lines.join("\n"))
+        .part1(black.len())
+        .part2(live(black))
+}
@@ -0,0 +1,21 @@
+use crate::Generated;
+use aoc_common::numtheory::mod_pow;
+use aoc_common::random::Rng;
+
+pub const SIZE: usize = 1;
+
+const SUBJECT: u64 = 7;
+const MODULUS: u64 = 20201227;
+
+//  There are always two public keys, so the size makes no difference. The
+//  loop sizes are picked first, which gives the keys and the encryption key
+//  straight away. There is no part two.
+pub fn generate(rng: &mut Rng, _: usize) -> Generated {
+    let card_loop = 1 + rng.next() % (MODULUS - 2);
+    let door_loop = 1 + rng.next() % (MODULUS - 2);
+    let card_key = mod_pow(SUBJECT, card_loop, MODULUS);
+    let door_key = mod_pow(SUBJECT, door_loop, MODULUS);
+
+    Generated::new(format!("{}\n{}", card_key, door_key))
+        .part1(mod_pow(door_key, card_loop, MODULUS))
+}
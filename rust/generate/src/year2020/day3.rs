@@ -0,0 +1,39 @@
+use crate::Generated;
+use aoc_common::random::Rng;
+
+pub const SIZE: usize = 323;
+
+const WIDTH: usize = 31;
+const SLOPES: [(usize, usize); 5] = [(1, 1), (3, 1), (5, 1), (7, 1), (1, 2)];
+
+//  A map `size` rows high, with the trees counted along each slope as the
+//  rows are laid down, not counting the starting square.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let mut rows = vec![];
+    let mut trees = [0; SLOPES.len()];
+
+    for y in 0..size.max(1) {
+        let row: Vec<bool> = (0..WIDTH).map(|_| rng.chance(25)).collect();
+
+        for (count, &(right, down)) in trees.iter_mut().zip(SLOPES.iter()) {
+            if y > 0 && y.is_multiple_of(down) && row[y / down * right % WIDTH] {
+                *count += 1;
+            }
+        }
+
+        rows.push(
+            row.iter()
+                .map(|&tree| if tree { '#' } else { '.' })
+                .collect::<String>(),
+        );
+    }
+
+    let generated = Generated::new(rows.join("\n")).part1(trees[1]);
+    match trees
+        .iter()
+        .try_fold(1_usize, |product, &count| product.checked_mul(count))
+    {
+        Some(product) => generated.part2(product),
+        None => generated,
+    }
+}
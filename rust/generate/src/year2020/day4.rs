@@ -0,0 +1,112 @@
+use super::{between, pick};
+use crate::Generated;
+use aoc_common::random::Rng;
+
+pub const SIZE: usize = 290;
+
+const REQUIRED: [&str; 7] = ["byr", "iyr", "eyr", "hgt", "hcl", "ecl", "pid"];
+const EYE_COLOURS: [&str; 7] = ["amb", "blu", "brn", "gry", "grn", "hzl", "oth"];
+
+fn digits(rng: &mut Rng, count: usize) -> String {
+    (0..count)
+        .map(|_| (b'0' + rng.below(10) as u8) as char)
+        .collect()
+}
+
+fn hex_digits(rng: &mut Rng, count: usize) -> String {
+    (0..count)
+        .map(|_| *pick(rng, &b"0123456789abcdef"[..]) as char)
+        .collect()
+}
+
+fn valid_value(rng: &mut Rng, field: &str) -> String {
+    match field {
+        "byr" => between(rng, 1920, 2002).to_string(),
+        "iyr" => between(rng, 2010, 2020).to_string(),
+        "eyr" => between(rng, 2020, 2030).to_string(),
+        "hgt" if rng.chance(50) => format!("{}cm", between(rng, 150, 193)),
+        "hgt" => format!("{}in", between(rng, 59, 76)),
+        "hcl" => format!("#{}", hex_digits(rng, 6)),
+        "ecl" => pick(rng, &EYE_COLOURS).to_string(),
+        //  All zeros would be a valid id, but isn't a realistic one.
+        "pid" => format!("{}{}", between(rng, 1, 9), digits(rng, 8)),
+        _ => between(rng, 100, 350).to_string(),
+    }
+}
+
+fn invalid_value(rng: &mut Rng, field: &str) -> String {
+    match field {
+        "byr" => pick(rng, &[1919, 2003, 1850]).to_string(),
+        "iyr" => pick(rng, &[2009, 2021, 1999]).to_string(),
+        "eyr" => pick(rng, &[2019, 2031, 2050]).to_string(),
+        "hgt" => match rng.below(4) {
+            0 => format!("{}cm", between(rng, 100, 149)),
+            1 => format!("{}in", between(rng, 77, 99)),
+            2 => between(rng, 59, 193).to_string(),
+            _ => format!("{}mm", between(rng, 59, 193)),
+        },
+        "hcl" => match rng.below(3) {
+            0 => hex_digits(rng, 6),
+            1 => format!("#{}", hex_digits(rng, 5)),
+            _ => format!("#{}z", hex_digits(rng, 5)),
+        },
+        "ecl" => pick(rng, &["zzz", "xry", "gmt", "blue"]).to_string(),
+        _ => match rng.below(2) {
+            0 => digits(rng, 8),
+            _ => format!("{}{}", between(rng, 1, 9), digits(rng, 9)),
+        },
+    }
+}
+
+//  Each passport is either valid, missing a required field, or has all of
+//  the fields with at least one of their values out of bounds. Only the
+//  last two look different to the two parts.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let mut passports = vec![];
+    let mut complete = 0;
+    let mut valid = 0;
+
+    for _ in 0..size {
+        let mut fields: Vec<(&str, String)> = REQUIRED
+            .iter()
+            .map(|&field| (field, valid_value(rng, field)))
+            .collect();
+
+        match rng.below(3) {
+            0 => {
+                complete += 1;
+                valid += 1;
+            }
+            1 => {
+                complete += 1;
+                for _ in 0..between(rng, 1, 3) {
+                    let i = rng.below(fields.len());
+                    fields[i].1 = invalid_value(rng, fields[i].0);
+                }
+            }
+            _ => {
+                fields.remove(rng.below(fields.len()));
+            }
+        }
+
+        if rng.chance(50) {
+            fields.push(("cid", valid_value(rng, "cid")));
+        }
+
+        rng.shuffle(&mut fields);
+
+        let mut passport = String::new();
+        for (i, (field, value)) in fields.iter().enumerate() {
+            if i > 0 {
+                passport.push(if rng.chance(25) { '\n' } else { ' ' });
+            }
+            passport.push_str(&format!("{}:{}", field, value));
+        }
+
+        passports.push(passport);
+    }
+
+    Generated::new(passports.join("\n\n"))
+        .part1(complete)
+        .part2(valid)
+}
@@ -0,0 +1,37 @@
+use super::between;
+use crate::Generated;
+use aoc_common::random::Rng;
+
+pub const SIZE: usize = 800;
+
+const SEATS: usize = 1024;
+
+fn boarding_pass(seat_id: usize) -> String {
+    (0..10)
+        .rev()
+        .map(|bit| match (bit >= 3, seat_id >> bit & 1) {
+            (true, 0) => 'F',
+            (true, _) => 'B',
+            (false, 0) => 'L',
+            (false, _) => 'R',
+        })
+        .collect()
+}
+
+//  A run of taken seats, with the one gap in the middle of it left for ours.
+//  There are only so many seats on the plane, so the size is capped.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let taken = size.clamp(2, SEATS - 1);
+    let first = rng.below(SEATS - taken);
+    let ours = between(rng, first + 1, first + taken - 1);
+
+    let mut seat_ids: Vec<usize> = (first..=first + taken)
+        .filter(|&seat_id| seat_id != ours)
+        .collect();
+    rng.shuffle(&mut seat_ids);
+
+    let passes: Vec<String> = seat_ids.into_iter().map(boarding_pass).collect();
+    Generated::new(passes.join("\n"))
+        .part1(first + taken)
+        .part2(ours)
+}
@@ -0,0 +1,53 @@
+use super::between;
+use crate::Generated;
+use aoc_common::random::Rng;
+
+pub const SIZE: usize = 490;
+
+//  Groups of people, where each group has a set of questions that everyone
+//  answered and each person adds a few of their own.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let mut groups = vec![];
+    let mut anyone = 0;
+    let mut everyone = 0;
+
+    for _ in 0..size {
+        let people = between(rng, 1, 5);
+        let shared: Vec<bool> = (0..26).map(|_| rng.chance(20)).collect();
+        let mut answered = [false; 26];
+        let mut lines = vec![];
+
+        for _ in 0..people {
+            let mut answers: Vec<char> = (0..26)
+                .filter(|&question| shared[question] || rng.chance(15))
+                .map(|question| (b'a' + question as u8) as char)
+                .collect();
+
+            //  Nobody hands in a blank form.
+            if answers.is_empty() {
+                answers.push((b'a' + rng.below(26) as u8) as char);
+            }
+
+            for &answer in &answers {
+                answered[(answer as u8 - b'a') as usize] = true;
+            }
+
+            rng.shuffle(&mut answers);
+            lines.push(answers.into_iter().collect::<String>());
+        }
+
+        anyone += answered.iter().filter(|&&yes| yes).count();
+        everyone += (0..26)
+            .filter(|&question| {
+                let letter = (b'a' + question as u8) as char;
+                lines.iter().all(|line| line.contains(letter))
+            })
+            .count();
+
+        groups.push(lines.join("\n"));
+    }
+
+    Generated::new(groups.join("\n\n"))
+        .part1(anyone)
+        .part2(everyone)
+}
@@ -0,0 +1,131 @@
+use super::between;
+use crate::Generated;
+use aoc_common::random::Rng;
+
+pub const SIZE: usize = 594;
+
+const ADJECTIVES: [&str; 24] = [
+    "bright",
+    "clear",
+    "dark",
+    "dim",
+    "dotted",
+    "drab",
+    "dull",
+    "faded",
+    "light",
+    "mirrored",
+    "muted",
+    "pale",
+    "plaid",
+    "posh",
+    "striped",
+    "vibrant",
+    "wavy",
+    "dashed",
+    "shimmering",
+    "wavering",
+    "matte",
+    "glossy",
+    "frosted",
+    "speckled",
+];
+const COLOURS: [&str; 24] = [
+    "aqua", "beige", "black", "blue", "bronze", "brown", "coral", "crimson", "cyan", "fuchsia",
+    "gold", "gray", "green", "indigo", "lavender", "lime", "magenta", "maroon", "olive", "orange",
+    "plum", "red", "silver", "teal",
+];
+
+//  Bags only hold bags from the next few layers down, which keeps the number
+//  of bags inside any one of them from getting out of hand.
+const LAYERS: usize = 7;
+const GOLD_LAYER: usize = 3;
+
+//  Colours of one or more adjectives and a colour, none of them shiny.
+fn colour(mut index: usize) -> String {
+    let mut words = vec![COLOURS[index % COLOURS.len()]];
+    index /= COLOURS.len();
+
+    loop {
+        words.push(ADJECTIVES[index % ADJECTIVES.len()]);
+        index /= ADJECTIVES.len();
+        if index == 0 {
+            break;
+        }
+        index -= 1;
+    }
+
+    words.reverse();
+    words.join(" ")
+}
+
+//  A DAG of bag rules, laid out in layers so that the shiny gold bag has
+//  plenty of bags above it and below it.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let count = size.max(LAYERS);
+    let layer = |bag: usize| bag * LAYERS / count;
+    let mut names: Vec<usize> = (0..count).collect();
+    rng.shuffle(&mut names);
+
+    let gold = (0..count)
+        .find(|&bag| layer(bag) == GOLD_LAYER)
+        .unwrap_or(0);
+    let mut colours: Vec<String> = names.into_iter().map(colour).collect();
+    colours[gold] = String::from("shiny gold");
+
+    let mut contents: Vec<Vec<(usize, usize)>> = vec![];
+    for bag in 0..count {
+        let below: Vec<usize> = (bag + 1..count)
+            .filter(|&other| layer(other) > layer(bag) && layer(other) <= layer(bag) + 2)
+            .collect();
+        let mut held: Vec<usize> = vec![];
+
+        if !below.is_empty() && rng.chance(85) {
+            held = (0..between(rng, 1, 4))
+                .map(|_| below[rng.below(below.len())])
+                .collect();
+            held.sort_unstable();
+            held.dedup();
+        }
+
+        let mut held: Vec<(usize, usize)> = held
+            .into_iter()
+            .map(|other| (between(rng, 1, 5), other))
+            .collect();
+        rng.shuffle(&mut held);
+        contents.push(held);
+    }
+
+    //  Bags only hold bags further down the list, so work back up it.
+    let mut inside = vec![0_usize; count];
+    let mut holds_gold = vec![false; count];
+    for bag in (0..count).rev() {
+        for &(number, other) in &contents[bag] {
+            inside[bag] += number * (1 + inside[other]);
+            holds_gold[bag] |= other == gold || holds_gold[other];
+        }
+    }
+
+    let mut rules: Vec<String> = (0..count)
+        .map(|bag| {
+            let held: Vec<String> = contents[bag]
+                .iter()
+                .map(|&(number, other)| {
+                    let plural = if number == 1 { "bag" } else { "bags" };
+                    format!("{} {} {}", number, colours[other], plural)
+                })
+                .collect();
+
+            if held.is_empty() {
+                format!("{} bags contain no other bags.", colours[bag])
+            } else {
+                format!("{} bags contain {}.", colours[bag], held.join(", "))
+            }
+        })
+        .collect();
+    rng.shuffle(&mut rules);
+
+    Generated::new(rules.join("\n"))
+        .part1(holds_gold.iter().filter(|&&holds| holds).count())
+        .part2(inside[gold])
+}
@@ -0,0 +1,133 @@
+use super::between;
+use crate::Generated;
+use aoc_common::random::Rng;
+
+pub const SIZE: usize = 640;
+
+#[derive(Clone, Copy, PartialEq)]
+enum Operation {
+    Acc,
+    Jmp,
+    Nop,
+}
+
+type Instruction = (Operation, isize);
+
+//  Whether the program ends, and the accumulator when it does or when it is
+//  about to run an instruction for the second time.
+fn run(program: &[Instruction]) -> (bool, isize) {
+    let mut seen = vec![false; program.len()];
+    let mut accumulator = 0;
+    let mut index = 0;
+
+    while index < program.len() && !seen[index] {
+        seen[index] = true;
+        let (operation, argument) = program[index];
+        match operation {
+            Operation::Acc => accumulator += argument,
+            Operation::Jmp => {
+                index = (index as isize + argument) as usize;
+                continue;
+            }
+            Operation::Nop => {}
+        }
+        index += 1;
+    }
+
+    (index == program.len(), accumulator)
+}
+
+//  Splits the program into blocks that each end by moving on to the next
+//  block along a route through all of them. The route visits every block in
+//  the first half, ending with the last of them, then every block in the
+//  second half, ending with the last of the program.
+//
+//  Changing any nop or jmp in the first half only sends the program to some
+//  other block in the first half, which can't escape it. So the only fix is
+//  the corrupted jmp at the end of the first half, which should have been a
+//  nop that lets the program fall through into the second.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let size = size.max(12);
+    let mut starts = vec![0];
+    while starts.last().unwrap() + 8 < size {
+        starts.push(starts.last().unwrap() + between(rng, 2, 6));
+    }
+    starts.push(size);
+
+    let blocks = starts.len() - 1;
+    let half = between(rng, 1, blocks - 1);
+    let mut route: Vec<usize> = (1..half - 1).collect();
+    rng.shuffle(&mut route);
+    route.insert(0, 0);
+    if half > 1 {
+        route.push(half - 1);
+    }
+
+    let mut second: Vec<usize> = (half + 1..blocks - 1).collect();
+    rng.shuffle(&mut second);
+    route.push(half);
+    route.extend(second);
+    if blocks - 1 > half {
+        route.push(blocks - 1);
+    }
+
+    let mut program: Vec<Instruction> = vec![(Operation::Nop, 0); size];
+    for (step, &block) in route.iter().enumerate() {
+        let (start, end) = (starts[block], starts[block + 1]);
+        let in_first_half = block < half;
+
+        //  A nop can point anywhere in its own half, which for the first
+        //  half is somewhere that leads back round to the corrupted jmp.
+        let somewhere = |rng: &mut Rng, at: usize| {
+            let target = if in_first_half {
+                rng.below(starts[half])
+            } else {
+                between(rng, starts[half], size - 1)
+            };
+            target as isize - at as isize
+        };
+
+        for (at, instruction) in program.iter_mut().enumerate().take(end - 1).skip(start) {
+            *instruction = match rng.below(3) {
+                0 => (Operation::Nop, somewhere(rng, at)),
+                _ => (Operation::Acc, between(rng, 1, 99) as isize - 50),
+            };
+        }
+
+        let last = end - 1;
+        program[last] = match route.get(step + 1) {
+            Some(&next) if next == block + 1 && rng.chance(50) => {
+                (Operation::Acc, between(rng, 1, 99) as isize - 50)
+            }
+            Some(&next) if next == block + 1 => (Operation::Nop, somewhere(rng, last)),
+            Some(&next) => (Operation::Jmp, starts[next] as isize - last as isize),
+            None => (Operation::Acc, between(rng, 1, 99) as isize - 50),
+        };
+    }
+
+    //  The last block of the first half is always followed by the first of
+    //  the second, so that its last instruction can be the corrupted one.
+    let corrupted = starts[half] - 1;
+    program[corrupted] = (Operation::Jmp, -(rng.below(corrupted + 1) as isize));
+
+    let (ends, part1) = run(&program);
+    debug_assert!(!ends);
+    let mut fixed = program.clone();
+    fixed[corrupted].0 = Operation::Nop;
+    let (ends, part2) = run(&fixed);
+    debug_assert!(ends);
+
+    let lines: Vec<String> = program
+        .iter()
+        .map(|&(operation, argument)| {
+            let name = match operation {
+                Operation::Acc => "acc",
+                Operation::Jmp => "jmp",
+                Operation::Nop => "nop",
+            };
+            format!("{} {:+}", name, argument)
+        })
+        .collect();
+
+    Generated::new(lines.join("\n")).part1(part1).part2(part2)
+}
@@ -0,0 +1,81 @@
+use super::between;
+use crate::Generated;
+use aoc_common::random::Rng;
+use std::collections::HashSet;
+
+pub const SIZE: usize = 1000;
+
+const PREAMBLE: usize = 25;
+
+//  Sums of two numbers from the window can only get bigger, so after this
+//  many the numbers would no longer fit. The invalid number is planted before
+//  then, and anything after it doesn't need to be a sum.
+const LATEST_INVALID: usize = 400;
+
+fn is_sum_of_two(window: &[u64], number: u64) -> bool {
+    let values: HashSet<u64> = window.iter().copied().collect();
+    values.iter().any(|&value| {
+        value < number && number - value != value && values.contains(&(number - value))
+    })
+}
+
+//  Every number up to the invalid one is the sum of two different numbers
+//  in the 25 before it, and the invalid one is the sum of a run of earlier
+//  numbers instead.
+pub fn generate(rng: &mut Rng, size: usize) -> Generated {
+    let size = size.max(PREAMBLE + 2);
+    let invalid = between(rng, PREAMBLE + 1, (size - 1).min(LATEST_INVALID));
+
+    let mut numbers: Vec<u64> = (1..=50).collect();
+    rng.shuffle(&mut numbers);
+    numbers.truncate(PREAMBLE);
+
+    while numbers.len() < invalid {
+        let window = &numbers[numbers.len() - PREAMBLE..];
+        let first = window[rng.below(PREAMBLE)];
+        let second = window[rng.below(PREAMBLE)];
+        if first != second {
+            numbers.push(first + second);
+        }
+    }
+
+    let target = loop {
+        let start = rng.below(invalid - 1);
+        let end = between(rng, start + 2, (start + 17).min(invalid));
+        let sum = numbers[start..end].iter().sum();
+
+        if !is_sum_of_two(&numbers[invalid - PREAMBLE..], sum) {
+            break sum;
+        }
+    };
+    numbers.push(target);
+
+    let largest = *numbers.iter().max().unwrap();
+    while numbers.len() < size {
+        numbers.push(1 + rng.next() % largest);
+    }
+
+    //  The run that the solution finds is the first one, which may not be the
+    //  one that was planted.
+    let mut found = (0, 0);
+    'search: for start in 0..invalid {
+        let mut sum = numbers[start];
+        for (end, &number) in numbers.iter().enumerate().skip(start + 1) {
+            sum += number;
+            if sum == target {
+                found = (start, end);
+                break 'search;
+            } else if sum > target {
+                break;
+            }
+        }
+    }
+
+    let (start, end) = found;
+    let run = &numbers[start..=end];
+
+    let lines: Vec<String> = numbers.iter().map(|number| number.to_string()).collect();
+    Generated::new(lines.join("\n"))
+        .part1(target)
+        .part2(run.iter().min().unwrap() + run.iter().max().unwrap())
+}
@@ -0,0 +1,75 @@
+use aoc_common::Solution;
+use aoc_generate::generate;
+
+const SEEDS: u64 = 5;
+
+//  Solves each seed's input and checks it against whichever answers the
+//  generator planted.
+fn check<S: Solution>(day: usize, size: usize) {
+    for seed in 0..SEEDS {
+        let generated = generate("2020", day, seed, Some(size)).unwrap();
+        let input = S::parse(&generated.input)
+            .unwrap_or_else(|e| panic!("day {} seed {}: {}", day, seed, e));
+
+        if let Some(answer) = generated.part1 {
+            let solved = S::part1(&input).unwrap().to_string();
+            assert_eq!(answer, solved, "day {} part 1, seed {}", day, seed);
+        }
+
+        if let Some(answer) = generated.part2 {
+            let solved = S::part2(&input).unwrap().to_string();
+            assert_eq!(answer, solved, "day {} part 2, seed {}", day, seed);
+        }
+    }
+}
+
+//  The smallest inputs are where a generator is most likely to leave out
+//  something the solution needs, such as a section that ends up empty.
+fn check_smallest<S: Solution>(day: usize) {
+    for seed in 0..SEEDS {
+        let generated = generate("2020", day, seed, Some(0)).unwrap();
+        if let Err(e) = S::parse(&generated.input) {
+            panic!("day {} seed {} at size 0: {}", day, seed, e);
+        }
+    }
+}
+
+macro_rules! answers_match {
+    ($($name:ident: $day:ty => $number:expr, $size:expr;)*) => {
+        $(
+            #[test]
+            fn $name() {
+                check::<$day>($number, $size);
+                check_smallest::<$day>($number);
+            }
+        )*
+    };
+}
+
+answers_match! {
+    day1_answers_match: day1::Day1 => 1, 200;
+    day2_answers_match: day2::Day2 => 2, 500;
+    day3_answers_match: day3::Day3 => 3, 300;
+    day4_answers_match: day4::Day4 => 4, 300;
+    day5_answers_match: day5::Day5 => 5, 500;
+    day6_answers_match: day6::Day6 => 6, 300;
+    day7_answers_match: day7::Day7 => 7, 300;
+    day8_answers_match: day8::Day8 => 8, 300;
+    day9_answers_match: day9::Day9 => 9, 500;
+    day10_answers_match: day10::Day10 => 10, 100;
+    day11_answers_match: day11::Day11 => 11, 30;
+    day12_answers_match: day12::Day12 => 12, 500;
+    day13_answers_match: day13::Day13 => 13, 9;
+    day14_answers_match: day14::Day14 => 14, 300;
+    day15_answers_match: day15::Day15 => 15, 7;
+    day16_answers_match: day16::Day16 => 16, 200;
+    day17_answers_match: day17::Day17 => 17, 4;
+    day18_answers_match: day18::Day18 => 18, 300;
+    day19_answers_match: day19::Day19 => 19, 300;
+    day20_answers_match: day20::Day20 => 20, 3;
+    day21_answers_match: day21::Day21 => 21, 40;
+    day22_answers_match: day22::Day22 => 22, 15;
+    day23_answers_match: day23::Day23 => 23, 9;
+    day24_answers_match: day24::Day24 => 24, 200;
+    day25_answers_match: day25::Day25 => 25, 1;
+}
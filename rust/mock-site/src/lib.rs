@@ -0,0 +1,338 @@
+//  A stand-in for the Advent of Code site, which serves puzzle inputs and
+//  judges answers from fixtures so that downloading and submitting can be
+//  tested without the network. It speaks just enough HTTP/1.1 for the runner.
+use std::collections::{HashMap, HashSet};
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::sync::Mutex;
+use std::thread;
+
+#[derive(Default)]
+struct Puzzle {
+    input: String,
+    answers: [Option<String>; 2],
+}
+
+pub struct MockSite {
+    session: String,
+    puzzles: HashMap<(String, usize), Puzzle>,
+    //  The levels answered correctly so far, by year and day.
+    solved: Mutex<HashSet<(String, usize, usize)>>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Request {
+    pub method: String,
+    pub path: String,
+    pub session: Option<String>,
+    pub body: String,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Response {
+    pub status: u16,
+    pub body: String,
+}
+
+#[cfg(test)]
+mod mock_site_tests {
+    use super::*;
+
+    fn get_test_site() -> MockSite {
+        MockSite::new("abc")
+            .puzzle("2020", 1, "1721\n979\n")
+            .answer("2020", 1, 1, "514579")
+            .answer("2020", 1, 2, "241861950")
+    }
+
+    fn request(method: &str, path: &str, session: Option<&str>, body: &str) -> Request {
+        Request {
+            method: String::from(method),
+            path: String::from(path),
+            session: session.map(String::from),
+            body: String::from(body),
+        }
+    }
+
+    fn submit(site: &MockSite, level: usize, answer: &str) -> String {
+        let body = format!("level={}&answer={}", level, answer);
+        site.respond(&request("POST", "/2020/day/1/answer", Some("abc"), &body))
+            .body
+    }
+
+    #[test]
+    fn should_serve_an_input_to_a_logged_in_user() {
+        let site = get_test_site();
+        let response = site.respond(&request("GET", "/2020/day/1/input", Some("abc"), ""));
+        assert_eq!(200, response.status);
+        assert_eq!("1721\n979\n", response.body);
+
+        let response = site.respond(&request("GET", "/2020/day/1/input", None, ""));
+        assert_eq!(400, response.status);
+        assert!(response.body.contains("Please log in"));
+
+        let response = site.respond(&request("GET", "/2020/day/2/input", Some("abc"), ""));
+        assert_eq!(404, response.status);
+    }
+
+    #[test]
+    fn should_judge_answers() {
+        let site = get_test_site();
+        assert!(submit(&site, 2, "241861950").contains("right level"));
+        assert!(submit(&site, 1, "600000").contains("your answer is too high"));
+        assert!(submit(&site, 1, "x").contains("not the right answer."));
+        assert!(submit(&site, 1, "514579").contains("That's the right answer"));
+        assert!(submit(&site, 1, "514579").contains("right level"));
+        assert!(submit(&site, 2, "241861950").contains("That's the right answer"));
+    }
+
+    #[test]
+    fn should_decode_form_values() {
+        let form = form("level=2&answer=fdsfpg%2Cjmvxx+lkv");
+        assert_eq!(Some(&String::from("2")), form.get("level"));
+        assert_eq!(Some(&String::from("fdsfpg,jmvxx lkv")), form.get("answer"));
+    }
+
+    #[test]
+    fn should_answer_over_http() {
+        let address = get_test_site().start().unwrap();
+        let mut stream = TcpStream::connect(address).unwrap();
+        write!(
+            stream,
+            "GET /2020/day/1/input HTTP/1.1\r\nHost: localhost\r\nCookie: other=1; session=abc\r\n\r\n"
+        )
+        .unwrap();
+
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        assert!(response.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert!(response.ends_with("\r\n\r\n1721\n979\n"));
+    }
+}
+
+fn not_found() -> Response {
+    Response {
+        status: 404,
+        body: String::from("404 Not Found\n"),
+    }
+}
+
+//  The site wraps what it has to say about an answer in an article.
+fn article(message: &str) -> Response {
+    Response {
+        status: 200,
+        body: format!("<main>\n<article><p>{}</p></article>\n</main>\n", message),
+    }
+}
+
+fn decode(value: &str) -> String {
+    let mut bytes = vec![];
+    let mut chars = value.bytes();
+
+    while let Some(byte) = chars.next() {
+        match byte {
+            b'+' => bytes.push(b' '),
+            b'%' => {
+                let hex: Vec<u8> = chars.by_ref().take(2).collect();
+                match u8::from_str_radix(&String::from_utf8_lossy(&hex), 16) {
+                    Ok(decoded) => bytes.push(decoded),
+                    Err(_) => bytes.extend(b"%".iter().chain(&hex)),
+                }
+            }
+            _ => bytes.push(byte),
+        }
+    }
+
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+fn form(body: &str) -> HashMap<String, String> {
+    body.split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .map(|(name, value)| (decode(name), decode(value)))
+        .collect()
+}
+
+fn read_request(stream: &mut TcpStream) -> io::Result<Request> {
+    let mut reader = BufReader::new(stream);
+    let mut line = String::new();
+    reader.read_line(&mut line)?;
+
+    let mut request_line = line.split_whitespace();
+    let method = String::from(request_line.next().unwrap_or(""));
+    let path = String::from(request_line.next().unwrap_or(""));
+    let mut length = 0;
+    let mut session = None;
+
+    loop {
+        line.clear();
+        if reader.read_line(&mut line)? == 0 || line.trim_end().is_empty() {
+            break;
+        }
+
+        if let Some((name, value)) = line.split_once(':') {
+            match name.trim().to_ascii_lowercase().as_str() {
+                "content-length" => length = value.trim().parse().unwrap_or(0),
+                "cookie" => {
+                    session = value
+                        .split(';')
+                        .find_map(|cookie| cookie.trim().strip_prefix("session="))
+                        .map(String::from)
+                }
+                _ => {}
+            }
+        }
+    }
+
+    let mut body = vec![0; length];
+    reader.read_exact(&mut body)?;
+
+    Ok(Request {
+        method,
+        path,
+        session,
+        body: String::from_utf8_lossy(&body).into_owned(),
+    })
+}
+
+fn write_response(stream: &mut TcpStream, response: &Response) -> io::Result<()> {
+    let reason = match response.status {
+        200 => "OK",
+        400 => "Bad Request",
+        _ => "Not Found",
+    };
+
+    write!(
+        stream,
+        "HTTP/1.1 {} {}\r\nContent-Type: text/html\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        response.status,
+        reason,
+        response.body.len(),
+        response.body
+    )
+}
+
+impl MockSite {
+    //  Only requests carrying this session cookie are treated as logged in.
+    pub fn new(session: &str) -> MockSite {
+        MockSite {
+            session: String::from(session),
+            puzzles: HashMap::new(),
+            solved: Mutex::new(HashSet::new()),
+        }
+    }
+
+    pub fn puzzle(mut self, year: &str, day: usize, input: &str) -> MockSite {
+        self.puzzles
+            .entry((String::from(year), day))
+            .or_default()
+            .input = String::from(input);
+        self
+    }
+
+    pub fn answer(mut self, year: &str, day: usize, level: usize, answer: &str) -> MockSite {
+        self.puzzles
+            .entry((String::from(year), day))
+            .or_default()
+            .answers[level - 1] = Some(String::from(answer));
+        self
+    }
+
+    fn judge(&self, year: &str, day: usize, puzzle: &Puzzle, body: &str) -> Response {
+        let form = form(body);
+        let level = form
+            .get("level")
+            .and_then(|level| level.parse::<usize>().ok());
+        let (level, answer) = match (level, form.get("answer")) {
+            (Some(level @ 1..=2), Some(answer)) => (level, answer.trim()),
+            _ => {
+                return Response {
+                    status: 400,
+                    body: String::from("Bad Request\n"),
+                }
+            }
+        };
+
+        let expected = match &puzzle.answers[level - 1] {
+            Some(expected) => expected,
+            None => return not_found(),
+        };
+
+        let mut solved = self.solved.lock().unwrap();
+        let key = (String::from(year), day, level);
+        if solved.contains(&key) || level == 2 && !solved.contains(&(String::from(year), day, 1)) {
+            return article(
+                "You don't seem to be solving the right level.  Did you already complete it?",
+            );
+        }
+
+        if answer == expected {
+            solved.insert(key);
+            return article(
+                "That's the right answer!  You are one gold star closer to saving your vacation.",
+            );
+        }
+
+        let hint = match (answer.parse::<i64>(), expected.parse::<i64>()) {
+            (Ok(given), Ok(expected)) if given > expected => "; your answer is too high",
+            (Ok(given), Ok(expected)) if given < expected => "; your answer is too low",
+            _ => "",
+        };
+        article(&format!("That's not the right answer{}.", hint))
+    }
+
+    pub fn respond(&self, request: &Request) -> Response {
+        let segments: Vec<&str> = request.path.trim_start_matches('/').split('/').collect();
+        let (year, day, action) = match segments.as_slice() {
+            [year, "day", day, action] => match day.parse::<usize>() {
+                Ok(day) => (*year, day, *action),
+                Err(_) => return not_found(),
+            },
+            _ => return not_found(),
+        };
+
+        let puzzle = match self.puzzles.get(&(String::from(year), day)) {
+            Some(puzzle) => puzzle,
+            None => return not_found(),
+        };
+
+        if request.session.as_deref() != Some(self.session.as_str()) {
+            return Response {
+                status: 400,
+                body: String::from(
+                    "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n",
+                ),
+            };
+        }
+
+        match (request.method.as_str(), action) {
+            ("GET", "input") => Response {
+                status: 200,
+                body: puzzle.input.clone(),
+            },
+            ("POST", "answer") => self.judge(year, day, puzzle, &request.body),
+            _ => not_found(),
+        }
+    }
+
+    //  Answers one connection at a time until the listener fails.
+    pub fn serve(self, listener: TcpListener) -> io::Result<()> {
+        for stream in listener.incoming() {
+            let mut stream = stream?;
+            if let Ok(request) = read_request(&mut stream) {
+                let _ = write_response(&mut stream, &self.respond(&request));
+            }
+        }
+
+        Ok(())
+    }
+
+    //  Serves on a free local port from a background thread, which lasts as
+    //  long as the process does.
+    pub fn start(self) -> io::Result<SocketAddr> {
+        let listener = TcpListener::bind("127.0.0.1:0")?;
+        let address = listener.local_addr()?;
+        thread::spawn(move || self.serve(listener));
+        Ok(address)
+    }
+}
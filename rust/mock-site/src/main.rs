@@ -0,0 +1,98 @@
+use aoc_mock_site::MockSite;
+use std::env;
+use std::fs;
+use std::io::{Error, ErrorKind, Result};
+use std::net::TcpListener;
+use std::path::Path;
+use std::process;
+
+const INPUTS_PATH: &str = "inputs";
+const ANSWERS_PATH: &str = "answers.txt";
+
+fn invalid_input(message: String) -> Error {
+    Error::new(ErrorKind::InvalidInput, message)
+}
+
+fn usage() -> Error {
+    invalid_input(String::from(
+        "usage: aoc-mock-site [--port <port>] [--session <token>]",
+    ))
+}
+
+//  Serves every input under inputs/<year>/dayNN.txt, judged against the
+//  answers in answers.txt.
+fn load_fixtures(site: MockSite) -> Result<(MockSite, usize)> {
+    let mut site = site;
+    let mut count = 0;
+
+    for year in fs::read_dir(INPUTS_PATH)? {
+        let year = year?;
+        let year_name = year.file_name().to_string_lossy().into_owned();
+
+        for file in fs::read_dir(year.path())? {
+            let path = file?.path();
+            let name = path.file_name().unwrap_or_default().to_string_lossy();
+            let day = name
+                .strip_prefix("day")
+                .and_then(|name| name.strip_suffix(".txt"))
+                .and_then(|day| day.parse::<usize>().ok());
+
+            if let Some(day) = day {
+                site = site.puzzle(&year_name, day, &fs::read_to_string(&path)?);
+                count += 1;
+            }
+        }
+    }
+
+    if Path::new(ANSWERS_PATH).exists() {
+        for line in fs::read_to_string(ANSWERS_PATH)?.lines() {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            if let [year, day, part, answer] = fields.as_slice() {
+                let level = match *part {
+                    "a" | "A" => 1,
+                    "b" | "B" => 2,
+                    _ => continue,
+                };
+                if let Ok(day) = day.parse::<usize>() {
+                    site = site.answer(year, day, level, answer);
+                }
+            }
+        }
+    }
+
+    Ok((site, count))
+}
+
+fn run(args: &[String]) -> Result<()> {
+    let mut port = String::from("8080");
+    let mut session = String::from("mock-session");
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+        match (arg.as_str(), args.next()) {
+            ("--port", Some(value)) => port = value.clone(),
+            ("--session", Some(value)) => session = value.clone(),
+            _ => return Err(usage()),
+        }
+    }
+
+    let (site, count) = load_fixtures(MockSite::new(&session))?;
+    let listener = TcpListener::bind(format!("127.0.0.1:{}", port))?;
+    println!(
+        "Serving {} inputs on http://{} for session {}",
+        count,
+        listener.local_addr()?,
+        session
+    );
+
+    site.serve(listener)
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    if let Err(e) = run(&args) {
+        eprintln!("{}", e);
+        process::exit(1);
+    }
+}